@@ -1,13 +1,19 @@
-use core::{net::SocketAddr, time::Duration};
+use core::{fmt, net::SocketAddr, time::Duration};
 
-use xitca_http::http::version::Version;
+use xitca_http::http::{
+    header::{HeaderMap, HeaderValue, AUTHORIZATION},
+    version::Version,
+    Uri,
+};
 
 use crate::{
     client::Client,
     connect::Connect,
     date::DateTimeService,
     error::Error,
-    pool,
+    middleware::{BaseUri, DefaultHeaders},
+    pool::{self, PoolConfig, PoolSweeper},
+    request,
     resolver::{base_resolver, ResolverService},
     response::Response,
     service::{base_service, HttpService},
@@ -19,11 +25,16 @@ use crate::{
     },
 };
 
+#[cfg(any(feature = "openssl", feature = "rustls", feature = "rustls-ring-crypto"))]
+use crate::tls::config::TlsConfig;
+
 /// Builder type for [Client]. Offer configurations before a client instance is created.
 pub struct ClientBuilder {
     connector: Connector,
     resolver: ResolverService,
     pool_capacity: usize,
+    pool_config: PoolConfig,
+    pool_sweep_interval: Option<Duration>,
     timeout_config: TimeoutConfig,
     local_addr: Option<SocketAddr>,
     max_http_version: Version,
@@ -42,6 +53,8 @@ impl ClientBuilder {
             connector: connector::nop(),
             resolver: base_resolver(),
             pool_capacity: 2,
+            pool_config: PoolConfig::new(),
+            pool_sweep_interval: None,
             timeout_config: TimeoutConfig::new(),
             local_addr: None,
             max_http_version: max_http_version(),
@@ -117,17 +130,96 @@ impl ClientBuilder {
         self
     }
 
+    /// add default headers to every request sent by client. headers already presented in request
+    /// would not be overwritten.
+    ///
+    /// Default headers are applied as middleware. See [ClientBuilder::middleware] and [DefaultHeaders] for detail.
+    ///
+    /// [DefaultHeaders]: crate::middleware::DefaultHeaders
+    pub fn default_headers(self, headers: HeaderMap) -> Self {
+        self.middleware(|service| DefaultHeaders::new(service, headers))
+    }
+
+    /// set base uri where relative request uri would be resolved against.
+    ///
+    /// Base uri is applied as middleware. See [ClientBuilder::middleware] and [BaseUri] for detail.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use xitca_client::{http::Uri, ClientBuilder};
+    /// # fn base() {
+    /// let client = ClientBuilder::new()
+    ///     .base_uri(Uri::from_static("http://localhost:8080/api"))
+    ///     .finish();
+    ///
+    /// // request would be sent to "http://localhost:8080/api/users"
+    /// let req = client.get("/users");
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// When base uri does not contain scheme and authority.
+    ///
+    /// [BaseUri]: crate::middleware::BaseUri
+    pub fn base_uri(self, uri: Uri) -> Self {
+        self.middleware(|service| BaseUri::new(service, uri))
+    }
+
+    /// add default [AUTHORIZATION] header with bearer token to every request sent by client.
+    ///
+    /// # Panics
+    /// When token can not be used as header value.
+    pub fn bearer_auth(self, token: impl fmt::Display) -> Self {
+        let value = request::bearer_auth(token).expect("bearer token must be valid header value");
+        self.default_authorization(value)
+    }
+
+    #[cfg(feature = "basic-auth")]
+    /// add default [AUTHORIZATION] header with username and optional password in basic authentication
+    /// scheme to every request sent by client.
+    ///
+    /// # Panics
+    /// When username and/or password can not be used as header value.
+    pub fn basic_auth(self, username: impl fmt::Display, password: Option<impl fmt::Display>) -> Self {
+        let value = request::basic_auth(username, password).expect("basic credential must be valid header value");
+        self.default_authorization(value)
+    }
+
+    fn default_authorization(self, value: HeaderValue) -> Self {
+        let mut headers = HeaderMap::with_capacity(1);
+        headers.insert(AUTHORIZATION, value);
+        self.default_headers(headers)
+    }
+
     #[cfg(feature = "openssl")]
     /// enable openssl as tls connector.
-    pub fn openssl(mut self) -> Self {
-        self.connector = connector::openssl::connect(self.alpn_from_version());
+    pub fn openssl(self) -> Self {
+        self.openssl_with_config(TlsConfig::new())
+    }
+
+    #[cfg(feature = "openssl")]
+    /// enable openssl as tls connector with given [TlsConfig].
+    ///
+    /// # Panics
+    /// When certificates and/or private key inside [TlsConfig] are invalid.
+    pub fn openssl_with_config(mut self, config: TlsConfig) -> Self {
+        self.connector = connector::openssl::connect(self.alpn_from_version(), config);
         self
     }
 
     #[cfg(any(feature = "rustls", feature = "rustls-ring-crypto"))]
     /// enable rustls as tls connector.
-    pub fn rustls(mut self) -> Self {
-        self.connector = connector::rustls::connect(self.alpn_from_version());
+    pub fn rustls(self) -> Self {
+        self.rustls_with_config(TlsConfig::new())
+    }
+
+    #[cfg(any(feature = "rustls", feature = "rustls-ring-crypto"))]
+    /// enable rustls as tls connector with given [TlsConfig].
+    ///
+    /// # Panics
+    /// When certificates and/or private key inside [TlsConfig] are invalid.
+    pub fn rustls_with_config(mut self, config: TlsConfig) -> Self {
+        self.connector = connector::rustls::connect(self.alpn_from_version(), config);
         self
     }
 
@@ -264,6 +356,14 @@ impl ClientBuilder {
         self
     }
 
+    /// Set timeout for waiting server's interim response of request with `Expect: 100-continue` header.
+    ///
+    /// Default to 1 second.
+    pub fn set_expect_continue_timeout(mut self, dur: Duration) -> Self {
+        self.timeout_config.expect_continue_timeout = dur;
+        self
+    }
+
     /// Set [TimeoutConfig] for client.
     pub fn set_timeout(mut self, timeout_config: TimeoutConfig) -> Self {
         self.timeout_config = timeout_config;
@@ -283,7 +383,7 @@ impl ClientBuilder {
     /// # Note
     /// capacity is for concurrent opening sockets PER remote Domain.
     /// capacity only applies to http/1 protocol.
-    /// http/2 always open one socket per remote domain.
+    /// http/2 open one socket per remote domain by default. See [ClientBuilder::set_http2_max_connections_per_host].
     /// http/3 always open one socket for all remote domains.
     ///
     /// Default to 2
@@ -296,6 +396,76 @@ impl ClientBuilder {
         self
     }
 
+    /// Set max duration a connection can stay idle inside the connection pool before it's evicted.
+    ///
+    /// Server and load balancer in front of it usually close keep-alive connections after certain
+    /// idle period. Setting idle timeout lower than the remote one avoids reusing connections that
+    /// are about to be closed.
+    ///
+    /// Default to 600 seconds.
+    pub fn set_pool_idle_timeout(mut self, dur: Duration) -> Self {
+        self.pool_config.idle_timeout = dur;
+        self
+    }
+
+    /// Set max lifetime of a connection. Connection exceeding it's lifetime would not be reused
+    /// and new connection would be made instead.
+    ///
+    /// Useful for rebalancing connections across multiple servers behind load balancer.
+    ///
+    /// Default to 3600 seconds.
+    pub fn set_pool_max_lifetime(mut self, dur: Duration) -> Self {
+        self.pool_config.max_lifetime = dur;
+        self
+    }
+
+    /// Set max idle connections kept inside the connection pool PER remote Domain.
+    /// Connections exceeding the limit would be closed instead of returning to pool.
+    ///
+    /// # Note
+    /// max idle only applies to http/1 protocol and it can not exceed pool capacity.
+    /// See [ClientBuilder::set_pool_capacity] for detail.
+    ///
+    /// Default to no limit.
+    pub fn set_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_config.max_idle_per_host = max;
+        self
+    }
+
+    /// Set max http/2 connections can be opened PER remote Domain.
+    ///
+    /// Additional connection would only be opened when all existing connections reached the
+    /// concurrent stream limit (`SETTINGS_MAX_CONCURRENT_STREAMS`) advertised by server. When the
+    /// limit is reached requests would be queued on the least busy connection.
+    ///
+    /// Default to 1
+    ///
+    /// # Panics
+    /// When pass 0 as max connections.
+    pub fn set_http2_max_connections_per_host(mut self, max: usize) -> Self {
+        assert_ne!(max, 0);
+        self.pool_config.max_multiplexed_per_host = max;
+        self
+    }
+
+    /// Set interval of periodic sweep where expired connections are evicted from the connection pool.
+    /// Pass None to disable periodic sweep and expired connections would only be evicted lazily when
+    /// they are acquired from pool.
+    ///
+    /// Periodic sweep runs as a task spawned on tokio runtime when [ClientBuilder::finish] is called.
+    ///
+    /// Default to None.
+    ///
+    /// # Panics
+    /// When pass zero duration as interval.
+    pub fn set_pool_sweep_interval(mut self, dur: Option<Duration>) -> Self {
+        if let Some(dur) = dur {
+            assert!(!dur.is_zero());
+        }
+        self.pool_sweep_interval = dur;
+        self
+    }
+
     /// Set max http version client would be used.
     ///
     /// Default to the max version of http feature enabled within Cargo.toml
@@ -325,6 +495,10 @@ impl ClientBuilder {
     }
 
     /// Finish the builder and construct [Client] instance.
+    ///
+    /// # Panics
+    /// When periodic sweep of connection pool is enabled with [ClientBuilder::set_pool_sweep_interval]
+    /// and it's called outside the context of tokio runtime.
     pub fn finish(self) -> Client {
         #[cfg(feature = "http3")]
         let h3_client = {
@@ -424,9 +598,17 @@ impl ClientBuilder {
             endpoint
         };
 
+        let exclusive_pool = pool::exclusive::Pool::with_config(self.pool_capacity, self.pool_config);
+        let shared_pool = pool::shared::Pool::with_config(self.pool_config);
+
+        let pool_sweeper = self
+            .pool_sweep_interval
+            .map(|dur| PoolSweeper::new(dur, exclusive_pool.clone(), shared_pool.clone()));
+
         Client {
-            exclusive_pool: pool::exclusive::Pool::with_capacity(self.pool_capacity),
-            shared_pool: pool::shared::Pool::with_capacity(self.pool_capacity),
+            exclusive_pool,
+            shared_pool,
+            _pool_sweeper: pool_sweeper,
             connector: self.connector,
             resolver: self.resolver,
             timeout_config: self.timeout_config,
@@ -461,3 +643,17 @@ fn max_http_version() -> Version {
 
     Version::HTTP_11
 }
+
+// http/3 endpoint must be constructed inside tokio runtime.
+#[cfg(all(test, not(feature = "http3")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finish_outside_runtime() {
+        let _ = Client::new();
+        let _ = ClientBuilder::new()
+            .set_pool_idle_timeout(Duration::from_secs(1))
+            .finish();
+    }
+}
@@ -31,6 +31,8 @@ use crate::{
 pub struct Client {
     pub(crate) exclusive_pool: pool::exclusive::Pool<ConnectionKey, ConnectionExclusive>,
     pub(crate) shared_pool: pool::shared::Pool<ConnectionKey, ConnectionShared>,
+    // write only. sweep task is aborted when it's dropped together with client.
+    pub(crate) _pool_sweeper: Option<pool::PoolSweeper>,
     pub(crate) connector: Connector,
     pub(crate) resolver: ResolverService,
     pub(crate) timeout_config: TimeoutConfig,
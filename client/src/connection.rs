@@ -2,11 +2,35 @@ use core::hash::{Hash, Hasher};
 
 use xitca_http::http::uri::{Authority, PathAndQuery};
 
-use super::{tls::TlsStream, uri::Uri};
+use super::{pool::shared::Multiplex, tls::TlsStream, uri::Uri};
 
 /// exclusive connection for http1 and in certain case they can be upgraded to [ConnectionShared]
 pub type ConnectionExclusive = TlsStream;
 
+// check if an idle exclusive connection is still usable before sending request on it.
+// idle http/1 connection does not expect any data from server. a readable state means the connection
+// is either closed by server(or load balancer in the middle) or it's in an unexpected state.
+#[cfg(feature = "http1")]
+pub(crate) async fn is_exclusive_alive(conn: &mut ConnectionExclusive) -> bool {
+    use core::{future::poll_fn, task::Poll};
+
+    use std::io;
+
+    use xitca_io::io::{AsyncIo, Interest};
+
+    poll_fn(|cx| {
+        let alive = match conn.poll_ready(Interest::READABLE, cx) {
+            Poll::Pending => true,
+            Poll::Ready(Ok(ready)) if !ready.is_read_closed() => {
+                matches!(io::Read::read(conn, &mut [0]), Err(ref e) if e.kind() == io::ErrorKind::WouldBlock)
+            }
+            Poll::Ready(_) => false,
+        };
+        Poll::Ready(alive)
+    })
+    .await
+}
+
 /// high level shared connection that support multiplexing over single socket
 /// used for http2 and http3
 #[derive(Clone)]
@@ -17,6 +41,18 @@ pub enum ConnectionShared {
     H3(crate::h3::Connection),
 }
 
+impl Multiplex for ConnectionShared {
+    fn max_concurrent_streams(&self) -> usize {
+        match *self {
+            #[cfg(feature = "http2")]
+            Self::H2(ref conn) => conn.current_max_send_streams(),
+            // stream concurrency of http/3 is managed by quic transport.
+            #[cfg(feature = "http3")]
+            Self::H3(_) => usize::MAX,
+        }
+    }
+}
+
 #[cfg(feature = "http2")]
 impl From<crate::h2::Connection> for ConnectionShared {
     fn from(conn: crate::h2::Connection) -> Self {
@@ -1,4 +1,8 @@
-use std::{ops::Deref, sync::Arc, sync::RwLock, time::Duration};
+use std::{
+    ops::Deref,
+    sync::{Arc, OnceLock, RwLock},
+    time::Duration,
+};
 
 use tokio::{
     task::{spawn, JoinHandle},
@@ -8,13 +12,15 @@ use xitca_http::date::{DateTime, DateTimeState, DATE_VALUE_LENGTH};
 
 pub(crate) struct DateTimeService {
     state: Arc<RwLock<DateTimeState>>,
-    handle: JoinHandle<()>,
+    handle: OnceLock<JoinHandle<()>>,
 }
 
 impl Drop for DateTimeService {
     fn drop(&mut self) {
         // stop the timer update async task on drop.
-        self.handle.abort();
+        if let Some(handle) = self.handle.get() {
+            handle.abort();
+        }
     }
 }
 
@@ -31,23 +37,27 @@ impl Deref for DateTimeHandle<'_> {
 impl DateTimeService {
     pub(crate) fn new() -> Self {
         // shared date and timer for Date and update async task.
-        let state = Arc::new(RwLock::new(DateTimeState::new()));
-        let state_clone = Arc::clone(&state);
-        // spawn an async task sleep for 500 milli sec and update date in a loop.
-        // handle is used to stop the task on Date drop.
-        let handle = spawn(async move {
-            let mut interval = interval(Duration::from_millis(500));
-            let state = &*state_clone;
-            loop {
-                let _ = interval.tick().await;
-                *state.write().unwrap() = DateTimeState::new();
-            }
-        });
-
-        Self { state, handle }
+        Self {
+            state: Arc::new(RwLock::new(DateTimeState::new())),
+            handle: OnceLock::new(),
+        }
     }
 
+    // update task is spawned on first use so client can be constructed outside of tokio runtime.
     pub(crate) fn handle(&self) -> DateTimeHandle<'_> {
+        self.handle.get_or_init(|| {
+            let state = Arc::clone(&self.state);
+            // spawn an async task sleep for 500 milli sec and update date in a loop.
+            // handle is used to stop the task on Date drop.
+            spawn(async move {
+                let mut interval = interval(Duration::from_millis(500));
+                let state = &*state;
+                loop {
+                    let _ = interval.tick().await;
+                    *state.write().unwrap() = DateTimeState::new();
+                }
+            })
+        });
         DateTimeHandle(self.state.deref())
     }
 }
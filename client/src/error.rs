@@ -4,7 +4,7 @@ use core::{convert::Infallible, fmt, str};
 
 use std::{error, io};
 
-use super::http::{uri, StatusCode};
+use super::http::{header::InvalidHeaderValue, uri, StatusCode};
 
 #[derive(Debug)]
 #[non_exhaustive]
@@ -173,6 +173,8 @@ pub enum ParseError {
     String(str::Utf8Error),
     #[cfg(feature = "json")]
     Json(serde_json::Error),
+    #[cfg(feature = "urlencoded")]
+    UrlEncoded(serde_urlencoded::ser::Error),
     #[cfg(feature = "websocket")]
     WebSocket(http_ws::ProtocolError),
 }
@@ -235,6 +237,19 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+#[cfg(feature = "urlencoded")]
+impl From<serde_urlencoded::ser::Error> for Error {
+    fn from(e: serde_urlencoded::ser::Error) -> Self {
+        Self::Parse(ParseError::UrlEncoded(e))
+    }
+}
+
+impl From<InvalidHeaderValue> for Error {
+    fn from(e: InvalidHeaderValue) -> Self {
+        Self::Std(Box::new(e))
+    }
+}
+
 #[cfg(feature = "http1")]
 impl From<crate::h1::Error> for Error {
     fn from(e: crate::h1::Error) -> Self {
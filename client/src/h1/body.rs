@@ -66,6 +66,8 @@ impl Stream for ResponseBody {
                     }
                 },
                 ChunkResult::Err(e) => return Poll::Ready(Some(Err(e.into()))),
+                // response trailers are ignored.
+                ChunkResult::Trailers(_) => {}
                 _ => return Poll::Ready(None),
             }
         }
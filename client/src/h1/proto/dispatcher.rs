@@ -1,4 +1,8 @@
-use core::{future::poll_fn, pin::Pin};
+use core::{
+    future::poll_fn,
+    pin::{pin, Pin},
+    time::Duration,
+};
 
 use std::io;
 
@@ -15,6 +19,8 @@ use crate::{
         header::{HeaderValue, EXPECT, HOST},
         Method, Request, Response, StatusCode,
     },
+    request::OnInformational,
+    timeout::Timeout,
 };
 
 use super::context::Context;
@@ -23,6 +29,7 @@ pub(crate) async fn send<S, B, E>(
     stream: &mut S,
     date: DateTimeHandle<'_>,
     req: &mut Request<B>,
+    expect_timeout: Duration,
 ) -> Result<(Response<()>, BytesMut, TransferCoding, bool), Error>
 where
     S: AsyncIo + Unpin,
@@ -82,22 +89,44 @@ where
 
     write_all_buf(stream, &mut buf).await?;
 
+    let on_informational = req.extensions().get::<OnInformational>().cloned();
+
     if is_expect {
         flush(stream).await?;
 
+        // wait for server's interim response before sending body. when server does not respond in time
+        // the body is sent anyway as server may not support expectation.
+        let mut timer = pin!(tokio::time::sleep(expect_timeout));
+
         loop {
-            if let Some((res, mut decoder)) = try_read_response(stream, &mut buf, &mut ctx).await? {
-                if res.status() == StatusCode::CONTINUE {
-                    break;
+            let Ok(res) = try_read_response(stream, &mut buf, &mut ctx)
+                .timeout(timer.as_mut())
+                .await
+            else {
+                break;
+            };
+
+            if let Some((res, mut decoder)) = res? {
+                match res.status() {
+                    StatusCode::CONTINUE => break,
+                    status if is_interim(status) => {
+                        if let Some(ref f) = on_informational {
+                            f.call(&res);
+                        }
+                        continue;
+                    }
+                    _ => {}
                 }
 
-                let is_close = ctx.is_connection_closed();
+                // server rejected request with final response. request body is not sent and the connection
+                // can not be reused.
+                ctx.set_close();
 
                 if ctx.is_head_method() {
                     decoder = TransferCoding::eof();
                 }
 
-                return Ok((res, buf, decoder, is_close));
+                return Ok((res, buf, decoder, true));
             }
         }
     }
@@ -118,6 +147,14 @@ where
     // read response head and get body decoder.
     loop {
         if let Some((res, mut decoder)) = try_read_response(stream, &mut buf, &mut ctx).await? {
+            // skip interim responses and wait for the final one.
+            if is_interim(res.status()) {
+                if let Some(ref f) = on_informational {
+                    f.call(&res);
+                }
+                continue;
+            }
+
             // check if server sent connection close header.
 
             // *. If send_body function produces error, Context has already set
@@ -136,6 +173,11 @@ where
     }
 }
 
+// 1xx informational responses except 101 which is the final response of protocol upgrade.
+fn is_interim(status: StatusCode) -> bool {
+    status.is_informational() && status != StatusCode::SWITCHING_PROTOCOLS
+}
+
 async fn send_body<S, B, E>(
     stream: &mut S,
     mut encoder: TransferCoding,
@@ -212,6 +254,14 @@ async fn try_read_response<S>(
 where
     S: AsyncIo,
 {
+    // multiple response heads can be received in one read(an interim response followed by the final one).
+    // decode remaining bytes of previous read before reading from io.
+    if !buf.is_empty() {
+        if let Some(res) = ctx.decode_head(buf)? {
+            return Ok(Some(res));
+        }
+    }
+
     loop {
         match xitca_unsafe_collection::bytes::read_buf(stream, buf) {
             Ok(n) => {
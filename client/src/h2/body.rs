@@ -11,6 +11,7 @@ use futures_core::stream::Stream;
 use crate::{
     body::BodyError,
     bytes::{Buf, Bytes, BytesMut},
+    pool::shared::StreamGuard,
 };
 
 type Tx = h2::SendStream<Bytes>;
@@ -24,6 +25,8 @@ pub struct ResponseBody {
     // TODO: use new type and import from xitca_http?
     pub(crate) tx: Tx,
     want_poll_cap: bool,
+    // keep stream counted as in flight on it's connection until body is dropped.
+    pub(crate) stream_guard: Option<StreamGuard>,
 }
 
 impl ResponseBody {
@@ -32,6 +35,7 @@ impl ResponseBody {
             tx,
             rx,
             want_poll_cap: false,
+            stream_guard: None,
         }
     }
 
@@ -1,4 +1,4 @@
-use core::{cmp, future::poll_fn, pin::pin};
+use core::{cmp, future::poll_fn, pin::pin, task::Poll};
 
 use ::h2::{client, Reason};
 use futures_core::stream::Stream;
@@ -128,6 +128,13 @@ where
     Ok(res)
 }
 
+// check if connection is closed or received GOAWAY frame from server. in both case no new
+// request can be sent on it. GOAWAY is reported as connection error by poll_ready even when it's
+// graceful and streams below it's last stream id are still in flight.
+pub(crate) async fn is_closed(conn: &mut Connection) -> bool {
+    poll_fn(|cx| Poll::Ready(matches!(conn.poll_ready(cx), Poll::Ready(Err(_))))).await
+}
+
 pub(crate) async fn handshake<S>(stream: S) -> Result<Connection, Error>
 where
     S: AsyncIo + Send + 'static,
@@ -1,3 +1,3 @@
 mod dispatcher;
 
-pub(crate) use dispatcher::{handshake, send};
+pub(crate) use dispatcher::{handshake, is_closed, send};
@@ -29,6 +29,9 @@
 //!
 //! ## Customize core feature
 //! Please reference [ClientBuilder::resolver] and [ClientBuilder::tls_connector]
+//!
+//! ## Customize tls
+//! Please reference [ClientBuilder::rustls_with_config] and [ClientBuilder::openssl_with_config]
 
 #![forbid(unsafe_code)]
 
@@ -74,6 +77,9 @@ pub use self::service::{HttpService, Service, ServiceRequest};
 pub use self::timeout::TimeoutConfig;
 pub use self::tls::{connector::Connector, TlsStream};
 
+#[cfg(any(feature = "openssl", feature = "rustls", feature = "rustls-ring-crypto"))]
+pub use self::tls::config::TlsConfig;
+
 // re-export http crate.
 pub use xitca_http::http;
 
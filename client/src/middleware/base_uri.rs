@@ -0,0 +1,119 @@
+use crate::{
+    error::{Error, InvalidUri},
+    http::{uri::PathAndQuery, Uri},
+    response::Response,
+    service::{Service, ServiceRequest},
+};
+
+/// middleware resolving relative request uri against a base uri.
+///
+/// Request uri without scheme and authority(for example `/users?page=1`) would be appended to
+/// the base uri's path. Query of base uri(for example `?key=x`) is kept and request's query is
+/// appended after it. Absolute request uri is left untouched.
+pub struct BaseUri<S> {
+    service: S,
+    base: Uri,
+}
+
+impl<S> BaseUri<S> {
+    /// construct a new base uri middleware with given http service type and base uri.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use xitca_client::{ClientBuilder, http::Uri, middleware::BaseUri};
+    /// let base = Uri::from_static("https://example.com/api/v1");
+    ///
+    /// // request to "/users" would be sent to "https://example.com/api/v1/users"
+    /// let builder = ClientBuilder::new()
+    ///     .middleware(|service| BaseUri::new(service, base));
+    /// ```
+    ///
+    /// # Panics
+    /// When base uri does not contain scheme and authority.
+    pub fn new(service: S, base: Uri) -> Self {
+        assert!(
+            base.scheme().is_some() && base.authority().is_some(),
+            "base uri must be absolute with scheme and authority"
+        );
+        Self { service, base }
+    }
+}
+
+impl<'r, 'c, S> Service<ServiceRequest<'r, 'c>> for BaseUri<S>
+where
+    S: for<'r2, 'c2> Service<ServiceRequest<'r2, 'c2>, Response = Response, Error = Error> + Send + Sync,
+{
+    type Response = Response;
+    type Error = Error;
+
+    async fn call(&self, req: ServiceRequest<'r, 'c>) -> Result<Self::Response, Self::Error> {
+        if req.req.uri().scheme().is_none() && req.req.uri().authority().is_none() {
+            let uri = resolve(&self.base, req.req.uri())?;
+            *req.req.uri_mut() = uri;
+        }
+
+        self.service.call(req).await
+    }
+}
+
+fn resolve(base: &Uri, uri: &Uri) -> Result<Uri, Error> {
+    let base_path = base.path().trim_end_matches('/');
+    let path_and_query = uri.path_and_query().map(PathAndQuery::as_str).unwrap_or("/");
+    let (path, query) = path_and_query.split_once('?').unwrap_or((path_and_query, ""));
+
+    let sep = if path.is_empty() || path.starts_with('/') {
+        ""
+    } else {
+        "/"
+    };
+
+    let mut path_and_query = format!("{base_path}{sep}{path}");
+
+    // query of base uri goes first and request's query is appended to it.
+    let mut queries = [base.query().unwrap_or(""), query]
+        .into_iter()
+        .filter(|q| !q.is_empty());
+    if let Some(query) = queries.next() {
+        path_and_query.push('?');
+        path_and_query.push_str(query);
+        for query in queries {
+            path_and_query.push('&');
+            path_and_query.push_str(query);
+        }
+    }
+
+    let path_and_query = PathAndQuery::try_from(path_and_query)?;
+
+    Uri::builder()
+        .scheme(base.scheme().unwrap().clone())
+        .authority(base.authority().unwrap().clone())
+        .path_and_query(path_and_query)
+        .build()
+        .map_err(|_| InvalidUri::MissingPathQuery.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn resolve_relative() {
+        let base = Uri::from_static("https://foo.bar/api/v1/");
+
+        let uri = resolve(&base, &Uri::from_static("/users?page=1")).unwrap();
+        assert_eq!(uri, "https://foo.bar/api/v1/users?page=1");
+
+        let base = Uri::from_static("https://foo.bar");
+
+        let uri = resolve(&base, &Uri::from_static("/users")).unwrap();
+        assert_eq!(uri, "https://foo.bar/users");
+
+        let base = Uri::from_static("https://foo.bar/api?key=x");
+
+        let uri = resolve(&base, &Uri::from_static("/users?page=1")).unwrap();
+        assert_eq!(uri, "https://foo.bar/api/users?key=x&page=1");
+
+        let uri = resolve(&base, &Uri::from_static("/users")).unwrap();
+        assert_eq!(uri, "https://foo.bar/api/users?key=x");
+    }
+}
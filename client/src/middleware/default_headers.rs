@@ -0,0 +1,93 @@
+use crate::{
+    error::Error,
+    http::header::HeaderMap,
+    response::Response,
+    service::{Service, ServiceRequest},
+};
+
+/// middleware adding default headers to every request. headers already presented in request
+/// would not be overwritten.
+pub struct DefaultHeaders<S> {
+    service: S,
+    headers: HeaderMap,
+}
+
+impl<S> DefaultHeaders<S> {
+    /// construct a new default headers middleware with given http service type and headers.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use xitca_client::{ClientBuilder, http::header::{HeaderMap, HeaderValue, USER_AGENT}, middleware::DefaultHeaders};
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert(USER_AGENT, HeaderValue::from_static("xitca-client"));
+    ///
+    /// let builder = ClientBuilder::new()
+    ///     .middleware(|service| DefaultHeaders::new(service, headers));
+    /// ```
+    pub const fn new(service: S, headers: HeaderMap) -> Self {
+        Self { service, headers }
+    }
+}
+
+impl<'r, 'c, S> Service<ServiceRequest<'r, 'c>> for DefaultHeaders<S>
+where
+    S: for<'r2, 'c2> Service<ServiceRequest<'r2, 'c2>, Response = Response, Error = Error> + Send + Sync,
+{
+    type Response = Response;
+    type Error = Error;
+
+    async fn call(&self, req: ServiceRequest<'r, 'c>) -> Result<Self::Response, Self::Error> {
+        let headers = req.req.headers_mut();
+
+        for name in self.headers.keys() {
+            if !headers.contains_key(name) {
+                for value in self.headers.get_all(name) {
+                    headers.append(name.clone(), value.clone());
+                }
+            }
+        }
+
+        self.service.call(req).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        body::{BoxBody, ResponseBody},
+        http::{
+            self,
+            header::{HeaderValue, ACCEPT, USER_AGENT},
+        },
+        service::mock_service,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn default_headers() {
+        let (handle, service) = mock_service();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static("xitca"));
+        headers.append(ACCEPT, HeaderValue::from_static("text/plain"));
+        headers.append(ACCEPT, HeaderValue::from_static("text/html"));
+
+        let service = DefaultHeaders::new(service, headers);
+
+        let handler = |req: http::Request<BoxBody>| {
+            assert_eq!(req.headers().get(USER_AGENT).unwrap(), "custom");
+            assert_eq!(req.headers().get_all(ACCEPT).iter().count(), 2);
+            Ok(http::Response::new(ResponseBody::Eof))
+        };
+
+        let mut req = http::Request::builder()
+            .uri("http://foo.bar/")
+            .header(USER_AGENT, "custom")
+            .body(Default::default())
+            .unwrap();
+
+        let req = handle.mock(&mut req, handler);
+        service.call(req).await.unwrap();
+    }
+}
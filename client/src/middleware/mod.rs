@@ -1,5 +1,7 @@
 //! middleware offer extended functionality to http client.
 
+mod base_uri;
+mod default_headers;
 mod redirect;
 
 #[cfg(feature = "compress")]
@@ -8,4 +10,6 @@ mod decompress;
 #[cfg(feature = "compress")]
 pub use decompress::Decompress;
 
+pub use base_uri::BaseUri;
+pub use default_headers::DefaultHeaders;
 pub use redirect::FollowRedirect;
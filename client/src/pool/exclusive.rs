@@ -1,17 +1,17 @@
 use core::{
     hash::Hash,
     ops::{Deref, DerefMut},
-    time::Duration,
 };
 
 use std::{
     collections::{HashMap, VecDeque},
     sync::{Arc, Mutex},
-    time::Instant,
 };
 
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
+use super::{ConnState, PoolConfig};
+
 type Entries<K, C> = HashMap<K, (Arc<Semaphore>, VecDeque<PooledConn<C>>)>;
 
 #[doc(hidden)]
@@ -21,6 +21,7 @@ pub struct Pool<K, C> {
     // the pool can have unbounded entries with different keys but a single
     // entry can only have up to cap size of C inside it.
     cap: usize,
+    config: PoolConfig,
 }
 
 impl<K, C> Clone for Pool<K, C> {
@@ -28,6 +29,7 @@ impl<K, C> Clone for Pool<K, C> {
         Self {
             conns: self.conns.clone(),
             cap: self.cap,
+            config: self.config,
         }
     }
 }
@@ -36,10 +38,11 @@ impl<K, C> Pool<K, C>
 where
     K: Eq + Hash + Clone,
 {
-    pub(crate) fn with_capacity(cap: usize) -> Self {
+    pub(crate) fn with_config(cap: usize, config: PoolConfig) -> Self {
         Self {
             conns: Arc::new(Mutex::new(HashMap::new())),
             cap,
+            config,
         }
     }
 
@@ -78,7 +81,7 @@ where
                 };
 
                 while let Some(conn) = queue.pop_front() {
-                    if !conn.state.is_expired() {
+                    if !conn.state.is_expired(&self.config) {
                         return AcquireOutput::Conn(Conn {
                             pool: self.clone(),
                             key,
@@ -111,7 +114,7 @@ where
                 // try to acquire a permit immediately.
                 // when failed the entry is already at full capacity. in that case just throw the connection.
                 let res = permits.try_acquire();
-                if res.is_ok() {
+                if res.is_ok() && queue.len() < self.config.max_idle_per_host {
                     queue.push_back(PooledConn {
                         conn,
                         state: ConnState::new(),
@@ -129,6 +132,17 @@ where
             }
         }
     }
+
+    // remove expired idle connections from pool. entries with no idle connection and no
+    // connection in use are removed as well.
+    pub(crate) fn sweep(&self) {
+        let cap = self.cap;
+        let config = &self.config;
+        self.conns.lock().unwrap().retain(|_, (permits, queue)| {
+            queue.retain(|conn| !conn.state.is_expired(config));
+            !queue.is_empty() || permits.available_permits() != cap
+        });
+    }
 }
 
 // create new entry inside pool and reserve one permit immediately from the entry capacity.
@@ -209,15 +223,17 @@ where
 {
     fn drop(&mut self) {
         if let Some(mut conn) = self.conn.take() {
-            if conn.state.is_expired() || self.destroy_on_drop {
+            if conn.state.is_expired(&self.pool.config) || self.destroy_on_drop {
                 return;
             }
 
             let mut conns = self.pool.conns.lock().unwrap();
 
             if let Some((_, queue)) = conns.get_mut(&self.key) {
-                conn.state.update_idle();
-                queue.push_back(conn);
+                if queue.len() < self.pool.config.max_idle_per_host {
+                    conn.state.update_idle();
+                    queue.push_back(conn);
+                }
             }
 
             let _ = self.permit;
@@ -253,31 +269,6 @@ impl<C> DerefMut for PooledConn<C> {
     }
 }
 
-#[derive(Clone, Copy)]
-struct ConnState {
-    born: Instant,
-    idle_since: Instant,
-}
-
-impl ConnState {
-    fn new() -> Self {
-        let now = Instant::now();
-
-        Self {
-            born: now,
-            idle_since: now,
-        }
-    }
-
-    fn update_idle(&mut self) {
-        self.idle_since = Instant::now();
-    }
-
-    fn is_expired(&self) -> bool {
-        self.born.elapsed() > Duration::from_secs(3600) || self.idle_since.elapsed() > Duration::from_secs(600)
-    }
-}
-
 pub struct Spawner<'a, K, C>
 where
     K: Eq + Hash + Clone,
@@ -315,3 +306,40 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn idle_eviction() {
+        let mut config = PoolConfig::new();
+        config.idle_timeout = Duration::from_millis(100);
+        config.max_idle_per_host = 1;
+
+        let pool = Pool::<&str, usize>::with_config(2, config);
+
+        let AcquireOutput::Spawner(spawner) = pool.acquire("key").await else {
+            panic!("empty pool must output spawner")
+        };
+        spawner.spawned(996);
+
+        let conn = match pool.acquire("key").await {
+            AcquireOutput::Conn(conn) => conn,
+            AcquireOutput::Spawner(_) => panic!("pool must output spawned connection"),
+        };
+        assert_eq!(*conn, 996);
+        drop(conn);
+
+        // connection over max idle limit is not returned to pool.
+        pool.try_add("key", 251);
+        assert_eq!(pool.conns.lock().unwrap().get("key").unwrap().1.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        pool.sweep();
+        assert!(pool.conns.lock().unwrap().is_empty());
+    }
+}
@@ -7,3 +7,97 @@ pub(crate) mod exclusive;
 // pool for http/2 and http/3 connections. connection is shared owned and ownership is reference
 // counted between pool and caller.
 pub(crate) mod shared;
+
+use core::time::Duration;
+
+use std::time::Instant;
+
+use tokio::{
+    task::{spawn, JoinHandle},
+    time::{interval_at, MissedTickBehavior},
+};
+
+use crate::connection::{ConnectionExclusive, ConnectionKey, ConnectionShared};
+
+/// eviction policy shared by both exclusive and shared pool.
+#[derive(Clone, Copy)]
+pub(crate) struct PoolConfig {
+    // max duration a connection can stay idle inside pool.
+    pub(crate) idle_timeout: Duration,
+    // max duration a connection can live since it's creation. used for rebalancing connections
+    // across multiple server instances behind load balancer.
+    pub(crate) max_lifetime: Duration,
+    // max idle connections that can be kept inside pool for a single remote host.
+    pub(crate) max_idle_per_host: usize,
+    // max multiplexed connections that can be opened for a single remote host. additional connection
+    // would only be opened when existing ones are saturated.
+    pub(crate) max_multiplexed_per_host: usize,
+}
+
+impl PoolConfig {
+    pub(crate) const fn new() -> Self {
+        Self {
+            idle_timeout: Duration::from_secs(600),
+            max_lifetime: Duration::from_secs(3600),
+            max_idle_per_host: usize::MAX,
+            max_multiplexed_per_host: 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ConnState {
+    born: Instant,
+    idle_since: Instant,
+}
+
+impl ConnState {
+    fn new() -> Self {
+        let now = Instant::now();
+
+        Self {
+            born: now,
+            idle_since: now,
+        }
+    }
+
+    fn update_idle(&mut self) {
+        self.idle_since = Instant::now();
+    }
+
+    fn is_expired(&self, config: &PoolConfig) -> bool {
+        self.born.elapsed() > config.max_lifetime || self.idle_since.elapsed() > config.idle_timeout
+    }
+}
+
+// async task periodically evicting expired connections from pools.
+// task is stopped when sweeper is dropped.
+pub(crate) struct PoolSweeper {
+    handle: JoinHandle<()>,
+}
+
+impl Drop for PoolSweeper {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+impl PoolSweeper {
+    pub(crate) fn new(
+        dur: Duration,
+        exclusive: exclusive::Pool<ConnectionKey, ConnectionExclusive>,
+        shared: shared::Pool<ConnectionKey, ConnectionShared>,
+    ) -> Self {
+        let handle = spawn(async move {
+            let mut interval = interval_at(tokio::time::Instant::now() + dur, dur);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                let _ = interval.tick().await;
+                exclusive.sweep();
+                shared.sweep();
+            }
+        });
+
+        Self { handle }
+    }
+}
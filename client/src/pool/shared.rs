@@ -3,19 +3,31 @@ use core::hash::Hash;
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex},
+    time::Instant,
 };
 
 use tokio::sync::Notify;
 
+use super::{ConnState, PoolConfig};
+
+/// trait for multiplexed connection types that can serve multiple requests concurrently.
+pub(crate) trait Multiplex {
+    /// max number of concurrent requests connection can serve. when reached pool would try to
+    /// spawn additional connection to the same remote host.
+    fn max_concurrent_streams(&self) -> usize;
+}
+
 #[doc(hidden)]
 pub struct Pool<K, C> {
-    conns: Arc<Mutex<HashMap<K, PooledConnection<C>>>>,
+    conns: Arc<Mutex<HashMap<K, Entry<C>>>>,
+    config: PoolConfig,
 }
 
 impl<K, C> Clone for Pool<K, C> {
     fn clone(&self) -> Self {
         Self {
             conns: self.conns.clone(),
+            config: self.config,
         }
     }
 }
@@ -23,52 +35,133 @@ impl<K, C> Clone for Pool<K, C> {
 impl<K, C> Pool<K, C>
 where
     K: Eq + Hash + Clone,
-    C: Clone,
+    C: Clone + Multiplex,
 {
-    pub(crate) fn with_capacity(_: usize) -> Self {
+    pub(crate) fn with_config(config: PoolConfig) -> Self {
         Self {
             conns: Arc::new(Mutex::new(HashMap::new())),
+            config,
         }
     }
 
-    pub(crate) async fn acquire(&self, key: impl Into<K>) -> AcquireOutput<'_, K, C>
-    where
-        C: Clone,
-    {
+    pub(crate) async fn acquire(&self, key: impl Into<K>) -> AcquireOutput<'_, K, C> {
         let key = key.into();
         loop {
             let notify = {
                 let mut conns = self.conns.lock().unwrap();
-                match conns.get(&key) {
-                    Some(PooledConnection::Conn(c)) => {
-                        return AcquireOutput::Conn(Conn {
-                            pool: self.clone(),
-                            key,
-                            conn: c.clone(),
-                            destroy_on_drop: false,
-                        })
-                    }
-                    Some(PooledConnection::Spawning(notify)) => notify.clone(),
-                    None => {
-                        let notify = Arc::new(Notify::new());
-                        conns.insert(key.clone(), PooledConnection::Spawning(notify.clone()));
-                        return AcquireOutput::Spawner(Spawner {
-                            pool: self,
-                            key,
-                            notify,
-                            fulfilled: false,
-                        });
+
+                let Some(entry) = conns.get_mut(&key) else {
+                    let notify = Arc::new(Notify::new());
+                    conns.insert(key.clone(), Entry::spawning(notify.clone()));
+                    return AcquireOutput::Spawner(self.spawner(key, notify));
+                };
+
+                // expired connections are dropped from pool and in flight requests on them would
+                // still be able to finish.
+                entry.conns.retain_mut(|conn| !conn.is_expired(&self.config));
+
+                // prefer the connection with the least in flight requests.
+                let idx = (0..entry.conns.len()).min_by_key(|idx| entry.conns[*idx].in_flight());
+
+                if let Some(idx) = idx {
+                    let conn = &mut entry.conns[idx];
+                    if conn.in_flight() < conn.conn.max_concurrent_streams() {
+                        return AcquireOutput::Conn(self.conn(key, conn));
                     }
                 }
+
+                // all connections are saturated and pool can spawn more connection.
+                if entry.spawning.is_none() && entry.conns.len() < self.config.max_multiplexed_per_host {
+                    let notify = Arc::new(Notify::new());
+                    entry.spawning = Some(notify.clone());
+                    return AcquireOutput::Spawner(self.spawner(key, notify));
+                }
+
+                match (idx, &entry.spawning) {
+                    // pool can not spawn more connection. over subscribe the least busy connection and
+                    // let the protocol queue requests internally.
+                    (Some(idx), _) => return AcquireOutput::Conn(self.conn(key, &mut entry.conns[idx])),
+                    // connection is spawning and there is nothing to use. wait for the spawning outcome.
+                    (None, Some(notify)) => notify.clone(),
+                    (None, None) => unreachable!("pool can not be at full capacity with no connection"),
+                }
             };
             notify.notified().await;
         }
     }
+
+    // remove expired connections from pool.
+    pub(crate) fn sweep(&self) {
+        let config = &self.config;
+        self.conns.lock().unwrap().retain(|_, entry| {
+            entry.conns.retain_mut(|conn| !conn.is_expired(config));
+            !entry.is_empty()
+        });
+    }
+
+    fn conn(&self, key: K, conn: &mut PooledConnection<C>) -> Conn<K, C> {
+        conn.state.update_idle();
+        Conn {
+            pool: self.clone(),
+            key,
+            conn: conn.conn.clone(),
+            stream: conn.stream.clone(),
+            destroy_on_drop: false,
+        }
+    }
+
+    fn spawner(&self, key: K, notify: Arc<Notify>) -> Spawner<'_, K, C> {
+        Spawner {
+            pool: self,
+            key,
+            notify,
+            fulfilled: false,
+        }
+    }
 }
 
-enum PooledConnection<C> {
-    Conn(C),
-    Spawning(Arc<Notify>),
+struct Entry<C> {
+    conns: Vec<PooledConnection<C>>,
+    spawning: Option<Arc<Notify>>,
+}
+
+impl<C> Entry<C> {
+    fn spawning(notify: Arc<Notify>) -> Self {
+        Self {
+            conns: Vec::new(),
+            spawning: Some(notify),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.conns.is_empty() && self.spawning.is_none()
+    }
+}
+
+struct PooledConnection<C> {
+    conn: C,
+    state: ConnState,
+    // reference counted token shared with every request in flight on connection. it records the time
+    // when a request on connection is finished.
+    stream: Arc<Mutex<Instant>>,
+}
+
+impl<C> PooledConnection<C> {
+    fn in_flight(&self) -> usize {
+        Arc::strong_count(&self.stream) - 1
+    }
+
+    // connection with request in flight is not idle. otherwise it's idle since the last request
+    // on it is finished.
+    fn is_expired(&mut self, config: &PoolConfig) -> bool {
+        if self.in_flight() > 0 {
+            self.state.update_idle();
+        } else {
+            let finished = *self.stream.lock().unwrap();
+            self.state.idle_since = self.state.idle_since.max(finished);
+        }
+        self.state.is_expired(config)
+    }
 }
 
 pub(crate) enum AcquireOutput<'a, K, C>
@@ -86,6 +179,7 @@ where
     pool: Pool<K, C>,
     key: K,
     pub(crate) conn: C,
+    stream: Arc<Mutex<Instant>>,
     destroy_on_drop: bool,
 }
 
@@ -104,10 +198,15 @@ where
     K: Eq + Hash + Clone,
 {
     fn drop(&mut self) {
+        finish_stream(&self.stream);
+
         if self.destroy_on_drop {
             let mut conns = self.pool.conns.lock().unwrap();
-            if matches!(conns.get(&self.key), Some(PooledConnection::Conn(_))) {
-                conns.remove(&self.key);
+            if let Some(entry) = conns.get_mut(&self.key) {
+                entry.conns.retain(|conn| !Arc::ptr_eq(&conn.stream, &self.stream));
+                if entry.is_empty() {
+                    conns.remove(&self.key);
+                }
             }
         }
     }
@@ -119,7 +218,13 @@ where
 {
     fn drop(&mut self) {
         if !self.fulfilled {
-            self.pool.conns.lock().unwrap().remove(&self.key);
+            let mut conns = self.pool.conns.lock().unwrap();
+            if let Some(entry) = conns.get_mut(&self.key) {
+                entry.spawning = None;
+                if entry.is_empty() {
+                    conns.remove(&self.key);
+                }
+            }
         }
 
         self.notify.notify_waiters();
@@ -132,14 +237,29 @@ where
 {
     pub(crate) fn spawned(mut self, conn: C) {
         self.fulfilled = true;
-        if let Some(PooledConnection::Spawning(notify)) = self
-            .pool
-            .conns
-            .lock()
-            .unwrap()
-            .insert(self.key.clone(), PooledConnection::Conn(conn))
-        {
-            notify.notify_waiters();
+
+        let conn = PooledConnection {
+            conn,
+            state: ConnState::new(),
+            stream: Arc::new(Mutex::new(Instant::now())),
+        };
+
+        let mut conns = self.pool.conns.lock().unwrap();
+        match conns.get_mut(&self.key) {
+            Some(entry) => {
+                entry.spawning = None;
+                entry.conns.push(conn);
+            }
+            // entry is removed by other party while spawning. in this case start a new one.
+            None => {
+                conns.insert(
+                    self.key.clone(),
+                    Entry {
+                        conns: vec![conn],
+                        spawning: None,
+                    },
+                );
+            }
         }
     }
 }
@@ -151,4 +271,126 @@ where
     pub(crate) fn destroy_on_drop(&mut self) {
         self.destroy_on_drop = true;
     }
+
+    /// a guard type counting as one in flight request on connection until it's dropped.
+    /// can be used to extend the lifetime of a request beyond [Conn] itself.
+    pub(crate) fn stream_guard(&self) -> StreamGuard {
+        StreamGuard(self.stream.clone())
+    }
+}
+
+pub(crate) struct StreamGuard(Arc<Mutex<Instant>>);
+
+impl Drop for StreamGuard {
+    fn drop(&mut self) {
+        finish_stream(&self.0);
+    }
+}
+
+fn finish_stream(stream: &Mutex<Instant>) {
+    *stream.lock().unwrap() = Instant::now();
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct MockConn(usize);
+
+    impl Multiplex for MockConn {
+        fn max_concurrent_streams(&self) -> usize {
+            1
+        }
+    }
+
+    #[tokio::test]
+    async fn saturated_spawn() {
+        let mut config = PoolConfig::new();
+        config.max_multiplexed_per_host = 2;
+
+        let pool = Pool::<&str, MockConn>::with_config(config);
+
+        let AcquireOutput::Spawner(spawner) = pool.acquire("key").await else {
+            panic!("empty pool must output spawner")
+        };
+        spawner.spawned(MockConn(1));
+
+        let AcquireOutput::Conn(conn1) = pool.acquire("key").await else {
+            panic!("pool must output spawned connection")
+        };
+        assert_eq!(conn1.conn.0, 1);
+
+        // first connection is saturated. a new one must be spawned.
+        let AcquireOutput::Spawner(spawner) = pool.acquire("key").await else {
+            panic!("saturated pool must output spawner")
+        };
+        spawner.spawned(MockConn(2));
+
+        let AcquireOutput::Conn(conn2) = pool.acquire("key").await else {
+            panic!("pool must output spawned connection")
+        };
+        assert_eq!(conn2.conn.0, 2);
+
+        // pool is at full capacity. the least busy connection is over subscribed.
+        let guard = conn2.stream_guard();
+        let AcquireOutput::Conn(conn3) = pool.acquire("key").await else {
+            panic!("pool at full capacity must output connection")
+        };
+        assert_eq!(conn3.conn.0, 1);
+
+        drop((guard, conn2, conn3));
+
+        // draining connection is removed from pool.
+        let mut conn1 = conn1;
+        conn1.destroy_on_drop();
+        drop(conn1);
+
+        let AcquireOutput::Conn(conn) = pool.acquire("key").await else {
+            panic!("pool must output connection")
+        };
+        assert_eq!(conn.conn.0, 2);
+    }
+
+    #[tokio::test]
+    async fn sweep_in_flight() {
+        let mut config = PoolConfig::new();
+        config.idle_timeout = Duration::from_millis(50);
+
+        let pool = Pool::<&str, MockConn>::with_config(config);
+
+        let AcquireOutput::Spawner(spawner) = pool.acquire("key").await else {
+            panic!("empty pool must output spawner")
+        };
+        spawner.spawned(MockConn(1));
+
+        let AcquireOutput::Conn(conn) = pool.acquire("key").await else {
+            panic!("pool must output spawned connection")
+        };
+
+        // request outlives idle timeout.
+        let guard = conn.stream_guard();
+        drop(conn);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // connection with request in flight is not idle.
+        pool.sweep();
+        assert!(pool.conns.lock().unwrap().contains_key("key"));
+
+        // connection is idle since request is finished.
+        drop(guard);
+        pool.sweep();
+        let AcquireOutput::Conn(conn) = pool.acquire("key").await else {
+            panic!("connection must not be evicted right after request is finished")
+        };
+        assert_eq!(conn.conn.0, 1);
+        drop(conn);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        pool.sweep();
+        assert!(pool.conns.lock().unwrap().is_empty());
+    }
 }
@@ -1,4 +1,6 @@
-use core::{marker::PhantomData, time::Duration};
+use core::{fmt, marker::PhantomData, time::Duration};
+
+use std::sync::Arc;
 
 use futures_core::Stream;
 
@@ -9,7 +11,7 @@ use crate::{
     error::Error,
     http::{
         self, const_header_value,
-        header::{HeaderMap, HeaderValue, CONTENT_LENGTH, CONTENT_TYPE},
+        header::{HeaderMap, HeaderValue, InvalidHeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, EXPECT},
         Extensions, Method, Version,
     },
     response::Response,
@@ -64,6 +66,24 @@ impl RequestBuilder<'_, marker::Http> {
         }
     }
 
+    #[cfg(feature = "urlencoded")]
+    /// Use url encoded form object as request body.
+    ///
+    /// [CONTENT_TYPE] header would be set with value: `application/x-www-form-urlencoded`.
+    pub fn form(mut self, body: impl serde::ser::Serialize) -> Self {
+        match serde_urlencoded::to_string(&body) {
+            Ok(body) => {
+                self.headers_mut()
+                    .insert(CONTENT_TYPE, const_header_value::APPLICATION_WWW_FORM_URLENCODED);
+                self.body(body)
+            }
+            Err(e) => {
+                self.push_error(e.into());
+                self
+            }
+        }
+    }
+
     /// Use pre allocated bytes as request body.
     ///
     /// Input type must implement [From] trait with [Bytes].
@@ -210,6 +230,88 @@ impl<'a, M> RequestBuilder<'a, M> {
         self
     }
 
+    /// Set `Expect: 100-continue` header. With http/1 the request body would not be sent until server
+    /// responds with `100 Continue` interim response. When server responds with a final status code
+    /// instead the body would not be sent at all and the response is returned early. This is useful
+    /// for large upload where server could reject request by it's head.
+    ///
+    /// When server does not respond in time the body would be sent anyway.
+    /// See [ClientBuilder::set_expect_continue_timeout] for detail.
+    ///
+    /// [ClientBuilder::set_expect_continue_timeout]: crate::builder::ClientBuilder::set_expect_continue_timeout
+    pub fn expect_continue(mut self) -> Self {
+        self.headers_mut()
+            .insert(EXPECT, HeaderValue::from_static("100-continue"));
+        self
+    }
+
+    /// Set a callback for 1xx informational responses(e.g. `103 Early Hints`) received before the
+    /// final response. `101 Switching Protocols` is a final response and would not be passed to callback.
+    ///
+    /// Only http/1 request is supported for now.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # fn hints(client: &xitca_client::Client) {
+    /// let req = client.get("http://localhost/").on_informational(|res| {
+    ///     if res.status().as_u16() == 103 {
+    ///         println!("early hints: {:?}", res.headers());
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn on_informational<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&http::Response<()>) + Send + Sync + 'static,
+    {
+        self.extensions_mut().insert(OnInformational(Arc::new(f)));
+        self
+    }
+
+    /// Set [AUTHORIZATION] header with bearer token.
+    pub fn bearer_auth(mut self, token: impl fmt::Display) -> Self {
+        match bearer_auth(token) {
+            Ok(value) => {
+                self.headers_mut().insert(AUTHORIZATION, value);
+            }
+            Err(e) => self.push_error(e.into()),
+        }
+        self
+    }
+
+    #[cfg(feature = "basic-auth")]
+    /// Set [AUTHORIZATION] header with username and optional password in basic authentication scheme.
+    pub fn basic_auth(mut self, username: impl fmt::Display, password: Option<impl fmt::Display>) -> Self {
+        match basic_auth(username, password) {
+            Ok(value) => {
+                self.headers_mut().insert(AUTHORIZATION, value);
+            }
+            Err(e) => self.push_error(e.into()),
+        }
+        self
+    }
+
+    #[cfg(feature = "urlencoded")]
+    /// Serialize given object as url encoded query string and append it to request uri.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # fn query(client: &xitca_client::Client) {
+    /// // uri would be "http://localhost/users?page=2&size=10"
+    /// let req = client.get("http://localhost/users?page=2").query([("size", 10)]);
+    /// # }
+    /// ```
+    pub fn query(mut self, query: impl serde::ser::Serialize) -> Self {
+        let res = serde_urlencoded::to_string(&query)
+            .map_err(Error::from)
+            .and_then(|query| append_query(self.req.uri(), &query));
+        match res {
+            Ok(uri) => *self.req.uri_mut() = uri,
+            Err(e) => self.push_error(e),
+        }
+        self
+    }
+
     fn map_body<B, E>(mut self, b: B) -> RequestBuilder<'a, M>
     where
         B: Stream<Item = Result<Bytes, E>> + Send + 'static,
@@ -219,3 +321,87 @@ impl<'a, M> RequestBuilder<'a, M> {
         self
     }
 }
+
+// callback for 1xx informational responses. stored in request extensions so it's passed through
+// middlewares to protocol dispatcher.
+#[derive(Clone)]
+pub(crate) struct OnInformational(Arc<InformationalFn>);
+
+type InformationalFn = dyn Fn(&http::Response<()>) + Send + Sync;
+
+impl OnInformational {
+    pub(crate) fn call(&self, res: &http::Response<()>) {
+        (self.0)(res)
+    }
+}
+
+pub(crate) fn bearer_auth(token: impl fmt::Display) -> Result<HeaderValue, InvalidHeaderValue> {
+    let mut value = HeaderValue::try_from(format!("Bearer {token}"))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+#[cfg(feature = "basic-auth")]
+pub(crate) fn basic_auth(
+    username: impl fmt::Display,
+    password: Option<impl fmt::Display>,
+) -> Result<HeaderValue, InvalidHeaderValue> {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    let credential = match password {
+        Some(password) => format!("{username}:{password}"),
+        None => format!("{username}:"),
+    };
+
+    let mut value = HeaderValue::try_from(format!("Basic {}", STANDARD.encode(credential)))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+#[cfg(feature = "urlencoded")]
+fn append_query(uri: &http::Uri, query: &str) -> Result<http::Uri, Error> {
+    if query.is_empty() {
+        return Ok(uri.clone());
+    }
+
+    let path_and_query = match uri.query() {
+        Some(q) if !q.is_empty() => format!("{}?{q}&{query}", uri.path()),
+        _ => format!("{}?{query}", uri.path()),
+    };
+
+    let mut parts = uri.clone().into_parts();
+    parts.path_and_query = Some(path_and_query.try_into()?);
+    http::Uri::from_parts(parts).map_err(|_| crate::error::InvalidUri::MissingPathQuery.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bearer() {
+        assert_eq!(bearer_auth("996").unwrap(), "Bearer 996");
+    }
+
+    #[cfg(feature = "basic-auth")]
+    #[test]
+    fn basic() {
+        assert_eq!(
+            basic_auth("aladdin", Some("opensesame")).unwrap(),
+            "Basic YWxhZGRpbjpvcGVuc2VzYW1l"
+        );
+        assert_eq!(basic_auth("aladdin", None::<&str>).unwrap(), "Basic YWxhZGRpbjo=");
+    }
+
+    #[cfg(feature = "urlencoded")]
+    #[test]
+    fn query() {
+        let uri = http::Uri::from_static("http://foo.bar/users?page=2");
+        let uri = append_query(&uri, &serde_urlencoded::to_string([("size", 10)]).unwrap()).unwrap();
+        assert_eq!(uri, "http://foo.bar/users?page=2&size=10");
+
+        let uri = http::Uri::from_static("/users");
+        let uri = append_query(&uri, "size=10").unwrap();
+        assert_eq!(uri, "/users?size=10");
+    }
+}
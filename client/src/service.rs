@@ -102,6 +102,17 @@ pub(crate) fn base_service() -> HttpService {
                 match version {
                     Version::HTTP_2 | Version::HTTP_3 => match client.shared_pool.acquire(&connect.uri).await {
                         shared::AcquireOutput::Conn(mut _conn) => {
+                            // connection could be draining after received GOAWAY from server. remove it from
+                            // pool and try again.
+                            #[cfg(feature = "http2")]
+                            #[allow(irrefutable_let_patterns)]
+                            if let crate::connection::ConnectionShared::H2(ref mut conn) = _conn.conn {
+                                if crate::h2::proto::is_closed(conn).await {
+                                    _conn.destroy_on_drop();
+                                    continue;
+                                }
+                            }
+
                             let mut _timer = Box::pin(tokio::time::sleep(timeout));
                             *req.version_mut() = version;
                             #[allow(unreachable_code)]
@@ -112,7 +123,10 @@ pub(crate) fn base_service() -> HttpService {
                                         .timeout(_timer.as_mut())
                                         .await
                                     {
-                                        Ok(Ok(res)) => {
+                                        Ok(Ok(mut res)) => {
+                                            if let crate::body::ResponseBody::H2(ref mut body) = *res.body_mut() {
+                                                body.stream_guard = Some(_conn.stream_guard());
+                                            }
                                             let timeout = client.timeout_config.response_timeout;
                                             Ok(Response::new(res, _timer, timeout))
                                         }
@@ -214,14 +228,26 @@ pub(crate) fn base_service() -> HttpService {
                     },
                     version => match client.exclusive_pool.acquire(&connect.uri).await {
                         exclusive::AcquireOutput::Conn(mut _conn) => {
+                            // connection could be closed by server while idling in pool. drop it and try again.
+                            #[cfg(feature = "http1")]
+                            if !crate::connection::is_exclusive_alive(&mut _conn).await {
+                                _conn.destroy_on_drop();
+                                continue;
+                            }
+
                             *req.version_mut() = version;
 
                             #[cfg(feature = "http1")]
                             {
                                 let mut timer = Box::pin(tokio::time::sleep(timeout));
-                                let res = crate::h1::proto::send(&mut *_conn, _date, req)
-                                    .timeout(timer.as_mut())
-                                    .await;
+                                let res = crate::h1::proto::send(
+                                    &mut *_conn,
+                                    _date,
+                                    req,
+                                    client.timeout_config.expect_continue_timeout,
+                                )
+                                .timeout(timer.as_mut())
+                                .await;
 
                                 return match res {
                                     Ok(Ok((res, buf, decoder, is_close))) => {
@@ -25,6 +25,11 @@ pub struct TimeoutConfig {
     /// Timeout for collecting response body.
     /// Default to 15 seconds.
     pub response_timeout: Duration,
+    /// Timeout for waiting server's `100 Continue` interim response before sending request body.
+    /// Only applies to http/1 request with `Expect: 100-continue` header. Request body would be sent
+    /// anyway when timeout is reached.
+    /// Default to 1 second.
+    pub expect_continue_timeout: Duration,
 }
 
 impl TimeoutConfig {
@@ -35,6 +40,7 @@ impl TimeoutConfig {
             tls_connect_timeout: Duration::from_secs(5),
             request_timeout: Duration::from_secs(15),
             response_timeout: Duration::from_secs(15),
+            expect_continue_timeout: Duration::from_secs(1),
         }
     }
 }
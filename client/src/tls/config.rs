@@ -0,0 +1,130 @@
+/// Configuration for tls connector used by http/1 and http/2 connections.
+///
+/// Apply it with [ClientBuilder::openssl_with_config] or [ClientBuilder::rustls_with_config].
+///
+/// # Examples
+/// ```rust
+/// use xitca_client::TlsConfig;
+///
+/// # fn config(cert: &[u8], key: &[u8], ca: &[u8]) {
+/// let config = TlsConfig::new()
+///     // client certificate chain and private key for mutual tls.
+///     .identity_pem(cert, key)
+///     // trust private certificate authority.
+///     .add_root_certificates_pem(ca)
+///     // only trust the private certificate authority.
+///     .disable_built_in_roots();
+/// # }
+/// ```
+///
+/// [ClientBuilder::openssl_with_config]: crate::ClientBuilder::openssl_with_config
+/// [ClientBuilder::rustls_with_config]: crate::ClientBuilder::rustls_with_config
+#[derive(Clone)]
+pub struct TlsConfig {
+    pub(crate) identity: Option<Identity>,
+    pub(crate) root_certs: Vec<Certificate>,
+    pub(crate) built_in_roots: bool,
+    pub(crate) server_name: Option<String>,
+    pub(crate) pins: Vec<[u8; 32]>,
+}
+
+#[derive(Clone)]
+pub(crate) enum Identity {
+    Pem { cert_chain: Vec<u8>, key: Vec<u8> },
+    Der { cert_chain: Vec<Vec<u8>>, key: Vec<u8> },
+}
+
+#[derive(Clone)]
+pub(crate) enum Certificate {
+    Pem(Vec<u8>),
+    Der(Vec<u8>),
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TlsConfig {
+    pub fn new() -> Self {
+        Self {
+            identity: None,
+            root_certs: Vec::new(),
+            built_in_roots: true,
+            server_name: None,
+            pins: Vec::new(),
+        }
+    }
+
+    /// Set client certificate chain and it's private key in PEM format. Used for mutual tls
+    /// authentication where server requires client to present it's certificate.
+    ///
+    /// `cert_chain` can contain multiple certificates where the first one must be the client's
+    /// end entity certificate.
+    pub fn identity_pem(mut self, cert_chain: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        self.identity = Some(Identity::Pem {
+            cert_chain: cert_chain.into(),
+            key: key.into(),
+        });
+        self
+    }
+
+    /// Set client certificate chain and it's private key in DER format. Used for mutual tls
+    /// authentication where server requires client to present it's certificate.
+    ///
+    /// The first certificate of `cert_chain` must be the client's end entity certificate.
+    pub fn identity_der(mut self, cert_chain: Vec<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        self.identity = Some(Identity::Der {
+            cert_chain,
+            key: key.into(),
+        });
+        self
+    }
+
+    /// Add certificate authorities in PEM format to trusted root certificates. `bundle` can
+    /// contain multiple certificates.
+    pub fn add_root_certificates_pem(mut self, bundle: impl Into<Vec<u8>>) -> Self {
+        self.root_certs.push(Certificate::Pem(bundle.into()));
+        self
+    }
+
+    /// Add certificate authority in DER format to trusted root certificates.
+    pub fn add_root_certificate_der(mut self, cert: impl Into<Vec<u8>>) -> Self {
+        self.root_certs.push(Certificate::Der(cert.into()));
+        self
+    }
+
+    /// Disable built in root certificates. Only certificates added by [TlsConfig::add_root_certificates_pem]
+    /// and [TlsConfig::add_root_certificate_der] would be trusted.
+    ///
+    /// Built in root certificates are webpki roots for rustls and system default certificate store for openssl.
+    pub fn disable_built_in_roots(mut self) -> Self {
+        self.built_in_roots = false;
+        self
+    }
+
+    /// Override server name used for tls SNI extension and certificate verification. By default
+    /// the host of request url is used.
+    pub fn server_name(mut self, name: impl Into<String>) -> Self {
+        self.server_name = Some(name.into());
+        self
+    }
+
+    /// Pin server's end entity certificate by it's SHA-256 fingerprint(hash of certificate in DER
+    /// format). Can be called multiple times and any pinned certificate would be accepted.
+    ///
+    /// When pinned certificate is presented together with trusted root certificates both the
+    /// certificate chain verification and pinning must pass. When built in root certificates are
+    /// disabled and no root certificate is added the certificate chain verification is skipped
+    /// and pinning becomes the only verification.
+    pub fn pin_sha256(mut self, fingerprint: [u8; 32]) -> Self {
+        self.pins.push(fingerprint);
+        self
+    }
+
+    // when no root certificate is trusted pinning becomes the only verification.
+    pub(crate) fn is_pin_only(&self) -> bool {
+        !self.pins.is_empty() && !self.built_in_roots && self.root_certs.is_empty()
+    }
+}
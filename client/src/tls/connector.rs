@@ -41,9 +41,14 @@ pub(crate) mod openssl {
     use xitca_http::bytes::BufMut;
     use xitca_tls::openssl::{
         self,
-        ssl::{SslConnector, SslMethod},
+        pkey::PKey,
+        sha::sha256,
+        ssl::{SslConnector, SslMethod, SslVerifyMode},
+        x509::{store::X509StoreBuilder, X509},
     };
 
+    use crate::tls::config::{Certificate, Identity, TlsConfig};
+
     use super::*;
 
     impl<'n> Service<(&'n str, TlsStream)> for SslConnector {
@@ -69,7 +74,22 @@ pub(crate) mod openssl {
         }
     }
 
-    pub(crate) fn connect(protocols: &[&[u8]]) -> Connector {
+    pub struct TlsConnector {
+        connector: SslConnector,
+        server_name: Option<String>,
+    }
+
+    impl<'n> Service<(&'n str, TlsStream)> for TlsConnector {
+        type Response = (TlsStream, Version);
+        type Error = Error;
+
+        async fn call(&self, (name, io): (&'n str, TlsStream)) -> Result<Self::Response, Self::Error> {
+            let name = self.server_name.as_deref().unwrap_or(name);
+            Service::call(&self.connector, (name, io)).await
+        }
+    }
+
+    pub(crate) fn connect(protocols: &[&[u8]], config: TlsConfig) -> Connector {
         let mut alpn = Vec::with_capacity(20);
         for proto in protocols {
             alpn.put_u8(proto.len() as u8);
@@ -81,31 +101,129 @@ pub(crate) mod openssl {
         ssl.set_alpn_protos(&alpn)
             .unwrap_or_else(|e| panic!("Can not set ALPN protocol: {e:?}"));
 
-        Box::new(ssl.build())
+        let pin_only = config.is_pin_only();
+
+        let TlsConfig {
+            identity,
+            root_certs,
+            built_in_roots,
+            server_name,
+            pins,
+        } = config;
+
+        if !built_in_roots {
+            ssl.set_cert_store(X509StoreBuilder::new().unwrap().build());
+        }
+
+        for cert in root_certs {
+            let certs = match cert {
+                Certificate::Pem(pem) => X509::stack_from_pem(&pem),
+                Certificate::Der(der) => X509::from_der(&der).map(|cert| vec![cert]),
+            }
+            .unwrap_or_else(|e| panic!("Can not parse root certificate: {e:?}"));
+
+            for cert in certs {
+                ssl.cert_store_mut()
+                    .add_cert(cert)
+                    .unwrap_or_else(|e| panic!("Can not add root certificate: {e:?}"));
+            }
+        }
+
+        if let Some(identity) = identity {
+            let (certs, key) = match identity {
+                Identity::Pem { cert_chain, key } => {
+                    (X509::stack_from_pem(&cert_chain), PKey::private_key_from_pem(&key))
+                }
+                Identity::Der { cert_chain, key } => (
+                    cert_chain.iter().map(|der| X509::from_der(der)).collect(),
+                    PKey::private_key_from_der(&key),
+                ),
+            };
+
+            let mut certs = certs
+                .unwrap_or_else(|e| panic!("Can not parse client certificate: {e:?}"))
+                .into_iter();
+            let key = key.unwrap_or_else(|e| panic!("Can not parse client private key: {e:?}"));
+
+            let cert = certs.next().expect("Client certificate chain must not be empty");
+            ssl.set_certificate(&cert)
+                .unwrap_or_else(|e| panic!("Can not set client certificate: {e:?}"));
+            for cert in certs {
+                ssl.add_extra_chain_cert(cert)
+                    .unwrap_or_else(|e| panic!("Can not set client certificate chain: {e:?}"));
+            }
+            ssl.set_private_key(&key)
+                .unwrap_or_else(|e| panic!("Can not set client private key: {e:?}"));
+            ssl.check_private_key()
+                .unwrap_or_else(|e| panic!("Client private key does not match certificate: {e:?}"));
+        }
+
+        if !pins.is_empty() {
+            ssl.set_verify_callback(SslVerifyMode::PEER, move |verified, ctx| {
+                let verified = verified || pin_only;
+
+                // only end entity certificate is pinned.
+                if ctx.error_depth() != 0 {
+                    return verified;
+                }
+
+                verified
+                    && ctx
+                        .current_cert()
+                        .and_then(|cert| cert.to_der().ok())
+                        .is_some_and(|der| pins.contains(&sha256(&der)))
+            });
+        }
+
+        Box::new(TlsConnector {
+            connector: ssl.build(),
+            server_name,
+        })
     }
 }
 
 #[cfg(any(feature = "rustls", feature = "rustls-ring-crypto"))]
 pub(crate) mod rustls {
+    use core::fmt;
+
     use std::sync::Arc;
 
     use webpki_roots::TLS_SERVER_ROOTS;
-    use xitca_tls::rustls::{self, pki_types::ServerName, ClientConfig, ClientConnection, RootCertStore};
+    use xitca_tls::rustls::{
+        self,
+        client::{
+            danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+            WebPkiServerVerifier,
+        },
+        crypto::{
+            hash::{Hash, HashAlgorithm},
+            verify_tls12_signature, verify_tls13_signature, CryptoProvider,
+        },
+        pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer, ServerName, UnixTime},
+        CertificateError, ClientConfig, ClientConnection, DigitallySignedStruct, RootCertStore, SignatureScheme,
+    };
+
+    use crate::tls::config::{Certificate, Identity, TlsConfig};
 
     use super::*;
 
-    pub struct TlsConnector(Arc<ClientConfig>);
+    pub struct TlsConnector {
+        config: Arc<ClientConfig>,
+        server_name: Option<String>,
+    }
 
     impl<'n> Service<(&'n str, TlsStream)> for TlsConnector {
         type Response = (TlsStream, Version);
         type Error = Error;
 
         async fn call(&self, (name, io): (&'n str, TlsStream)) -> Result<Self::Response, Self::Error> {
+            let name = self.server_name.as_deref().unwrap_or(name);
+
             let name = ServerName::try_from(name)
                 .map_err(|_| crate::error::RustlsError::InvalidDnsName)?
                 .to_owned();
 
-            let conn = ClientConnection::new(self.0.clone(), name).unwrap();
+            let conn = ClientConnection::new(self.config.clone(), name).unwrap();
 
             let stream = rustls::TlsStream::handshake(io, conn)
                 .await
@@ -123,17 +241,194 @@ pub(crate) mod rustls {
         }
     }
 
-    pub(crate) fn connect(protocols: &[&[u8]]) -> Connector {
-        let mut root_certs = RootCertStore::empty();
+    pub(crate) fn connect(protocols: &[&[u8]], config: TlsConfig) -> Connector {
+        let pin_only = config.is_pin_only();
 
-        root_certs.extend(TLS_SERVER_ROOTS.iter().cloned());
+        let TlsConfig {
+            identity,
+            root_certs,
+            built_in_roots,
+            server_name,
+            pins,
+        } = config;
 
-        let mut config = ClientConfig::builder()
-            .with_root_certificates(root_certs)
-            .with_no_client_auth();
+        let mut root_store = RootCertStore::empty();
+
+        if built_in_roots {
+            root_store.extend(TLS_SERVER_ROOTS.iter().cloned());
+        }
+
+        for cert in root_certs {
+            let certs = match cert {
+                Certificate::Pem(pem) => CertificateDer::pem_slice_iter(&pem)
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap_or_else(|e| panic!("Can not parse root certificate: {e:?}")),
+                Certificate::Der(der) => vec![CertificateDer::from(der)],
+            };
+
+            for cert in certs {
+                root_store
+                    .add(cert)
+                    .unwrap_or_else(|e| panic!("Can not add root certificate: {e:?}"));
+            }
+        }
+
+        let builder = ClientConfig::builder();
+
+        let builder = if pins.is_empty() {
+            builder.with_root_certificates(root_store)
+        } else {
+            let provider = builder.crypto_provider().clone();
+
+            let verifier = (!pin_only).then(|| {
+                WebPkiServerVerifier::builder_with_provider(Arc::new(root_store), provider.clone())
+                    .build()
+                    .unwrap_or_else(|e| panic!("Can not build certificate verifier: {e:?}"))
+            });
+
+            let verifier = PinnedVerifier::new(verifier, pins, provider);
+
+            builder.dangerous().with_custom_certificate_verifier(Arc::new(verifier))
+        };
+
+        let mut config = match identity {
+            Some(identity) => {
+                let (cert_chain, key) = match identity {
+                    Identity::Pem { cert_chain, key } => (
+                        CertificateDer::pem_slice_iter(&cert_chain)
+                            .collect::<Result<Vec<_>, _>>()
+                            .unwrap_or_else(|e| panic!("Can not parse client certificate: {e:?}")),
+                        PrivateKeyDer::from_pem_slice(&key)
+                            .unwrap_or_else(|e| panic!("Can not parse client private key: {e:?}")),
+                    ),
+                    Identity::Der { cert_chain, key } => (
+                        cert_chain.into_iter().map(CertificateDer::from).collect(),
+                        PrivateKeyDer::try_from(key)
+                            .unwrap_or_else(|e| panic!("Can not parse client private key: {e:?}")),
+                    ),
+                };
+
+                builder
+                    .with_client_auth_cert(cert_chain, key)
+                    .unwrap_or_else(|e| panic!("Can not set client certificate: {e:?}"))
+            }
+            None => builder.with_no_client_auth(),
+        };
 
         config.alpn_protocols = protocols.iter().map(|p| p.to_vec()).collect();
 
-        Box::new(TlsConnector(Arc::new(config)))
+        Box::new(TlsConnector {
+            config: Arc::new(config),
+            server_name,
+        })
+    }
+
+    // certificate verifier checking server's end entity certificate against pinned SHA-256 fingerprints.
+    // when inner verifier is absent the certificate chain is not verified.
+    struct PinnedVerifier {
+        inner: Option<Arc<WebPkiServerVerifier>>,
+        pins: Vec<[u8; 32]>,
+        sha256: &'static dyn Hash,
+        provider: Arc<CryptoProvider>,
+    }
+
+    impl fmt::Debug for PinnedVerifier {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("PinnedVerifier").finish_non_exhaustive()
+        }
+    }
+
+    impl PinnedVerifier {
+        fn new(inner: Option<Arc<WebPkiServerVerifier>>, pins: Vec<[u8; 32]>, provider: Arc<CryptoProvider>) -> Self {
+            let sha256 = provider
+                .cipher_suites
+                .iter()
+                .filter_map(|suite| suite.tls13())
+                .map(|suite| suite.common.hash_provider)
+                .find(|hash| hash.algorithm() == HashAlgorithm::SHA256)
+                .expect("Crypto provider must support SHA-256 for certificate pinning");
+
+            Self {
+                inner,
+                pins,
+                sha256,
+                provider,
+            }
+        }
+    }
+
+    impl ServerCertVerifier for PinnedVerifier {
+        fn verify_server_cert(
+            &self,
+            end_entity: &CertificateDer<'_>,
+            intermediates: &[CertificateDer<'_>],
+            server_name: &ServerName<'_>,
+            ocsp_response: &[u8],
+            now: UnixTime,
+        ) -> Result<ServerCertVerified, rustls::Error> {
+            if let Some(ref inner) = self.inner {
+                inner.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)?;
+            }
+
+            let fingerprint = self.sha256.hash(end_entity.as_ref());
+
+            if self.pins.iter().any(|pin| pin.as_slice() == fingerprint.as_ref()) {
+                Ok(ServerCertVerified::assertion())
+            } else {
+                Err(rustls::Error::InvalidCertificate(
+                    CertificateError::ApplicationVerificationFailure,
+                ))
+            }
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            verify_tls12_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            verify_tls13_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.provider.signature_verification_algorithms.supported_schemes()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rustls-ring-crypto"))]
+mod test {
+    use crate::tls::config::TlsConfig;
+
+    #[test]
+    fn rustls_with_config() {
+        let cert = std::fs::read("../examples/cert/cert.pem").unwrap();
+        let key = std::fs::read("../examples/cert/key.pem").unwrap();
+
+        let config = TlsConfig::new()
+            .identity_pem(cert.clone(), key)
+            .add_root_certificates_pem(cert)
+            .disable_built_in_roots()
+            .server_name("localhost")
+            .pin_sha256([0; 32]);
+
+        let _ = super::rustls::connect(&[b"h2"], config.clone());
+
+        // pin only verification.
+        let config = TlsConfig {
+            root_certs: Vec::new(),
+            ..config
+        };
+        assert!(config.is_pin_only());
+        let _ = super::rustls::connect(&[b"h2"], config);
     }
 }
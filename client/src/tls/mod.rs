@@ -1,3 +1,6 @@
 pub(crate) mod connector;
 
+#[cfg(any(feature = "openssl", feature = "rustls", feature = "rustls-ring-crypto"))]
+pub(crate) mod config;
+
 pub type TlsStream = Box<dyn xitca_io::io::AsyncIoDyn + Send>;
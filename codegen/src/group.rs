@@ -0,0 +1,180 @@
+use core::mem;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Attribute, Error, Expr, ExprLit, Ident, Item, ItemMod, Lit, LitStr, Meta, PathSegment};
+
+use crate::route::{methods, middleware_name, path_lit, Args, MSG};
+
+pub(crate) fn route_group(attr: Args, mut input: ItemMod) -> Result<TokenStream, Error> {
+    let mut attrs = attr.vars.into_iter();
+
+    let prefix = attrs
+        .next()
+        .ok_or_else(|| Error::new(input.ident.span(), "expect #[route_group(<path>)]"))?;
+    let prefix = path_lit(&prefix).ok_or_else(|| Error::new(prefix.span(), "expect <path> to be string literal"))?;
+
+    let middlewares = attrs.collect::<Vec<_>>();
+
+    for middleware in middlewares.iter() {
+        let Expr::Assign(pair) = middleware else {
+            return Err(Error::new(middleware.span(), "expect '<name> = <value>' expression"));
+        };
+        let name = middleware_name(pair)?;
+        if name != "enclosed" && name != "enclosed_fn" {
+            return Err(Error::new(name.span(), "expect enclosed or enclosed_fn"));
+        }
+    }
+
+    let Some((_, ref mut items)) = input.content else {
+        return Err(Error::new(
+            input.span(),
+            "route_group can only be applied to inline module. e.g. mod api { .. }",
+        ));
+    };
+
+    let mut group = Group {
+        prefix: prefix.trim_end_matches('/').to_owned(),
+        middlewares: &middlewares,
+        depth: 1,
+        rewrite: true,
+        routes: Vec::new(),
+    };
+
+    group.items(items)?;
+
+    Ok(quote! { #input }.into())
+}
+
+struct Group<'a> {
+    prefix: String,
+    middlewares: &'a [Expr],
+    // module depth relative to the scope group attribute is written in.
+    depth: usize,
+    // attributes are only rewritten for items belong to the group. items of nested group are visited
+    // for detecting duplicate routes.
+    rewrite: bool,
+    // registered full path, method and handler name.
+    routes: Vec<(String, Ident, Ident)>,
+}
+
+impl Group<'_> {
+    fn items(&mut self, items: &mut [Item]) -> Result<(), Error> {
+        for item in items.iter_mut() {
+            match item {
+                Item::Fn(func) => {
+                    let ident = &func.sig.ident;
+                    for attr in func.attrs.iter_mut().filter(|attr| is_attr(attr, "route")) {
+                        let (path, vars) = self.visit(attr)?;
+
+                        let Some(Expr::Assign(method)) = vars.get(1) else {
+                            return Err(Error::new(ident.span(), MSG));
+                        };
+
+                        for method in methods(&method.right)? {
+                            if let Some((_, _, f)) = self.routes.iter().find(|(p, m, _)| *p == path && *m == method) {
+                                return Err(Error::new_spanned(
+                                    &*attr,
+                                    format!(
+                                        "duplicate route {} {path}. it's already declared by {f}",
+                                        method.to_string().to_uppercase()
+                                    ),
+                                ));
+                            }
+                            self.routes.push((path.clone(), method, ident.clone()));
+                        }
+                    }
+                }
+                Item::Mod(module) => match module.attrs.iter_mut().find(|attr| is_attr(attr, "route_group")) {
+                    // nested group would prefix and enclose it's own routes. only the group itself is
+                    // prefixed and enclosed. it's routes are visited with full prefix for detecting
+                    // duplicate routes between groups.
+                    Some(attr) => {
+                        let (prefix, _) = self.visit(attr)?;
+                        if let Some((_, ref mut items)) = module.content {
+                            let prefix = mem::replace(&mut self.prefix, prefix.trim_end_matches('/').to_owned());
+                            let rewrite = mem::replace(&mut self.rewrite, false);
+                            self.items(items)?;
+                            self.prefix = prefix;
+                            self.rewrite = rewrite;
+                        }
+                    }
+                    None => {
+                        if let Some((_, ref mut items)) = module.content {
+                            self.depth += 1;
+                            self.items(items)?;
+                            self.depth -= 1;
+                        }
+                    }
+                },
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    // full path of attribute. when rewriting prefix path of attribute and append group middlewares
+    // to it.
+    fn visit(&self, attr: &mut Attribute) -> Result<(String, Vec<Expr>), Error> {
+        let Meta::List(ref mut list) = attr.meta else {
+            return Err(Error::new(attr.span(), MSG));
+        };
+
+        let mut vars = list.parse_args_with(Args::parse_vars)?;
+
+        let Some(Expr::Lit(ExprLit { lit: Lit::Str(lit), .. })) = vars.first_mut() else {
+            return Err(Error::new(
+                list.span(),
+                "expect <path> to be string literal inside route_group",
+            ));
+        };
+
+        let path = format!("{}{}", self.prefix, lit.value());
+
+        if self.rewrite {
+            *lit = LitStr::new(&path, lit.span());
+            vars.extend(self.middlewares.iter().map(|middleware| self.relocate(middleware)));
+            list.tokens = quote! { #(#vars),* };
+        }
+
+        Ok((path, vars))
+    }
+}
+
+impl Group<'_> {
+    // middleware is written outside of group module. relative path of it is prefixed with super
+    // so it resolves to the same item inside module where route is declared.
+    fn relocate(&self, middleware: &Expr) -> Expr {
+        let mut middleware = middleware.clone();
+        if let Expr::Assign(ref mut pair) = middleware {
+            let path = match *pair.right {
+                Expr::Path(ref mut expr) => Some(&mut expr.path),
+                Expr::Call(ref mut call) => match *call.func {
+                    Expr::Path(ref mut expr) => Some(&mut expr.path),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            if let Some(path) = path {
+                let is_relative = path.leading_colon.is_none()
+                    && path
+                        .segments
+                        .first()
+                        .is_some_and(|seg| seg.ident != "crate" && seg.ident != "$crate");
+                if is_relative {
+                    for _ in 0..self.depth {
+                        path.segments
+                            .insert(0, PathSegment::from(Ident::new("super", path.span())));
+                    }
+                }
+            }
+        }
+        middleware
+    }
+}
+
+fn is_attr(attr: &Attribute, name: &str) -> bool {
+    attr.path().segments.last().is_some_and(|seg| seg.ident == name)
+}
@@ -1,4 +1,5 @@
 mod error;
+mod group;
 mod route;
 mod service;
 mod state;
@@ -23,9 +24,15 @@ pub fn state_impl(item: TokenStream) -> TokenStream {
 /// - `"path"`: string literal represent path register to http router.
 ///   `"/foo"` for example.  
 /// - `method = <method>`: function path of http method register to http router.
-///   `method = get` for example.
+///   `method = get` for example. multiple methods can be registered with array.
+///   `method = [get, post]` for example.
 /// - `enclosed = <type>`: typed middleware applied to route.
 /// - `enclosed_fn = <async function>`: async function as middleware applied to route
+///
+/// # Duplicate route
+/// routes with the same path and method inside `#[route_group]` module fail to compile with error
+/// pointing to the duplicate route and the handler it conflicts with. routes outside of group are
+/// not checked.
 /// ```
 #[proc_macro_attribute]
 pub fn route(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -34,6 +41,34 @@ pub fn route(attr: TokenStream, item: TokenStream) -> TokenStream {
     route::route(attr, item).unwrap_or_else(|e| e.to_compile_error().into())
 }
 
+/// attribute macro for grouping `#[route]` in inline module.
+///
+/// # Pattern
+/// ```plain
+/// #[route_group("prefix"[, attributes])]
+/// mod name { .. }
+/// ```
+///
+/// # Attributes
+/// - `"prefix"`: string literal prepended to path of every route in module.
+///   `"/api"` for example.
+/// - `enclosed = <type>`: typed middleware applied to every route in module.
+/// - `enclosed_fn = <async function>`: async function as middleware applied to every route in module.
+///
+/// group middlewares are applied after route's own middlewares. routes inside nested inline
+/// module are part of the group. nested `#[route_group]` module is prefixed and enclosed as a
+/// whole. middleware path is resolved from the scope where `#[route_group]` is written.
+///
+/// # Duplicate route
+/// routes with the same full path and method in the group and it's nested groups fail to compile with
+/// error pointing to the later `#[route]` attribute.
+#[proc_macro_attribute]
+pub fn route_group(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = syn::parse_macro_input!(attr);
+    let item = syn::parse_macro_input!(item);
+    group::route_group(attr, item).unwrap_or_else(|e| e.to_compile_error().into())
+}
+
 #[proc_macro_attribute]
 pub fn error_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
     let item = syn::parse_macro_input!(item);
@@ -1,24 +1,30 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    Error, Expr, FnArg, GenericArgument, ItemFn, PathArguments, Type,
+    visit_mut::{self, VisitMut},
+    Error, Expr, ExprAssign, ExprLit, FnArg, GenericArgument, Ident, ItemFn, Lifetime, Lit, PathArguments, ReturnType,
+    Type,
 };
 
+pub(crate) const MSG: &str = "not enough attributes. try add #[route(<path>, method = <method>)]";
+
+const METHODS: [&str; 9] = [
+    "get", "post", "put", "delete", "head", "options", "connect", "patch", "trace",
+];
+
 pub(crate) fn route(attr: Args, input: ItemFn) -> Result<TokenStream, Error> {
     let mut attrs = attr.vars.into_iter();
-    const MSG: &str = "not enough attributes. try add #[route(<path>, method = <method>)]";
 
     let path = attrs.next().ok_or_else(|| Error::new(input.span(), MSG))?;
 
-    // TODO: add support for multiple method = x.
     let method = attrs.next().ok_or_else(|| Error::new(input.sig.ident.span(), MSG))?;
     let Expr::Assign(method) = method else {
         return Err(Error::new(method.span(), "expect 'method = <method>'"));
     };
-    let method = &*method.right;
+    let methods = methods(&method.right)?;
 
     let mut middlewares = quote! {};
 
@@ -27,24 +33,13 @@ pub(crate) fn route(attr: Args, input: ItemFn) -> Result<TokenStream, Error> {
             return Err(Error::new(input.span(), "expect '<name> = <value>' expression"));
         };
 
-        let Expr::Path(ref name) = *pair.left else {
-            return Err(Error::new(pair.left.span(), "expect <name> to be path expression"));
-        };
-
-        let name = name
-            .path
-            .get_ident()
-            .ok_or_else(|| Error::new(name.span(), "expect enclosed or enclosed_fn path"))?;
+        let name = middleware_name(&pair)?;
         match name.to_string().as_str() {
             "enclosed_fn" => {
                 let Expr::Path(ref value) = *pair.right else {
                     return Err(Error::new(pair.right.span(), "expect <value> to be path expression"));
                 };
 
-                let value = value
-                    .path
-                    .get_ident()
-                    .ok_or_else(|| Error::new(value.span(), "expect function name"))?;
                 middlewares = quote! {
                     #middlewares.enclosed_fn(#value)
                 };
@@ -179,10 +174,36 @@ pub(crate) fn route(attr: Args, input: ItemFn) -> Result<TokenStream, Error> {
         quote! { ::xitca_web::handler::handler_sync_service }
     };
 
+    let route = match methods.as_slice() {
+        [method] => quote! { ::xitca_web::route::#method(#handler(#ident)#middlewares) },
+        methods => {
+            let methods = methods
+                .iter()
+                .map(|method| format_ident!("{}", method.to_string().to_uppercase()));
+            quote! {
+                ::xitca_web::route::Route::new([#(::xitca_web::http::Method::#methods),*])
+                    .route(#handler(#ident)#middlewares)
+            }
+        }
+    };
+
+    // argument and return types of handler for describing the route in OpenAPI document.
+    let arg_tys = input.sig.inputs.iter().filter_map(|arg| match arg {
+        FnArg::Typed(arg) => doc_type(&arg.ty),
+        FnArg::Receiver(_) => None,
+    });
+    let output_ty = match input.sig.output {
+        ReturnType::Type(_, ref ty) => doc_type(ty),
+        ReturnType::Default => None,
+    }
+    .unwrap_or_else(|| syn::parse_quote!(()));
+
     Ok(quote! {
         #[allow(non_camel_case_types)]
         #vis struct #ident;
 
+        ::xitca_web::codegen::__private::operation!(#ident, [#(#arg_tys),*], #output_ty);
+
         impl #generic_arg ::xitca_web::codegen::__private::TypedRoute<#state_ident> for #ident
         #where_clause
         {
@@ -205,26 +226,111 @@ pub(crate) fn route(attr: Args, input: ItemFn) -> Result<TokenStream, Error> {
 
                 use xitca_web::codegen::__private::IntoObject;
                 use xitca_web::WebContext;
-                use xitca_web::route::#method;
                 use xitca_web::service::ServiceExt;
 
-                WebContext::<'_, #state_ident>::into_object(#method(#handler(#ident)#middlewares))
+                WebContext::<'_, #state_ident>::into_object(#route)
             }
         }
     }
     .into())
 }
 
+// type usable outside of handler function. lifetimes are elided and impl trait type is not nameable.
+fn doc_type(ty: &Type) -> Option<Type> {
+    struct Elide(bool);
+
+    impl VisitMut for Elide {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+            if lifetime.ident != "static" {
+                lifetime.ident = Ident::new("_", lifetime.ident.span());
+            }
+        }
+
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            if let Type::ImplTrait(_) = ty {
+                self.0 = false;
+            }
+            visit_mut::visit_type_mut(self, ty);
+        }
+    }
+
+    let mut ty = ty.clone();
+    let mut elide = Elide(true);
+    elide.visit_type_mut(&mut ty);
+    elide.0.then_some(ty)
+}
+
+// parse `method = get` or `method = [get, post]`.
+pub(crate) fn methods(expr: &Expr) -> Result<Vec<Ident>, Error> {
+    let exprs = match expr {
+        Expr::Array(array) => array.elems.iter().collect(),
+        expr => vec![expr],
+    };
+
+    if exprs.is_empty() {
+        return Err(Error::new(expr.span(), "expect at least one method"));
+    }
+
+    let mut methods = Vec::<Ident>::with_capacity(exprs.len());
+
+    for expr in exprs {
+        let method = match expr {
+            Expr::Path(path) => path.path.get_ident(),
+            _ => None,
+        }
+        .ok_or_else(|| Error::new(expr.span(), "expect method name. e.g. get"))?;
+
+        if !METHODS.contains(&method.to_string().as_str()) {
+            return Err(Error::new(
+                method.span(),
+                format!("unknown method {method}. expect one of {}", METHODS.join(", ")),
+            ));
+        }
+
+        if methods.contains(method) {
+            return Err(Error::new(method.span(), format!("duplicate method {method}")));
+        }
+
+        methods.push(method.clone());
+    }
+
+    Ok(methods)
+}
+
+// name of middleware attribute. `enclosed` or `enclosed_fn`.
+pub(crate) fn middleware_name(pair: &ExprAssign) -> Result<&Ident, Error> {
+    let Expr::Path(ref name) = *pair.left else {
+        return Err(Error::new(pair.left.span(), "expect <name> to be path expression"));
+    };
+
+    name.path
+        .get_ident()
+        .ok_or_else(|| Error::new(name.span(), "expect enclosed or enclosed_fn path"))
+}
+
+// string literal of route path.
+pub(crate) fn path_lit(path: &Expr) -> Option<String> {
+    match path {
+        Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Some(lit.value()),
+        _ => None,
+    }
+}
+
 pub struct Args {
-    vars: Vec<Expr>,
+    pub(crate) vars: Vec<Expr>,
 }
 
-impl Parse for Args {
-    fn parse(input: ParseStream) -> Result<Self, Error> {
+impl Args {
+    pub(crate) fn parse_vars(input: ParseStream) -> Result<Vec<Expr>, Error> {
         let vars = Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(input)?;
+        Ok(vars.into_iter().collect())
+    }
+}
 
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self, Error> {
         Ok(Args {
-            vars: vars.into_iter().collect(),
+            vars: Self::parse_vars(input)?,
         })
     }
 }
@@ -22,8 +22,12 @@ use pin_project_lite::pin_project;
 use super::{
     bytes::{Buf, Bytes, BytesMut},
     error::BodyError,
+    http::HeaderMap,
 };
 
+#[cfg(any(feature = "http1", feature = "http2", feature = "http3"))]
+use super::http::{header, HeaderName};
+
 // this is a crate level hack to hint for none body type.
 // A body type with this size hint means the body MUST not be polled/collected by anyone.
 pub const fn none_body_hint() -> (usize, Option<usize>) {
@@ -53,6 +57,26 @@ pub enum RequestBody {
     None,
 }
 
+impl RequestBody {
+    /// take trailer fields received after request body data. fields not allowed in trailer section
+    /// are removed.
+    ///
+    /// `None` is returned when request body is not fully consumed, when client did not send any
+    /// trailer field or when the body can not carry trailers. (e.g. Http/1 request without
+    /// `transfer-encoding: chunked`)
+    pub fn take_trailers(&mut self) -> Option<HeaderMap> {
+        match self {
+            #[cfg(feature = "http1")]
+            Self::H1(body) => body.take_trailers(),
+            #[cfg(feature = "http2")]
+            Self::H2(body) => body.take_trailers(),
+            #[cfg(feature = "http3")]
+            Self::H3(body) => body.take_trailers(),
+            Self::Unknown(_) | Self::None => None,
+        }
+    }
+}
+
 impl Stream for RequestBody {
     type Item = Result<Bytes, BodyError>;
 
@@ -480,6 +504,75 @@ impl<B> From<Cow<'static, str>> for ResponseBody<B> {
     }
 }
 
+/// A frame of response body.
+///
+/// Response body is a [Stream] with item type of `Result<T, E>` where `T: Into<Frame>`. Body
+/// yielding [Bytes] only produces data frames. A body can yield [Frame::Trailers] as it's last item
+/// to send trailer fields after body data.
+///
+/// Http/1 dispatcher encodes trailers when response is using `transfer-encoding: chunked` and drop
+/// them otherwise. Http/2 and Http/3 dispatchers always encode them. Fields not allowed in trailer
+/// section (`content-length`, `transfer-encoding`, `host` etc) are removed before encoding.
+#[derive(Debug)]
+pub enum Frame {
+    /// chunk of body data.
+    Data(Bytes),
+    /// trailer fields sent after body data. body is not polled after it's yielded.
+    Trailers(HeaderMap),
+}
+
+impl From<Bytes> for Frame {
+    fn from(bytes: Bytes) -> Self {
+        Self::Data(bytes)
+    }
+}
+
+impl From<HeaderMap> for Frame {
+    fn from(trailers: HeaderMap) -> Self {
+        Self::Trailers(trailers)
+    }
+}
+
+// fields can not be sent in trailer section as they control message framing, routing, request
+// modifiers, authentication and response control.
+// See <https://www.rfc-editor.org/rfc/rfc9110#section-6.5.1>
+#[cfg(any(feature = "http1", feature = "http2", feature = "http3"))]
+const FORBIDDEN_TRAILERS: [HeaderName; 23] = [
+    header::AUTHORIZATION,
+    header::CONNECTION,
+    header::CONTENT_LENGTH,
+    header::COOKIE,
+    header::EXPECT,
+    header::HOST,
+    header::IF_MATCH,
+    header::IF_MODIFIED_SINCE,
+    header::IF_NONE_MATCH,
+    header::IF_RANGE,
+    header::IF_UNMODIFIED_SINCE,
+    header::LOCATION,
+    header::MAX_FORWARDS,
+    header::PROXY_AUTHENTICATE,
+    header::PROXY_AUTHORIZATION,
+    header::RANGE,
+    header::RETRY_AFTER,
+    header::SET_COOKIE,
+    header::TE,
+    header::TRAILER,
+    header::TRANSFER_ENCODING,
+    header::UPGRADE,
+    header::WWW_AUTHENTICATE,
+];
+
+#[cfg(any(feature = "http1", feature = "http2", feature = "http3"))]
+// remove fields not allowed in trailer section. connection specific fields are removed too.
+pub(crate) fn remove_forbidden_trailers(trailers: &mut HeaderMap) {
+    for name in FORBIDDEN_TRAILERS.iter() {
+        trailers.remove(name);
+    }
+    trailers.remove("keep-alive");
+    trailers.remove("proxy-connection");
+}
+
 /// Body size hint.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum BodySize {
@@ -523,4 +616,36 @@ mod test {
         let body = BoxBody::new(NoneBody::<Bytes>::default());
         assert_eq!(BodySize::from_stream(&body), BodySize::None);
     }
+
+    #[cfg(any(feature = "http1", feature = "http2", feature = "http3"))]
+    #[test]
+    fn forbidden_trailers() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", header::HeaderValue::from_static("0"));
+        trailers.insert(header::CONTENT_LENGTH, header::HeaderValue::from_static("3"));
+        trailers.insert(header::HOST, header::HeaderValue::from_static("localhost"));
+        trailers.insert("keep-alive", header::HeaderValue::from_static("timeout=5"));
+
+        remove_forbidden_trailers(&mut trailers);
+
+        assert_eq!(trailers.len(), 1);
+        assert_eq!(trailers.get("grpc-status").unwrap(), "0");
+    }
+
+    #[cfg(any(feature = "http1", feature = "http2", feature = "http3"))]
+    #[test]
+    fn allowed_trailers() {
+        let mut trailers = HeaderMap::new();
+        trailers.insert(header::CONTENT_TYPE, header::HeaderValue::from_static("text/plain"));
+        trailers.insert(header::CACHE_CONTROL, header::HeaderValue::from_static("no-cache"));
+        trailers.insert(header::VARY, header::HeaderValue::from_static("accept"));
+        trailers.insert(header::SET_COOKIE, header::HeaderValue::from_static("id=1"));
+
+        remove_forbidden_trailers(&mut trailers);
+
+        assert_eq!(trailers.len(), 3);
+        assert_eq!(trailers.get(header::CONTENT_TYPE).unwrap(), "text/plain");
+        assert_eq!(trailers.get(header::CACHE_CONTROL).unwrap(), "no-cache");
+        assert_eq!(trailers.get(header::VARY).unwrap(), "accept");
+    }
 }
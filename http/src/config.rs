@@ -30,6 +30,65 @@ pub struct HttpServiceConfig<
     pub(crate) request_head_timeout: Duration,
     pub(crate) tls_accept_timeout: Duration,
     pub(crate) peek_protocol: bool,
+    pub(crate) proxy_protocol: bool,
+    pub(crate) tls_peer_certificates: bool,
+    pub(crate) h2c_upgrade: bool,
+    pub(crate) h2: H2Config,
+}
+
+/// Http/2 specific configuration. `None` means the default value of `h2` crate is used.
+#[derive(Copy, Clone)]
+pub(crate) struct H2Config {
+    pub(crate) max_concurrent_streams: Option<u32>,
+    pub(crate) initial_window_size: Option<u32>,
+    pub(crate) initial_connection_window_size: Option<u32>,
+    pub(crate) max_frame_size: Option<u32>,
+    pub(crate) max_header_list_size: Option<u32>,
+    pub(crate) max_send_buffer_size: Option<usize>,
+    pub(crate) keep_alive_interval: Option<Duration>,
+    pub(crate) keep_alive_timeout: Option<Duration>,
+}
+
+impl H2Config {
+    const fn new() -> Self {
+        Self {
+            max_concurrent_streams: None,
+            initial_window_size: None,
+            initial_connection_window_size: None,
+            max_frame_size: None,
+            max_header_list_size: None,
+            max_send_buffer_size: None,
+            keep_alive_interval: None,
+            keep_alive_timeout: None,
+        }
+    }
+
+    #[cfg(feature = "http2")]
+    pub(crate) fn builder(&self) -> ::h2::server::Builder {
+        let mut builder = ::h2::server::Builder::new();
+        builder.enable_connect_protocol();
+
+        if let Some(max) = self.max_concurrent_streams {
+            builder.max_concurrent_streams(max);
+        }
+        if let Some(size) = self.initial_window_size {
+            builder.initial_window_size(size);
+        }
+        if let Some(size) = self.initial_connection_window_size {
+            builder.initial_connection_window_size(size);
+        }
+        if let Some(size) = self.max_frame_size {
+            builder.max_frame_size(size);
+        }
+        if let Some(size) = self.max_header_list_size {
+            builder.max_header_list_size(size);
+        }
+        if let Some(size) = self.max_send_buffer_size {
+            builder.max_send_buffer_size(size);
+        }
+
+        builder
+    }
 }
 
 impl Default for HttpServiceConfig {
@@ -46,6 +105,10 @@ impl HttpServiceConfig {
             request_head_timeout: Duration::from_secs(5),
             tls_accept_timeout: Duration::from_secs(3),
             peek_protocol: false,
+            proxy_protocol: false,
+            tls_peer_certificates: false,
+            h2c_upgrade: false,
+            h2: H2Config::new(),
         }
     }
 }
@@ -65,6 +128,9 @@ impl<const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIM
     ///
     /// connection have not done any IO after duration would be closed. IO operation
     /// can possibly result in reset of the duration.
+    ///
+    /// Http/2 connection does not use it directly. It's the default value of
+    /// [HttpServiceConfig::h2_keep_alive_interval].
     pub fn keep_alive_timeout(mut self, dur: Duration) -> Self {
         self.keep_alive_timeout = dur;
         self
@@ -122,12 +188,144 @@ impl<const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIM
     /// of alpn negotiation.
     ///
     /// This API is used to bypass alpn setting from tls and enable Http/2 protocol over
-    /// plain Tcp connection.
+    /// plain Tcp connection. Connection starting with Http/2 connection preface is served
+    /// as Http/2 prior knowledge(h2c) and all others are served as Http/1. It applies to both
+    /// Tcp and Unix domain socket connections.
     pub fn peek_protocol(mut self) -> Self {
         self.peek_protocol = true;
         self
     }
 
+    /// Enable PROXY protocol for Tcp connections.
+    ///
+    /// It applies to Tcp connections of `HttpServiceBuilder::new` and all connections of
+    /// `HttpServiceBuilder::h1` and `HttpServiceBuilder::h2`. Http/3 and Unix domain socket
+    /// connections of `HttpServiceBuilder::new` are not affected. io-uring services do not support
+    /// it and fail to build when it's enabled.
+    ///
+    /// Every connection must start with a PROXY protocol v1 or v2 header sent by load balancer in
+    /// front of server. The header is read before tls handshake and source address in it overrides
+    /// the socket address of connection. connection without a valid header is closed. The header
+    /// must be received within the duration of [HttpServiceConfig::tls_accept_timeout].
+    ///
+    /// Only enable it when all connections are from trusted proxies as the source address can be
+    /// forged by anyone able to connect to the server directly.
+    pub fn proxy_protocol(mut self) -> Self {
+        self.proxy_protocol = true;
+        self
+    }
+
+    /// Enable collecting client's certificates of tls connection for [TlsInfo::peer_certificate]
+    /// and [TlsInfo::peer_certificate_chain].
+    ///
+    /// Certificates are copied from tls session for every connection when enabled. Only enable it
+    /// when client certificates are needed (mutual tls).
+    ///
+    /// [TlsInfo::peer_certificate]: crate::http::TlsInfo::peer_certificate
+    /// [TlsInfo::peer_certificate_chain]: crate::http::TlsInfo::peer_certificate_chain
+    pub fn tls_peer_certificates(mut self) -> Self {
+        self.tls_peer_certificates = true;
+        self
+    }
+
+    /// Enable upgrading Http/1 connection to Http/2 cleartext(h2c) with `Upgrade: h2c` request header.
+    ///
+    /// Upgrade only happens on plain text Tcp and Unix domain socket connections. Tls connections
+    /// ignore the header and keep serving Http/1 as RFC 9113 section 3.1 forbids h2c over tls.
+    /// Request body of upgrade request is not supported and such request is served as Http/1.
+    /// `HTTP2-Settings` header of upgrade request is applied as client's initial settings and
+    /// request with malformed header is responded with `400 Bad Request`.
+    /// It has no effect when either `http1` or `http2` feature is not enabled.
+    pub fn h2c_upgrade(mut self) -> Self {
+        self.h2c_upgrade = true;
+        self
+    }
+
+    /// Define max number of concurrent streams a Http/2 connection can have.
+    ///
+    /// Default to no limit.
+    pub fn h2_max_concurrent_streams(mut self, max: u32) -> Self {
+        self.h2.max_concurrent_streams = Some(max);
+        self
+    }
+
+    /// Define initial flow control window size of Http/2 stream.
+    ///
+    /// Default to 65,535 bytes.
+    pub fn h2_initial_window_size(mut self, size: u32) -> Self {
+        self.h2.initial_window_size = Some(size);
+        self
+    }
+
+    /// Define initial flow control window size of Http/2 connection.
+    ///
+    /// Default to 65,535 bytes.
+    pub fn h2_initial_connection_window_size(mut self, size: u32) -> Self {
+        self.h2.initial_connection_window_size = Some(size);
+        self
+    }
+
+    /// Define max frame size of Http/2 connection. Value must be in range of 16,384 and 16,777,215.
+    ///
+    /// Default to 16,384 bytes.
+    ///
+    /// # Panics
+    /// When value is out of range.
+    pub fn h2_max_frame_size(mut self, size: u32) -> Self {
+        assert!(
+            (16_384..=16_777_215).contains(&size),
+            "max frame size must be in range of 16,384 and 16,777,215"
+        );
+        self.h2.max_frame_size = Some(size);
+        self
+    }
+
+    /// Define max size of request header list of Http/2 connection.
+    ///
+    /// Default to 16mb.
+    pub fn h2_max_header_list_size(mut self, size: u32) -> Self {
+        self.h2.max_header_list_size = Some(size);
+        self
+    }
+
+    /// Define max send buffer size of Http/2 stream.
+    ///
+    /// Default to 400kb.
+    pub fn h2_max_send_buffer_size(mut self, size: usize) -> Self {
+        self.h2.max_send_buffer_size = Some(size);
+        self
+    }
+
+    /// Define interval of Http/2 ping frames sent to idle connection for keeping it alive.
+    ///
+    /// A single timer drives both ping sending and connection liveness of Http/2. There is no
+    /// separate idle timeout: a ping is sent after every interval without in flight request and
+    /// connection is kept open as long as peer answers it in time. See
+    /// [HttpServiceConfig::h2_keep_alive_timeout] for how long the answer is waited for.
+    ///
+    /// Default to the same value of [HttpServiceConfig::keep_alive_timeout]. Changing
+    /// keep_alive_timeout changes Http/2 ping interval too unless this value is set explicitly.
+    pub fn h2_keep_alive_interval(mut self, dur: Duration) -> Self {
+        self.h2.keep_alive_interval = Some(dur);
+        self
+    }
+
+    /// Define duration of how long to wait for Http/2 pong frame before closing the connection.
+    ///
+    /// Default to 10 times of [HttpServiceConfig::h2_keep_alive_interval]. When it's not set
+    /// explicitly changing the interval changes it too.
+    pub fn h2_keep_alive_timeout(mut self, dur: Duration) -> Self {
+        self.h2.keep_alive_timeout = Some(dur);
+        self
+    }
+
+    #[cfg(feature = "http2")]
+    pub(crate) fn h2_keep_alive(&self) -> (Duration, Duration) {
+        let interval = self.h2.keep_alive_interval.unwrap_or(self.keep_alive_timeout);
+        let timeout = self.h2.keep_alive_timeout.unwrap_or(interval * 10);
+        (interval, timeout)
+    }
+
     #[doc(hidden)]
     /// A shortcut for mutating const generic params.
     pub fn mutate_const_generic<
@@ -143,6 +341,10 @@ impl<const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIM
             request_head_timeout: self.request_head_timeout,
             tls_accept_timeout: self.tls_accept_timeout,
             peek_protocol: self.peek_protocol,
+            proxy_protocol: self.proxy_protocol,
+            tls_peer_certificates: self.tls_peer_certificates,
+            h2c_upgrade: self.h2c_upgrade,
+            h2: self.h2,
         }
     }
 }
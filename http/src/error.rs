@@ -4,6 +4,7 @@ use std::{
     convert::Infallible,
     error::Error,
     fmt::{self, Debug, Formatter},
+    io,
 };
 
 use tracing::error;
@@ -20,6 +21,7 @@ pub enum HttpServiceError<S, B> {
     Timeout(TimeoutError),
     UnSupportedVersion(Version),
     Tls(TlsError),
+    ProxyProtocol(io::Error),
     #[cfg(feature = "http1")]
     H1(super::h1::Error<S, B>),
     // Http/2 error happen in HttpService handle.
@@ -43,6 +45,7 @@ where
             Self::UnSupportedVersion(ref protocol) => write!(f, "Protocol: {protocol:?} is not supported"),
             Self::Body(ref e) => Debug::fmt(e, f),
             Self::Tls(ref e) => Debug::fmt(e, f),
+            Self::ProxyProtocol(ref e) => write!(f, "invalid PROXY protocol header: {e}"),
             #[cfg(feature = "http1")]
             Self::H1(ref e) => Debug::fmt(e, f),
             #[cfg(feature = "http2")]
@@ -68,6 +71,8 @@ where
 #[derive(Debug)]
 pub enum TimeoutError {
     TlsAccept,
+    PeekProtocol,
+    ProxyProtocol,
     #[cfg(feature = "http2")]
     H2Handshake,
 }
@@ -10,7 +10,7 @@ use std::{collections::VecDeque, io, rc::Rc};
 
 use futures_core::stream::Stream;
 
-use crate::bytes::Bytes;
+use crate::{bytes::Bytes, http::HeaderMap};
 
 /// max buffer size 32k
 pub(crate) const MAX_BUFFER_SIZE: usize = 32_768;
@@ -55,6 +55,15 @@ impl RequestBody {
     pub(super) fn io_uring(body: super::dispatcher_uring::Body) -> Self {
         RequestBody(RequestBodyInner::Completion(body))
     }
+
+    /// take trailer fields received after the last chunk of `transfer-encoding: chunked` request
+    /// body. See [crate::body::RequestBody::take_trailers] for detail.
+    pub fn take_trailers(&mut self) -> Option<HeaderMap> {
+        match self.0 {
+            RequestBodyInner::Some(ref inner) => inner.borrow_mut().trailers.take(),
+            _ => None,
+        }
+    }
 }
 
 impl Stream for RequestBody {
@@ -133,6 +142,12 @@ impl RequestBodySender {
         }
     }
 
+    pub(super) fn feed_trailers(&mut self, trailers: HeaderMap) {
+        if let Some(mut inner) = self.try_inner_infallible() {
+            inner.trailers = Some(trailers);
+        }
+    }
+
     pub(super) fn feed_data(&mut self, data: Bytes) {
         if let Some(mut inner) = self.try_inner_infallible() {
             inner.feed_data(data);
@@ -179,6 +194,7 @@ struct Inner {
     len: usize,
     err: Option<io::Error>,
     items: VecDeque<Bytes>,
+    trailers: Option<HeaderMap>,
     task: Option<Waker>,
     io_task: Option<Waker>,
 }
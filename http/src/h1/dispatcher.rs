@@ -11,12 +11,15 @@ use std::io;
 
 use futures_core::stream::Stream;
 use tracing::trace;
-use xitca_io::io::{AsyncIo, Interest, Ready};
+use xitca_io::{
+    io::{AsyncIo, Interest, Ready},
+    net::InFlight,
+};
 use xitca_service::Service;
 use xitca_unsafe_collection::futures::{Select as _, SelectOutput};
 
 use crate::{
-    body::NoneBody,
+    body::{Frame, NoneBody},
     bytes::{Bytes, EitherBuf},
     config::HttpServiceConfig,
     date::DateTime,
@@ -26,7 +29,7 @@ use crate::{
     },
     http::{
         response::{Parts, Response},
-        StatusCode,
+        ConnectionInfo, StatusCode,
     },
     util::{
         buffered::{BufferedIo, ListWriteBuf, ReadBuf, WriteBuf},
@@ -42,6 +45,9 @@ use super::proto::{
     error::ProtoError,
 };
 
+#[cfg(feature = "http2")]
+use {super::proto::encode::SWITCHING_PROTOCOLS_H2C, crate::bytes::BytesMut};
+
 type ExtRequest<B> = crate::http::Request<crate::http::RequestExt<B>>;
 
 /// function to generic over different writer buffer types dispatcher.
@@ -52,6 +58,7 @@ pub(crate) async fn run<
     ReqB,
     ResB,
     BE,
+    BF,
     D,
     const HEADER_LIMIT: usize,
     const READ_BUF_LIMIT: usize,
@@ -59,6 +66,7 @@ pub(crate) async fn run<
 >(
     io: &'a mut St,
     addr: SocketAddr,
+    conn: ConnectionInfo,
     timer: Pin<&'a mut KeepAlive>,
     config: HttpServiceConfig<HEADER_LIMIT, READ_BUF_LIMIT, WRITE_BUF_LIMIT>,
     service: &'a S,
@@ -67,7 +75,62 @@ pub(crate) async fn run<
 where
     S: Service<ExtRequest<ReqB>, Response = Response<ResB>>,
     ReqB: From<RequestBody>,
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
+    St: AsyncIo,
+    D: DateTime,
+{
+    let write_buf = if config.vectored_write && io.is_vectored_write() {
+        EitherBuf::Left(ListWriteBuf::<_, WRITE_BUF_LIMIT>::default())
+    } else {
+        EitherBuf::Right(WriteBuf::<WRITE_BUF_LIMIT>::default())
+    };
+
+    let mut dispatcher = Dispatcher::new(io, addr, timer, config, service, date, write_buf);
+    dispatcher.ctx.set_connection_info(conn);
+    dispatcher.run().await
+}
+
+/// http/1 request asking for upgrading connection to http/2 cleartext(h2c).
+/// `101 Switching Protocols` response is already sent when it's produced by dispatcher.
+#[cfg(feature = "http2")]
+pub(crate) struct H2cUpgrade {
+    pub(crate) req: ExtRequest<()>,
+    // decoded SETTINGS payload of HTTP2-Settings header.
+    pub(crate) settings: Bytes,
+    // bytes already read from io after the request head.
+    pub(crate) read_buf: BytesMut,
+}
+
+/// same as [run] but stop dispatching when a request asks for upgrading connection to h2c and
+/// hand it to caller.
+#[cfg(feature = "http2")]
+pub(crate) async fn run_h2c_upgradable<
+    'a,
+    St,
+    S,
+    ReqB,
+    ResB,
+    BE,
+    BF,
+    D,
+    const HEADER_LIMIT: usize,
+    const READ_BUF_LIMIT: usize,
+    const WRITE_BUF_LIMIT: usize,
+>(
+    io: &'a mut St,
+    addr: SocketAddr,
+    conn: ConnectionInfo,
+    timer: Pin<&'a mut KeepAlive>,
+    config: HttpServiceConfig<HEADER_LIMIT, READ_BUF_LIMIT, WRITE_BUF_LIMIT>,
+    service: &'a S,
+    date: &'a D,
+) -> Result<Option<H2cUpgrade>, Error<S::Error, BE>>
+where
+    S: Service<ExtRequest<ReqB>, Response = Response<ResB>>,
+    ReqB: From<RequestBody>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     St: AsyncIo,
     D: DateTime,
 {
@@ -77,9 +140,26 @@ where
         EitherBuf::Right(WriteBuf::<WRITE_BUF_LIMIT>::default())
     };
 
-    Dispatcher::new(io, addr, timer, config, service, date, write_buf)
-        .run()
-        .await
+    let mut dispatcher = Dispatcher::new(io, addr, timer, config, service, date, write_buf);
+    dispatcher.ctx.set_connection_info(conn);
+    dispatcher.h2c = H2c::Enabled;
+    dispatcher.run().await?;
+
+    Ok(match core::mem::replace(&mut dispatcher.h2c, H2c::Disabled) {
+        H2c::Upgrade(upgrade) => Some(H2cUpgrade {
+            req: upgrade.0,
+            settings: upgrade.1,
+            read_buf: dispatcher.io.read_buf.split(),
+        }),
+        _ => None,
+    })
+}
+
+#[cfg(feature = "http2")]
+enum H2c {
+    Disabled,
+    Enabled,
+    Upgrade(Box<(ExtRequest<()>, Bytes)>),
 }
 
 /// Http/1 dispatcher
@@ -88,6 +168,8 @@ struct Dispatcher<'a, St, S, ReqB, W, D, const HEADER_LIMIT: usize, const READ_B
     timer: Timer<'a>,
     ctx: Context<'a, D, HEADER_LIMIT>,
     service: &'a S,
+    #[cfg(feature = "http2")]
+    h2c: H2c,
     _phantom: PhantomData<ReqB>,
 }
 
@@ -156,12 +238,13 @@ impl<'a> Timer<'a> {
     }
 }
 
-impl<'a, St, S, ReqB, ResB, BE, W, D, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize>
+impl<'a, St, S, ReqB, ResB, BE, BF, W, D, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize>
     Dispatcher<'a, St, S, ReqB, W, D, HEADER_LIMIT, READ_BUF_LIMIT>
 where
     S: Service<ExtRequest<ReqB>, Response = Response<ResB>>,
     ReqB: From<RequestBody>,
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     St: AsyncIo,
     W: H1BufWrite,
     D: DateTime,
@@ -180,11 +263,13 @@ where
             timer: Timer::new(timer, config.keep_alive_timeout, config.request_head_timeout),
             ctx: Context::with_addr(addr, date),
             service,
+            #[cfg(feature = "http2")]
+            h2c: H2c::Disabled,
             _phantom: PhantomData,
         }
     }
 
-    async fn run(mut self) -> Result<(), Error<S::Error, BE>> {
+    async fn run(&mut self) -> Result<(), Error<S::Error, BE>> {
         loop {
             match self._run().await {
                 Ok(_) => {}
@@ -203,6 +288,11 @@ where
             // TODO: add timeout for drain write?
             self.io.drain_write().await?;
 
+            #[cfg(feature = "http2")]
+            if matches!(self.h2c, H2c::Upgrade(_)) {
+                return Ok(());
+            }
+
             if self.ctx.is_connection_closed() {
                 return self.io.shutdown().await.map_err(Into::into);
             }
@@ -220,6 +310,18 @@ where
         while let Some((req, decoder)) = self.ctx.decode_head::<READ_BUF_LIMIT>(&mut self.io.read_buf)? {
             self.timer.reset_state();
 
+            // request is in-flight until it's response is sent.
+            let _in_flight = InFlight::guard();
+
+            #[cfg(feature = "http2")]
+            if matches!(self.h2c, H2c::Enabled) && self.ctx.is_h2c_upgrade() && is_h2c_upgradable(&req) {
+                // malformed HTTP2-Settings header is rejected with 400 Bad Request.
+                let settings = crate::h2::h2c::decode_settings(req.headers()).ok_or(ProtoError::HeaderValue)?;
+                self.io.write_buf.write_buf_static(SWITCHING_PROTOCOLS_H2C);
+                self.h2c = H2c::Upgrade(Box::new((req, settings)));
+                return Ok(());
+            }
+
             let (mut body_reader, body) = BodyReader::from_coding(decoder);
             let req = req.map(|ext| ext.map_body(|_| ReqB::from(body)));
 
@@ -244,7 +346,13 @@ where
                     .select(self.io_ready(&mut body_reader))
                     .await
                 {
-                    SelectOutput::A(Some(Ok(bytes))) => encoder.encode(bytes, &mut self.io.write_buf),
+                    SelectOutput::A(Some(Ok(frame))) => match frame.into() {
+                        Frame::Data(bytes) => encoder.encode(bytes, &mut self.io.write_buf),
+                        Frame::Trailers(trailers) => {
+                            encoder.encode_eof_with_trailers(trailers, &mut self.io.write_buf);
+                            break;
+                        }
+                    },
                     SelectOutput::B(Ok(ready)) => {
                         if ready.is_readable() {
                             if let Err(e) = self.io.try_read() {
@@ -295,7 +403,7 @@ where
         }
     }
 
-    fn try_poll_body<'b>(&self, mut body: Pin<&'b mut ResB>) -> impl Future<Output = Option<Result<Bytes, BE>>> + 'b {
+    fn try_poll_body<'b>(&self, mut body: Pin<&'b mut ResB>) -> impl Future<Output = Option<Result<BF, BE>>> + 'b {
         let want_buf = self.io.write_buf.want_write_buf();
         async move {
             if want_buf {
@@ -351,6 +459,8 @@ impl BodyReader {
         loop {
             match self.decoder.decode(&mut *read_buf) {
                 ChunkResult::Ok(bytes) => self.tx.feed_data(bytes),
+                // trailers must be fed before eof so they are visible when body stream ends.
+                ChunkResult::Trailers(trailers) => self.tx.feed_trailers(trailers),
                 ChunkResult::InsufficientData => match self.tx.ready().await {
                     Ok(_) => return,
                     // service future drop RequestBody so marker decoder to corrupted.
@@ -381,6 +491,18 @@ impl BodyReader {
     }
 }
 
+// upgrade is only accepted for request without body. request with body is served as http/1
+// and Upgrade header is ignored.
+#[cfg(feature = "http2")]
+fn is_h2c_upgradable(req: &ExtRequest<()>) -> bool {
+    use crate::http::header::{CONTENT_LENGTH, TRANSFER_ENCODING};
+
+    let headers = req.headers();
+    headers.contains_key("http2-settings")
+        && !headers.contains_key(TRANSFER_ENCODING)
+        && headers.get(CONTENT_LENGTH).into_iter().all(|len| len == "0")
+}
+
 #[cold]
 #[inline(never)]
 pub(super) fn status_only(status: StatusCode) -> Response<NoneBody<Bytes>> {
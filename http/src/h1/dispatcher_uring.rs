@@ -18,12 +18,13 @@ use tracing::trace;
 use xitca_io::{
     bytes::BytesMut,
     io_uring::{write_all, AsyncBufRead, AsyncBufWrite, BoundedBuf},
+    net::InFlight,
 };
 use xitca_service::Service;
 use xitca_unsafe_collection::futures::SelectOutput;
 
 use crate::{
-    body::NoneBody,
+    body::{Frame, NoneBody},
     bytes::Bytes,
     config::HttpServiceConfig,
     date::DateTime,
@@ -102,13 +103,14 @@ impl BufOwned {
     }
 }
 
-impl<'a, Io, S, ReqB, ResB, BE, D, const H_LIMIT: usize, const R_LIMIT: usize, const W_LIMIT: usize>
+impl<'a, Io, S, ReqB, ResB, BE, BF, D, const H_LIMIT: usize, const R_LIMIT: usize, const W_LIMIT: usize>
     Dispatcher<'a, Io, S, ReqB, D, H_LIMIT, R_LIMIT, W_LIMIT>
 where
     Io: AsyncBufRead + AsyncBufWrite + 'static,
     S: Service<ExtRequest<ReqB>, Response = Response<ResB>>,
     ReqB: From<RequestBody>,
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     D: DateTime,
 {
     pub(super) fn new(
@@ -173,6 +175,9 @@ where
         while let Some((req, decoder)) = self.ctx.decode_head::<R_LIMIT>(&mut self.read_buf)? {
             self.timer.reset_state();
 
+            // request is in-flight until it's response is sent.
+            let _in_flight = InFlight::guard();
+
             let (waiter, body) = if decoder.is_eof() {
                 (None, RequestBody::default())
             } else {
@@ -211,12 +216,15 @@ where
                     .await;
 
                     match res {
-                        SelectOutput::A(Some(Ok(bytes))) => {
-                            encoder.encode(bytes, buf);
-                            if buf.len() < W_LIMIT {
-                                continue;
+                        SelectOutput::A(Some(Ok(frame))) => match frame.into() {
+                            Frame::Data(bytes) => {
+                                encoder.encode(bytes, buf);
+                                if buf.len() < W_LIMIT {
+                                    continue;
+                                }
                             }
-                        }
+                            Frame::Trailers(trailers) => break encoder.encode_eof_with_trailers(trailers, buf),
+                        },
                         SelectOutput::A(Some(Err(e))) => return self.on_body_error(e).await,
                         SelectOutput::A(None) => break encoder.encode_eof(buf),
                         SelectOutput::B(_) => {}
@@ -380,6 +388,8 @@ where
                         ChunkResult::Ok(bytes) => return Poll::Ready(Some(Ok(bytes))),
                         ChunkResult::Err(e) => return Poll::Ready(Some(Err(e))),
                         ChunkResult::InsufficientData => {}
+                        // request trailers are not supported by io-uring dispatcher.
+                        ChunkResult::Trailers(_) => continue,
                         _ => return Poll::Ready(None),
                     }
 
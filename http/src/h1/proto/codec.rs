@@ -1,10 +1,15 @@
-use core::{fmt, mem};
+use core::{convert::Infallible, fmt, mem};
 
 use std::io;
 
+use httparse::Status;
 use tracing::{trace, warn};
 
-use crate::bytes::{Buf, Bytes, BytesMut};
+use crate::{
+    body::remove_forbidden_trailers,
+    bytes::{Buf, BufMut, Bytes, BytesMut},
+    http::header::{HeaderMap, HeaderName, HeaderValue},
+};
 
 use super::{buf_write::H1BufWrite, error::ProtoError};
 
@@ -78,7 +83,6 @@ pub enum ChunkedState {
     BodyCr,
     BodyLf,
     Trailer,
-    TrailerLf,
     EndCr,
     EndLf,
     End,
@@ -106,8 +110,7 @@ impl ChunkedState {
             Self::Body => Self::read_body(body, size, buf),
             Self::BodyCr => Self::read_body_cr(body),
             Self::BodyLf => Self::read_body_lf(body),
-            Self::Trailer => Self::read_trailer(body),
-            Self::TrailerLf => Self::read_trailer_lf(body),
+            Self::Trailer => unreachable!("trailer section must be decoded by TransferCoding::decode"),
             Self::EndCr => Self::read_end_cr(body),
             Self::EndLf => Self::read_end_lf(body),
             Self::End => Ok(Some(Self::End)),
@@ -214,25 +217,46 @@ impl ChunkedState {
         }
     }
 
-    fn read_trailer(rdr: &mut BytesMut) -> io::Result<Option<Self>> {
+    // decode the whole trailer section at once. the section is buffered until it's complete.
+    fn read_trailer(rdr: &mut BytesMut) -> io::Result<Option<HeaderMap>> {
         trace!(target: "h1_decode", "read_trailer");
-        match byte!(rdr) {
-            b'\r' => Ok(Some(Self::TrailerLf)),
-            _ => Ok(Some(Self::Trailer)),
-        }
-    }
 
-    fn read_trailer_lf(rdr: &mut BytesMut) -> io::Result<Option<Self>> {
-        match byte!(rdr) {
-            b'\n' => Ok(Some(Self::EndCr)),
-            _ => Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid trailer end LF")),
+        let mut headers = [httparse::EMPTY_HEADER; MAX_TRAILERS];
+
+        match httparse::parse_headers(rdr, &mut headers) {
+            Ok(Status::Complete((len, headers))) => {
+                let mut trailers = HeaderMap::with_capacity(headers.len());
+
+                for header in headers {
+                    let name = HeaderName::from_bytes(header.name.as_bytes());
+                    let value = HeaderValue::from_bytes(header.value);
+                    match (name, value) {
+                        (Ok(name), Ok(value)) => trailers.append(name, value),
+                        _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid trailer field")),
+                    };
+                }
+
+                rdr.advance(len);
+
+                remove_forbidden_trailers(&mut trailers);
+
+                Ok(Some(trailers))
+            }
+            Ok(Status::Partial) if rdr.len() < MAX_TRAILER_SIZE => Ok(None),
+            Ok(Status::Partial) => Err(io::Error::new(io::ErrorKind::InvalidData, "Trailer section too large")),
+            Err(_) => Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid trailer field")),
         }
     }
 
+    // peek for empty trailer section. trailer fields are left in buffer for read_trailer.
     fn read_end_cr(rdr: &mut BytesMut) -> io::Result<Option<Self>> {
-        match byte!(rdr) {
-            b'\r' => Ok(Some(Self::EndLf)),
-            _ => Ok(Some(Self::Trailer)),
+        match rdr.first() {
+            Some(b'\r') => {
+                rdr.advance(1);
+                Ok(Some(Self::EndLf))
+            }
+            Some(_) => Ok(Some(Self::Trailer)),
+            None => Ok(None),
         }
     }
 
@@ -316,6 +340,32 @@ impl TransferCoding {
         }
     }
 
+    /// Encode eof with trailer fields. trailers are only encoded when encoding chunked data and
+    /// they are dropped silently for other encoders. fields not allowed in trailer section are
+    /// removed.
+    pub fn encode_eof_with_trailers<W>(&mut self, mut trailers: HeaderMap, buf: &mut W)
+    where
+        W: H1BufWrite,
+    {
+        remove_forbidden_trailers(&mut trailers);
+
+        if !matches!(self, Self::EncodeChunked) || trailers.is_empty() {
+            return self.encode_eof(buf);
+        }
+
+        let _ = buf.write_buf_head(|buf| {
+            buf.put_slice(b"0\r\n");
+            for (name, value) in trailers.iter() {
+                buf.put_slice(name.as_str().as_bytes());
+                buf.put_slice(b": ");
+                buf.put_slice(value.as_bytes());
+                buf.put_slice(b"\r\n");
+            }
+            buf.put_slice(b"\r\n");
+            Ok::<_, Infallible>(())
+        });
+    }
+
     /// decode body. See [ChunkResult] for detailed outcome.
     pub fn decode(&mut self, src: &mut BytesMut) -> ChunkResult {
         match *self {
@@ -334,6 +384,17 @@ impl TransferCoding {
             Self::Upgrade => ChunkResult::Ok(src.split().freeze()),
             Self::DecodeChunked(ref mut state, ref mut size) => {
                 loop {
+                    if matches!(state, ChunkedState::Trailer) {
+                        return match ChunkedState::read_trailer(src) {
+                            Ok(Some(trailers)) => {
+                                *state = ChunkedState::End;
+                                ChunkResult::Trailers(trailers)
+                            }
+                            Ok(None) => ChunkResult::InsufficientData,
+                            Err(e) => ChunkResult::Err(e),
+                        };
+                    }
+
                     let mut buf = None;
                     // advances the chunked state
                     *state = match state.step(src, size, &mut buf) {
@@ -362,6 +423,8 @@ pub enum ChunkResult {
     Ok(Bytes),
     /// io error type produced by coder that can be bubbled up to upstream caller.
     Err(io::Error),
+    /// trailer fields decoded after the last chunk. it's always followed by [ChunkResult::OnEof].
+    Trailers(HeaderMap),
     /// insufficient data. More input bytes required.
     InsufficientData,
     /// coder reached EOF state and no more chunk can be produced.
@@ -377,6 +440,7 @@ impl fmt::Display for ChunkResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::Ok(_) => f.write_str("chunked data."),
+            Self::Trailers(_) => f.write_str("chunked trailers."),
             Self::Err(ref e) => fmt::Display::fmt(e, f),
             Self::InsufficientData => f.write_str("no sufficient data. More input bytes required."),
             Self::OnEof => f.write_str("coder reached EOF state. no more chunk can be produced."),
@@ -392,6 +456,10 @@ impl From<io::Error> for ChunkResult {
     }
 }
 
+// limits of trailer section of chunked request body.
+const MAX_TRAILERS: usize = 32;
+const MAX_TRAILER_SIZE: usize = 8 * 1024;
+
 fn bounded_split(rem: &mut u64, buf: &mut BytesMut) -> Bytes {
     let len = buf.len() as u64;
     if *rem >= len {
@@ -519,6 +587,57 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_read_chunked_trailers() {
+        let mut decoder = TransferCoding::decode_chunked();
+
+        let mock_buf = &mut BytesMut::from("3\r\nfoo\r\n0\r\nfoo: bar\r\n");
+
+        match decoder.decode(mock_buf) {
+            ChunkResult::Ok(buf) => assert_eq!(buf, "foo"),
+            state => panic!("{}", state),
+        }
+
+        // partial trailer section must wait for more data.
+        match decoder.decode(mock_buf) {
+            ChunkResult::InsufficientData => {}
+            state => panic!("{}", state),
+        }
+
+        // forbidden trailer field is removed.
+        mock_buf.extend_from_slice(b"checksum: 996\r\nfoo: baz\r\ntransfer-encoding: gzip\r\n\r\n");
+
+        match decoder.decode(mock_buf) {
+            ChunkResult::Trailers(trailers) => {
+                assert_eq!(trailers.len(), 3);
+                assert_eq!(trailers.get("checksum").unwrap(), "996");
+                let foo = trailers.get_all("foo").iter().collect::<Vec<_>>();
+                assert_eq!(foo, ["bar", "baz"]);
+            }
+            state => panic!("{}", state),
+        }
+
+        match decoder.decode(mock_buf) {
+            ChunkResult::OnEof => {}
+            state => panic!("{}", state),
+        }
+
+        assert!(mock_buf.is_empty());
+    }
+
+    #[test]
+    fn test_read_chunked_trailers_too_large() {
+        let mut decoder = TransferCoding::decode_chunked();
+
+        let mut buf = String::from("0\r\nfoo: ");
+        buf.push_str(&"a".repeat(MAX_TRAILER_SIZE));
+
+        match decoder.decode(&mut BytesMut::from(buf.as_str())) {
+            ChunkResult::Err(e) => assert_eq!(e.kind(), io::ErrorKind::InvalidData),
+            state => panic!("{}", state),
+        }
+    }
+
     #[test]
     fn test_read_chunked_after_eof() {
         let mock_buf = &mut BytesMut::from("10\r\n1234567890abcdef\r\n0\r\n\r\n");
@@ -567,6 +686,34 @@ mod test {
         assert_eq!(dst.buf(), b"7\r\nfoo bar\r\nD\r\nbaz quux herp\r\n0\r\n\r\n");
     }
 
+    #[test]
+    fn encode_chunked_trailers() {
+        let mut encoder = TransferCoding::encode_chunked();
+        let dst = &mut WriteBuf::<1024>::default();
+
+        encoder.encode(Bytes::from("foo bar"), dst);
+
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", HeaderValue::from_static("0"));
+        // forbidden trailer field is not encoded.
+        trailers.insert("content-length", HeaderValue::from_static("7"));
+        encoder.encode_eof_with_trailers(trailers, dst);
+
+        assert_eq!(dst.buf(), b"7\r\nfoo bar\r\n0\r\ngrpc-status: 0\r\n\r\n");
+
+        // trailers are dropped for sized body.
+        let mut encoder = TransferCoding::length(3);
+        let dst = &mut WriteBuf::<1024>::default();
+
+        encoder.encode(Bytes::from("foo"), dst);
+
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", HeaderValue::from_static("0"));
+        encoder.encode_eof_with_trailers(trailers, dst);
+
+        assert_eq!(dst.buf(), b"foo");
+    }
+
     #[test]
     fn encode_length() {
         let max_len = 8;
@@ -1,10 +1,13 @@
 use core::{mem, net::SocketAddr};
 
-use crate::http::{header::HeaderMap, Extensions};
+use std::sync::Arc;
+
+use crate::http::{header::HeaderMap, ConnectionInfo, Extensions, TlsInfo};
 
 /// Context is connection specific struct contain states for processing.
 pub struct Context<'a, D, const HEADER_LIMIT: usize> {
     addr: SocketAddr,
+    conn: ConnectionInfo,
     state: ContextState,
     // header map reused by next request.
     header: Option<HeaderMap>,
@@ -26,6 +29,8 @@ impl ContextState {
     const HEAD: u8 = 0b_0100;
     // Enable when current connection is supposed to be closed after current response is sent.
     const CLOSE: u8 = 0b_1000;
+    // Enable when current request asks for upgrading to http/2 cleartext(h2c).
+    const UPGRADE_H2C: u8 = 0b1_0000;
 
     const fn new() -> Self {
         Self(0)
@@ -60,6 +65,7 @@ impl<'a, D, const HEADER_LIMIT: usize> Context<'a, D, HEADER_LIMIT> {
     pub fn with_addr(addr: SocketAddr, date: &'a D) -> Self {
         Self {
             addr,
+            conn: ConnectionInfo::default(),
             state: ContextState::new(),
             header: None,
             exts: Extensions::new(),
@@ -125,6 +131,12 @@ impl<'a, D, const HEADER_LIMIT: usize> Context<'a, D, HEADER_LIMIT> {
         self.state.insert(ContextState::HEAD)
     }
 
+    /// Set Context's state to h2c upgrade requested.
+    #[inline]
+    pub fn set_h2c_upgrade(&mut self) {
+        self.state.insert(ContextState::UPGRADE_H2C)
+    }
+
     /// Set Context's state to Close.
     #[inline]
     pub fn set_close(&mut self) {
@@ -155,6 +167,12 @@ impl<'a, D, const HEADER_LIMIT: usize> Context<'a, D, HEADER_LIMIT> {
         self.state.contains(ContextState::HEAD)
     }
 
+    /// Get h2c upgrade state.
+    #[inline]
+    pub const fn is_h2c_upgrade(&self) -> bool {
+        self.state.contains(ContextState::UPGRADE_H2C)
+    }
+
     /// Return true if connection type is `Connection: Close`.
     #[inline]
     pub const fn is_connection_closed(&self) -> bool {
@@ -166,4 +184,18 @@ impl<'a, D, const HEADER_LIMIT: usize> Context<'a, D, HEADER_LIMIT> {
     pub fn socket_addr(&self) -> &SocketAddr {
         &self.addr
     }
+
+    /// Get tls connection information context associated with.
+    #[inline]
+    pub fn tls_info(&self) -> Option<&Arc<TlsInfo>> {
+        self.conn.tls_info.as_ref()
+    }
+
+    pub(crate) fn connection_info(&self) -> &ConnectionInfo {
+        &self.conn
+    }
+
+    pub(crate) fn set_connection_info(&mut self, conn: ConnectionInfo) {
+        self.conn = conn;
+    }
 }
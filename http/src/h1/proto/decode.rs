@@ -82,7 +82,7 @@ impl<D, const MAX_HEADERS: usize> Context<'_, D, MAX_HEADERS> {
                     self.try_write_header(&mut headers, &mut decoder, idx, &slice, version)?;
                 }
 
-                let ext = Extension::new(*self.socket_addr());
+                let ext = Extension::with_connection_info(*self.socket_addr(), self.connection_info().clone());
                 let mut req = Request::new(RequestExt::from_parts((), ext));
 
                 let extensions = self.take_extensions();
@@ -144,6 +144,9 @@ impl<D, const MAX_HEADERS: usize> Context<'_, D, MAX_HEADERS> {
                 if version != Version::HTTP_11 {
                     return Err(ProtoError::HeaderName);
                 }
+                if value.as_bytes().eq_ignore_ascii_case(b"h2c") {
+                    self.set_h2c_upgrade();
+                }
                 decoder.try_set(TransferCoding::upgrade())?;
             }
             _ => {}
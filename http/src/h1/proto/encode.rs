@@ -16,6 +16,9 @@ use super::{buf_write::H1BufWrite, codec::TransferCoding, context::Context, erro
 
 pub const CONTINUE: &[u8; 25] = b"HTTP/1.1 100 Continue\r\n\r\n";
 
+pub const SWITCHING_PROTOCOLS_H2C: &[u8; 71] =
+    b"HTTP/1.1 101 Switching Protocols\r\nconnection: upgrade\r\nupgrade: h2c\r\n\r\n";
+
 #[allow(clippy::declare_interior_mutable_const)]
 pub const CONTINUE_BYTES: Bytes = Bytes::from_static(CONTINUE);
 
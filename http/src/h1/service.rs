@@ -1,14 +1,18 @@
 use core::{net::SocketAddr, pin::pin};
 
+use std::sync::Arc;
+
 use futures_core::stream::Stream;
 use xitca_io::io::AsyncIo;
 use xitca_service::Service;
 
 use crate::{
-    bytes::Bytes,
+    body::Frame,
     error::{HttpServiceError, TimeoutError},
-    http::{Request, RequestExt, Response},
-    service::HttpService,
+    http::{ConnectionInfo, Request, RequestExt, Response},
+    proxy,
+    service::{proxy_header, HttpService},
+    tls::AsTlsInfo,
     util::timer::Timeout,
 };
 
@@ -17,23 +21,31 @@ use super::body::RequestBody;
 pub type H1Service<St, S, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize> =
     HttpService<St, S, RequestBody, A, HEADER_LIMIT, READ_BUF_LIMIT, WRITE_BUF_LIMIT>;
 
-impl<St, S, B, BE, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize>
+impl<St, S, B, BE, BF, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize>
     Service<(St, SocketAddr)> for H1Service<St, S, A, HEADER_LIMIT, READ_BUF_LIMIT, WRITE_BUF_LIMIT>
 where
     S: Service<Request<RequestExt<RequestBody>>, Response = Response<B>>,
     A: Service<St>,
     St: AsyncIo,
-    A::Response: AsyncIo,
-    B: Stream<Item = Result<Bytes, BE>>,
+    A::Response: AsyncIo + AsTlsInfo,
+    B: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     HttpServiceError<S::Error, BE>: From<A::Error>,
 {
     type Response = ();
     type Error = HttpServiceError<S::Error, BE>;
 
-    async fn call(&self, (io, addr): (St, SocketAddr)) -> Result<Self::Response, Self::Error> {
+    async fn call(&self, (mut io, mut addr): (St, SocketAddr)) -> Result<Self::Response, Self::Error> {
         // at this stage keep-alive timer is used to tracks tls accept timeout.
         let mut timer = pin!(self.keep_alive());
 
+        let proxy_header = if self.config.proxy_protocol {
+            let header = proxy_header(proxy::read_header_exact(&mut io), &mut addr, timer.as_mut()).await?;
+            Some(header)
+        } else {
+            None
+        };
+
         let mut io = self
             .tls_acceptor
             .call(io)
@@ -41,7 +53,12 @@ where
             .await
             .map_err(|_| HttpServiceError::Timeout(TimeoutError::TlsAccept))??;
 
-        super::dispatcher::run(&mut io, addr, timer, self.config, &self.service, self.date.get())
+        let conn = ConnectionInfo {
+            tls_info: io.tls_info(self.config.tls_peer_certificates).map(Arc::new),
+            proxy_header,
+        };
+
+        super::dispatcher::run(&mut io, addr, conn, timer, self.config, &self.service, self.date.get())
             .await
             .map_err(Into::into)
     }
@@ -90,13 +107,14 @@ impl<S, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_B
 }
 
 #[cfg(feature = "io-uring")]
-impl<S, B, BE, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize>
+impl<S, B, BE, BF, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize>
     Service<(TcpStream, SocketAddr)> for H1UringService<S, A, HEADER_LIMIT, READ_BUF_LIMIT, WRITE_BUF_LIMIT>
 where
     S: Service<Request<RequestExt<RequestBody>>, Response = Response<B>>,
     A: Service<TcpStream>,
     A::Response: AsyncBufRead + AsyncBufWrite + 'static,
-    B: Stream<Item = Result<Bytes, BE>>,
+    B: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     HttpServiceError<S::Error, BE>: From<A::Error>,
 {
     type Response = ();
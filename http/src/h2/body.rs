@@ -6,12 +6,42 @@ use core::{
 use futures_core::stream::Stream;
 use h2::RecvStream;
 
-use crate::{bytes::Bytes, error::BodyError};
+use crate::{body::remove_forbidden_trailers, bytes::Bytes, error::BodyError, http::HeaderMap};
 
 /// Request body type for Http/2 specifically.
 pub struct RequestBody {
     end_stream: bool,
     stream: RecvStream,
+    trailers: Trailers,
+}
+
+enum Trailers {
+    // trailers are not received yet.
+    Pending,
+    Received(Option<HeaderMap>),
+}
+
+impl RequestBody {
+    /// take trailer fields received after request body data. See
+    /// [crate::body::RequestBody::take_trailers] for detail.
+    pub fn take_trailers(&mut self) -> Option<HeaderMap> {
+        match self.trailers {
+            Trailers::Received(ref mut trailers) => trailers.take(),
+            Trailers::Pending => None,
+        }
+    }
+
+    // receive trailers once after data frames are consumed.
+    fn poll_trailers(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes, BodyError>>> {
+        if let Trailers::Pending = self.trailers {
+            let trailers = ready!(self.stream.poll_trailers(cx)?).map(|mut trailers| {
+                remove_forbidden_trailers(&mut trailers);
+                trailers
+            });
+            self.trailers = Trailers::Received(trailers);
+        }
+        Poll::Ready(None)
+    }
 }
 
 impl Stream for RequestBody {
@@ -21,7 +51,7 @@ impl Stream for RequestBody {
         let this = self.as_mut().get_mut();
 
         if this.end_stream {
-            return Poll::Ready(None);
+            return this.poll_trailers(cx);
         }
 
         let res = ready!(this.stream.poll_data(cx)?);
@@ -37,7 +67,7 @@ impl Stream for RequestBody {
                     .expect("releasing the same amount of received data should never fail");
                 Poll::Ready(Some(Ok(bytes)))
             }
-            None => Poll::Ready(None),
+            None => this.poll_trailers(cx),
         }
     }
 }
@@ -53,6 +83,7 @@ impl From<RecvStream> for RequestBody {
         RequestBody {
             end_stream: false,
             stream,
+            trailers: Trailers::Pending,
         }
     }
 }
@@ -64,6 +64,9 @@ where
     type Error = Error;
 
     async fn call(&self, res: Result<S, E>) -> Result<Self::Response, Self::Error> {
+        if self.config.proxy_protocol {
+            return Err(Box::new("PROXY protocol is not supported by io-uring service") as Error);
+        }
         let service = res.map_err(|e| Box::new(e) as Error)?;
         let tls_acceptor = self.tls_factory.call(()).await.map_err(|e| Box::new(e) as Error)?;
         Ok(super::service::H2UringService::new(self.config, service, tls_acceptor))
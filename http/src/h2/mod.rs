@@ -9,6 +9,9 @@ pub mod body;
 
 pub(crate) use self::proto::Dispatcher;
 
+#[cfg(feature = "http1")]
+pub(crate) use self::proto::h2c;
+
 pub use self::body::RequestBody;
 pub use self::error::Error;
 pub use self::service::H2Service;
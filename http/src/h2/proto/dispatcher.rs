@@ -14,19 +14,22 @@ use ::h2::{
 };
 use futures_core::stream::Stream;
 use tracing::trace;
-use xitca_io::io::{AsyncRead, AsyncWrite};
+use xitca_io::{
+    io::{AsyncRead, AsyncWrite},
+    net::InFlight,
+};
 use xitca_service::Service;
 use xitca_unsafe_collection::futures::{Select as _, SelectOutput};
 
 use crate::{
-    body::BodySize,
+    body::{remove_forbidden_trailers, BodySize, Frame},
     bytes::Bytes,
     date::{DateTime, DateTimeHandle},
     error::HttpServiceError,
     h2::{body::RequestBody, error::Error},
     http::{
         header::{HeaderMap, HeaderName, HeaderValue, CONNECTION, CONTENT_LENGTH, DATE, TRAILER},
-        Extension, Request, RequestExt, Response, Version,
+        ConnectionInfo, Extension, Request, RequestExt, Response, Version,
     },
     util::{futures::Queue, timer::KeepAlive},
 };
@@ -35,19 +38,22 @@ use crate::{
 pub(crate) struct Dispatcher<'a, TlsSt, S, ReqB> {
     io: &'a mut Connection<TlsSt, Bytes>,
     addr: SocketAddr,
+    conn: ConnectionInfo,
     keep_alive: Pin<&'a mut KeepAlive>,
     ka_dur: Duration,
+    ping_timeout: Duration,
     service: &'a S,
     date: &'a DateTimeHandle,
     _req_body: PhantomData<ReqB>,
 }
 
-impl<'a, TlsSt, S, ReqB, ResB, BE> Dispatcher<'a, TlsSt, S, ReqB>
+impl<'a, TlsSt, S, ReqB, ResB, BE, BF> Dispatcher<'a, TlsSt, S, ReqB>
 where
     S: Service<Request<RequestExt<ReqB>>, Response = Response<ResB>>,
     S::Error: fmt::Debug,
 
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     BE: fmt::Debug,
 
     TlsSt: AsyncRead + AsyncWrite + Unpin,
@@ -56,16 +62,19 @@ where
     pub(crate) fn new(
         io: &'a mut Connection<TlsSt, Bytes>,
         addr: SocketAddr,
+        conn: ConnectionInfo,
         keep_alive: Pin<&'a mut KeepAlive>,
-        ka_dur: Duration,
+        (ka_dur, ping_timeout): (Duration, Duration),
         service: &'a S,
         date: &'a DateTimeHandle,
     ) -> Self {
         Self {
             io,
             addr,
+            conn,
             keep_alive,
             ka_dur,
+            ping_timeout,
             service,
             date,
             _req_body: PhantomData,
@@ -76,8 +85,10 @@ where
         let Self {
             io,
             addr,
+            conn,
             mut keep_alive,
             ka_dur,
+            ping_timeout,
             service,
             date,
             ..
@@ -96,6 +107,7 @@ where
             ping_pong,
             date,
             ka_dur,
+            ping_timeout,
         };
 
         let mut queue = Queue::new();
@@ -107,10 +119,12 @@ where
                     // and reconstruct as HttpRequest.
                     let req = req.map(|body| {
                         let body = ReqB::from(RequestBody::from(body));
-                        RequestExt::from_parts(body, Extension::new(addr))
+                        RequestExt::from_parts(body, Extension::with_connection_info(addr, conn.clone()))
                     });
 
+                    let in_flight = InFlight::guard();
                     queue.push(async move {
+                        let _in_flight = in_flight;
                         let fut = service.call(req);
                         h2_handler(fut, tx, date).await
                     });
@@ -163,6 +177,7 @@ struct H2PingPong<'a> {
     ping_pong: PingPong,
     date: &'a DateTimeHandle,
     ka_dur: Duration,
+    ping_timeout: Duration,
 }
 
 impl Future for H2PingPong<'_> {
@@ -195,10 +210,9 @@ impl Future for H2PingPong<'_> {
 
                 this.ping_pong.send_ping(Ping::opaque())?;
 
-                // Update the keep alive to 10 times the normal keep alive duration.
-                // There is no particular reason for the duration choice here. as h2 connection is
-                // suggested to be kept alive for a relative long time.
-                let deadline = this.date.now() + (this.ka_dur * 10);
+                // Update the keep alive to ping timeout duration. Connection is closed when pong
+                // is not received before it.
+                let deadline = this.date.now() + this.ping_timeout;
 
                 this.keep_alive.as_mut().update(deadline);
 
@@ -214,14 +228,15 @@ enum ConnectionState {
 }
 
 // handle request/response and return if connection should go into graceful shutdown.
-async fn h2_handler<Fut, B, SE, BE>(
+async fn h2_handler<Fut, B, SE, BE, BF>(
     fut: Fut,
     mut tx: SendResponse<Bytes>,
     date: &DateTimeHandle,
 ) -> Result<ConnectionState, Error<SE, BE>>
 where
     Fut: Future<Output = Result<Response<B>, SE>>,
-    B: Stream<Item = Result<Bytes, BE>>,
+    B: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     BE: fmt::Debug,
 {
     // split response to header and body.
@@ -281,7 +296,14 @@ where
         let mut body = pin!(body);
 
         while let Some(res) = poll_fn(|cx| body.as_mut().poll_next(cx)).await {
-            let mut chunk = res.map_err(Error::Body)?;
+            let mut chunk = match res.map_err(Error::Body)?.into() {
+                Frame::Data(bytes) => bytes,
+                Frame::Trailers(mut map) => {
+                    remove_forbidden_trailers(&mut map);
+                    trailers.extend(map);
+                    break;
+                }
+            };
 
             while !chunk.is_empty() {
                 let len = chunk.len();
@@ -0,0 +1,246 @@
+//! http/2 cleartext(h2c) upgrade from http/1 request.
+//!
+//! `h2` crate does not support upgrading connection from http/1. After `101 Switching Protocols`
+//! response is sent the upgrade request is replayed as stream 1 by injecting a synthetic HEADERS
+//! frame after client's connection preface and it's first SETTINGS frame.
+//!
+//! settings from `HTTP2-Settings` header of upgrade request are applied by prepending them to the
+//! payload of client's first SETTINGS frame. they are acknowledged together with it and the
+//! settings of the frame still take priority as they come later.
+
+use std::io;
+
+use xitca_io::io::{AsyncIo, Interest};
+use xitca_unsafe_collection::bytes::BytesStr;
+
+use crate::{
+    bytes::{BufMut, Bytes},
+    h1::dispatcher::H2cUpgrade,
+    http::{
+        header::{HeaderMap, CONNECTION, HOST, TE, TRANSFER_ENCODING, UPGRADE},
+        uri::Scheme,
+    },
+    version::{PeekedIo, H2_PREFACE},
+};
+
+use super::{
+    head::Kind,
+    headers::{Headers, Pseudo},
+    hpack, HEADER_LEN,
+};
+
+// default SETTINGS_MAX_FRAME_SIZE of http/2.
+const MAX_FRAME_SIZE: usize = 16_384;
+
+/// read client's connection preface and first SETTINGS frame from io and inject the upgrade request
+/// after them. the returned io can be passed to http/2 server handshake directly.
+pub(crate) async fn upgrade<Io>(mut io: Io, upgrade: H2cUpgrade) -> io::Result<PeekedIo<Io>>
+where
+    Io: AsyncIo,
+{
+    let H2cUpgrade {
+        req,
+        settings,
+        read_buf: mut buf,
+    } = upgrade;
+
+    let settings_end = loop {
+        if let Some(end) = settings_end(&buf)? {
+            break end;
+        }
+
+        let mut chunk = [0; 4096];
+        match io::Read::read(&mut io, &mut chunk) {
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => buf.extend_from_slice(&chunk[..n]),
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                io.ready(Interest::READABLE).await?;
+            }
+            Err(e) => return Err(e),
+        }
+    };
+
+    let rest = buf.split_off(settings_end);
+
+    if !settings.is_empty() {
+        let payload = buf.split_off(H2_PREFACE.len() + HEADER_LEN);
+        let len = settings.len() + payload.len();
+        if len > MAX_FRAME_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "SETTINGS frame exceeds max frame size",
+            ));
+        }
+        buf[H2_PREFACE.len()..H2_PREFACE.len() + 3].copy_from_slice(&(len as u32).to_be_bytes()[1..]);
+        buf.extend_from_slice(&settings);
+        buf.extend_from_slice(&payload);
+    }
+
+    let (parts, _) = req.into_parts();
+    let mut fields = parts.headers;
+
+    let mut pseudo = Pseudo::request(parts.method, parts.uri, None);
+    pseudo.set_scheme(Scheme::HTTP);
+    if let Some(host) = fields.get(HOST).and_then(|h| h.to_str().ok()) {
+        pseudo.set_authority(BytesStr::from(host));
+    }
+
+    // remove connection specific headers that are not allowed in http/2.
+    for name in [CONNECTION, UPGRADE, HOST, TRANSFER_ENCODING] {
+        fields.remove(name);
+    }
+    for name in ["http2-settings", "keep-alive", "proxy-connection"] {
+        fields.remove(name);
+    }
+    if fields.get(TE).is_some_and(|te| te != "trailers") {
+        fields.remove(TE);
+    }
+
+    let mut headers = Headers::new(1.into(), pseudo, fields);
+    headers.set_end_headers();
+    headers.set_end_stream();
+
+    // zero sized dynamic table. injected frame must not mutate server's hpack decoder state as
+    // client is not aware of it.
+    let mut encoder = hpack::Encoder::new(0, 0);
+
+    let mut continuation = headers.encode(&mut encoder, &mut (&mut buf).limit(HEADER_LEN + MAX_FRAME_SIZE));
+    while let Some(c) = continuation {
+        continuation = c.encode(&mut (&mut buf).limit(HEADER_LEN + MAX_FRAME_SIZE));
+    }
+
+    buf.unsplit(rest);
+
+    Ok(PeekedIo::new(buf.freeze(), io))
+}
+
+/// decode SETTINGS payload from `HTTP2-Settings` header. RFC 7540 section 3.2.1 requires exactly
+/// one header with base64url encoded SETTINGS payload. None is returned when header is missing,
+/// duplicated or malformed.
+pub(crate) fn decode_settings(headers: &HeaderMap) -> Option<Bytes> {
+    let mut values = headers.get_all("http2-settings").into_iter();
+    let value = values.next()?;
+    if values.next().is_some() {
+        return None;
+    }
+
+    let payload = decode_base64url(value.as_bytes())?;
+    if payload.len() % 6 != 0 {
+        return None;
+    }
+
+    payload
+        .chunks(6)
+        .all(|setting| {
+            let id = u16::from_be_bytes([setting[0], setting[1]]);
+            let value = u32::from_be_bytes([setting[2], setting[3], setting[4], setting[5]]);
+            match id {
+                // SETTINGS_ENABLE_PUSH
+                0x2 => value <= 1,
+                // SETTINGS_INITIAL_WINDOW_SIZE
+                0x4 => value <= 0x7fff_ffff,
+                // SETTINGS_MAX_FRAME_SIZE
+                0x5 => (16_384..=16_777_215).contains(&value),
+                // unknown settings are ignored.
+                _ => true,
+            }
+        })
+        .then(|| Bytes::from(payload))
+}
+
+// base64url without padding. trailing padding is tolerated.
+fn decode_base64url(value: &[u8]) -> Option<Vec<u8>> {
+    let value = match value.iter().rposition(|b| *b != b'=') {
+        Some(idx) => &value[..=idx],
+        None => value,
+    };
+
+    // a single character left at the end can not encode a full byte.
+    if value.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(value.len() * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0;
+
+    for b in value {
+        let v = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'-' => 62,
+            b'_' => 63,
+            _ => return None,
+        };
+        acc = (acc << 6) | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+
+    Some(out)
+}
+
+// try to find the end of client's connection preface and first SETTINGS frame.
+fn settings_end(buf: &[u8]) -> io::Result<Option<usize>> {
+    let len = buf.len().min(H2_PREFACE.len());
+    if buf[..len] != H2_PREFACE[..len] {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid http/2 connection preface",
+        ));
+    }
+
+    let Some(head) = buf.get(H2_PREFACE.len()..H2_PREFACE.len() + HEADER_LEN) else {
+        return Ok(None);
+    };
+
+    let payload_len = u32::from_be_bytes([0, head[0], head[1], head[2]]) as usize;
+    if !matches!(Kind::new(head[3]), Kind::Settings) || payload_len > MAX_FRAME_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "connection preface must be followed by SETTINGS frame",
+        ));
+    }
+
+    let end = H2_PREFACE.len() + HEADER_LEN + payload_len;
+    Ok((buf.len() >= end).then_some(end))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::HeaderValue;
+
+    use super::*;
+
+    fn settings(values: &[&'static str]) -> Option<Bytes> {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append("http2-settings", HeaderValue::from_static(value));
+        }
+        decode_settings(&headers)
+    }
+
+    #[test]
+    fn decode() {
+        // SETTINGS_MAX_CONCURRENT_STREAMS = 100, SETTINGS_INITIAL_WINDOW_SIZE = 10485760,
+        // SETTINGS_ENABLE_PUSH = 0
+        let payload = b"\0\x03\0\0\0\x64\0\x04\0\xa0\0\0\0\x02\0\0\0\0";
+        assert_eq!(settings(&["AAMAAABkAAQAoAAAAAIAAAAA"]).unwrap(), &payload[..]);
+        assert_eq!(settings(&["AAMAAABkAAQAoAAAAAIAAAAA=="]).unwrap(), &payload[..]);
+        assert_eq!(settings(&[""]).unwrap(), &b""[..]);
+
+        assert!(settings(&[]).is_none());
+        assert!(settings(&["AAMAAABk", "AAMAAABk"]).is_none());
+        // invalid base64url character.
+        assert!(settings(&["AAMAAAB+"]).is_none());
+        // payload is not multiple of setting size.
+        assert!(settings(&["AAMAAAA"]).is_none());
+        // SETTINGS_ENABLE_PUSH = 2
+        assert!(settings(&["AAIAAAAC"]).is_none());
+    }
+}
@@ -4,6 +4,8 @@ mod data;
 mod dispatcher;
 mod error;
 mod go_away;
+#[cfg(feature = "http1")]
+pub(crate) mod h2c;
 mod head;
 mod headers;
 mod hpack;
@@ -42,6 +44,7 @@ mod io_uring {
     use xitca_io::{
         bytes::{Buf, BufMut, BytesMut},
         io_uring::{write_all, AsyncBufRead, AsyncBufWrite, BoundedBuf},
+        net::InFlight,
     };
     use xitca_service::Service;
     use xitca_unsafe_collection::futures::{Select, SelectOutput};
@@ -461,7 +464,9 @@ mod io_uring {
                             let t = &tx;
                             let flow = &flow;
 
+                            let in_flight = InFlight::guard();
                             queue.push(async move {
+                                let _in_flight = in_flight;
                                 match s.call(req).await {
                                     Ok(res) => {
                                         let (mut parts, body) = res.into_parts();
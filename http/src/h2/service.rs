@@ -1,14 +1,18 @@
 use core::{fmt, net::SocketAddr, pin::pin};
 
+use std::sync::Arc;
+
 use futures_core::Stream;
 use xitca_io::io::{AsyncIo, PollIoAdapter};
 use xitca_service::Service;
 
 use crate::{
-    bytes::Bytes,
+    body::Frame,
     error::{HttpServiceError, TimeoutError},
-    http::{Request, RequestExt, Response},
-    service::HttpService,
+    http::{ConnectionInfo, Request, RequestExt, Response},
+    proxy,
+    service::{proxy_header, HttpService},
+    tls::AsTlsInfo,
     util::timer::Timeout,
 };
 
@@ -22,6 +26,7 @@ impl<
         S,
         ResB,
         BE,
+        BF,
         A,
         TlsSt,
         const HEADER_LIMIT: usize,
@@ -34,21 +39,29 @@ where
 
     A: Service<St, Response = TlsSt>,
     St: AsyncIo,
-    TlsSt: AsyncIo,
+    TlsSt: AsyncIo + AsTlsInfo,
 
     HttpServiceError<S::Error, BE>: From<A::Error>,
 
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     BE: fmt::Debug,
 {
     type Response = ();
     type Error = HttpServiceError<S::Error, BE>;
 
-    async fn call(&self, (io, addr): (St, SocketAddr)) -> Result<Self::Response, Self::Error> {
+    async fn call(&self, (mut io, mut addr): (St, SocketAddr)) -> Result<Self::Response, Self::Error> {
         // tls accept timer.
         let timer = self.keep_alive();
         let mut timer = pin!(timer);
 
+        let proxy_header = if self.config.proxy_protocol {
+            let header = proxy_header(proxy::read_header_exact(&mut io), &mut addr, timer.as_mut()).await?;
+            Some(header)
+        } else {
+            None
+        };
+
         let tls_stream = self
             .tls_acceptor
             .call(io)
@@ -56,11 +69,18 @@ where
             .await
             .map_err(|_| HttpServiceError::Timeout(TimeoutError::TlsAccept))??;
 
+        let conn_info = ConnectionInfo {
+            tls_info: tls_stream.tls_info(self.config.tls_peer_certificates).map(Arc::new),
+            proxy_header,
+        };
+
         // update timer to first request timeout.
         self.update_first_request_deadline(timer.as_mut());
 
-        let mut conn = ::h2::server::Builder::new()
-            .enable_connect_protocol()
+        let mut conn = self
+            .config
+            .h2
+            .builder()
             .handshake(PollIoAdapter(tls_stream))
             .timeout(timer.as_mut())
             .await
@@ -69,8 +89,9 @@ where
         let dispatcher = Dispatcher::new(
             &mut conn,
             addr,
+            conn_info,
             timer,
-            self.config.keep_alive_timeout,
+            self.config.h2_keep_alive(),
             &self.service,
             self.date.get(),
         );
@@ -95,6 +116,7 @@ mod io_uring {
     };
 
     use crate::{
+        bytes::Bytes,
         config::HttpServiceConfig,
         date::{DateTime, DateTimeService},
         util::timer::KeepAlive,
@@ -1,28 +1,82 @@
 use core::{
     pin::Pin,
-    task::{Context, Poll},
+    task::{ready, Context, Poll},
 };
 
 use ::h3::server::RequestStream;
-use futures_core::stream::Stream;
+use futures_core::{future::BoxFuture, stream::Stream};
 use h3_quinn::RecvStream;
 
 use crate::{
+    body::remove_forbidden_trailers,
     bytes::{Buf, Bytes},
     error::BodyError,
+    http::HeaderMap,
 };
 
 /// Request body type for Http/3 specifically.
-pub struct RequestBody(pub(super) RequestStream<RecvStream, Bytes>);
+pub struct RequestBody {
+    state: State,
+    trailers: Option<HeaderMap>,
+}
+
+enum State {
+    Data(RequestStream<RecvStream, Bytes>),
+    // receiving trailers is async and takes ownership of stream after data frames are consumed.
+    Trailers(BoxFuture<'static, Result<Option<HeaderMap>, BodyError>>),
+    End,
+}
+
+impl RequestBody {
+    pub(super) fn new(stream: RequestStream<RecvStream, Bytes>) -> Self {
+        Self {
+            state: State::Data(stream),
+            trailers: None,
+        }
+    }
+
+    /// take trailer fields received after request body data. See
+    /// [crate::body::RequestBody::take_trailers] for detail.
+    pub fn take_trailers(&mut self) -> Option<HeaderMap> {
+        self.trailers.take()
+    }
+}
 
 impl Stream for RequestBody {
     type Item = Result<Bytes, BodyError>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.get_mut()
-            .0
-            .poll_recv_data(cx)?
-            .map(|res| res.map(|buf| Ok(Bytes::copy_from_slice(buf.chunk()))))
+        let this = self.get_mut();
+
+        loop {
+            match this.state {
+                State::Data(ref mut stream) => {
+                    if let Some(buf) = ready!(stream.poll_recv_data(cx))? {
+                        return Poll::Ready(Some(Ok(Bytes::copy_from_slice(buf.chunk()))));
+                    }
+
+                    let State::Data(mut stream) = core::mem::replace(&mut this.state, State::End) else {
+                        unreachable!()
+                    };
+                    this.state = State::Trailers(Box::pin(async move { Ok(stream.recv_trailers().await?) }));
+                }
+                State::Trailers(ref mut fut) => {
+                    let res = ready!(fut.as_mut().poll(cx));
+                    this.state = State::End;
+                    return match res {
+                        Ok(trailers) => {
+                            this.trailers = trailers.map(|mut trailers| {
+                                remove_forbidden_trailers(&mut trailers);
+                                trailers
+                            });
+                            Poll::Ready(None)
+                        }
+                        Err(e) => Poll::Ready(Some(Err(e))),
+                    };
+                }
+                State::End => return Poll::Ready(None),
+            }
+        }
     }
 }
 
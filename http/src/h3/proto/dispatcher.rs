@@ -11,11 +11,12 @@ use ::h3::{
     server::{self, RequestStream},
 };
 use futures_core::stream::Stream;
-use xitca_io::net::QuicStream;
+use xitca_io::net::{InFlight, QuicStream};
 use xitca_service::Service;
 use xitca_unsafe_collection::futures::{Select, SelectOutput};
 
 use crate::{
+    body::{remove_forbidden_trailers, Frame},
     bytes::Bytes,
     error::HttpServiceError,
     h3::{body::RequestBody, error::Error},
@@ -31,12 +32,13 @@ pub(crate) struct Dispatcher<'a, S, ReqB> {
     _req_body: PhantomData<ReqB>,
 }
 
-impl<'a, S, ReqB, ResB, BE> Dispatcher<'a, S, ReqB>
+impl<'a, S, ReqB, ResB, BE, BF> Dispatcher<'a, S, ReqB>
 where
     S: Service<Request<RequestExt<ReqB>>, Response = Response<ResB>>,
     S::Error: fmt::Debug,
 
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     BE: fmt::Debug,
 
     ReqB: From<RequestBody>,
@@ -68,11 +70,13 @@ where
 
                     // Reconstruct Request to attach crate body type.
                     let req = req.map(|_| {
-                        let body = ReqB::from(RequestBody(rx));
+                        let body = ReqB::from(RequestBody::new(rx));
                         RequestExt::from_parts(body, Extension::new(self.addr))
                     });
 
+                    let in_flight = InFlight::guard();
                     queue.push(async move {
+                        let _in_flight = in_flight;
                         let fut = self.service.call(req);
                         h3_handler(fut, tx).await
                     });
@@ -93,14 +97,15 @@ where
     }
 }
 
-async fn h3_handler<'a, Fut, C, ResB, SE, BE>(
+async fn h3_handler<'a, Fut, C, ResB, SE, BE, BF>(
     fut: Fut,
     mut stream: RequestStream<C, Bytes>,
 ) -> Result<(), Error<SE, BE>>
 where
     Fut: Future<Output = Result<Response<ResB>, SE>> + 'a,
     C: SendStream<Bytes>,
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
 {
     let (parts, body) = fut.await.map_err(Error::Service)?.into_parts();
     let res = Response::from_parts(parts, ());
@@ -109,8 +114,14 @@ where
     let mut body = pin!(body);
 
     while let Some(res) = poll_fn(|cx| body.as_mut().poll_next(cx)).await {
-        let bytes = res.map_err(Error::Body)?;
-        stream.send_data(bytes).await?;
+        match res.map_err(Error::Body)?.into() {
+            Frame::Data(bytes) => stream.send_data(bytes).await?,
+            Frame::Trailers(mut trailers) => {
+                remove_forbidden_trailers(&mut trailers);
+                stream.send_trailers(trailers).await?;
+                break;
+            }
+        }
     }
 
     stream.finish().await?;
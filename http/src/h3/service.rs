@@ -5,7 +5,7 @@ use xitca_io::net::QuicStream;
 use xitca_service::{ready::ReadyService, Service};
 
 use crate::{
-    bytes::Bytes,
+    body::Frame,
     error::HttpServiceError,
     http::{Request, RequestExt, Response},
 };
@@ -24,12 +24,13 @@ impl<S> H3Service<S> {
     }
 }
 
-impl<S, ResB, BE> Service<(QuicStream, SocketAddr)> for H3Service<S>
+impl<S, ResB, BE, BF> Service<(QuicStream, SocketAddr)> for H3Service<S>
 where
     S: Service<Request<RequestExt<RequestBody>>, Response = Response<ResB>>,
     S::Error: fmt::Debug,
 
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     BE: fmt::Debug,
 {
     type Response = ();
@@ -10,6 +10,8 @@ use core::{
     task::{Context, Poll},
 };
 
+use std::sync::Arc;
+
 use futures_core::stream::Stream;
 use pin_project_lite::pin_project;
 
@@ -113,7 +115,7 @@ where
 }
 
 #[cfg(feature = "router")]
-use super::util::service::router::Params;
+use super::util::service::router::{Params, RouteNames};
 
 pin_project! {
     /// extension types for [Request]
@@ -147,10 +149,17 @@ pub(crate) struct Extension(Box<_Extension>);
 
 impl Extension {
     pub(crate) fn new(addr: SocketAddr) -> Self {
+        Self::with_connection_info(addr, ConnectionInfo::default())
+    }
+
+    pub(crate) fn with_connection_info(addr: SocketAddr, conn: ConnectionInfo) -> Self {
         Self(Box::new(_Extension {
             addr,
+            conn,
             #[cfg(feature = "router")]
             params: Default::default(),
+            #[cfg(feature = "router")]
+            route_names: Default::default(),
         }))
     }
 }
@@ -158,8 +167,130 @@ impl Extension {
 #[derive(Clone, Debug)]
 struct _Extension {
     addr: SocketAddr,
+    conn: ConnectionInfo,
     #[cfg(feature = "router")]
     params: Params,
+    #[cfg(feature = "router")]
+    route_names: RouteNames,
+}
+
+// information of connection shared by all requests from it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ConnectionInfo {
+    pub(crate) tls_info: Option<Arc<TlsInfo>>,
+    pub(crate) proxy_header: Option<Arc<ProxyHeader>>,
+}
+
+/// information of tls connection a request is received from. it's shared by all requests from
+/// the same connection.
+#[derive(Clone, Debug, Default)]
+pub struct TlsInfo {
+    pub(crate) server_name: Option<String>,
+    pub(crate) alpn_protocol: Option<Vec<u8>>,
+    pub(crate) protocol_version: Option<&'static str>,
+    pub(crate) cipher_suite: Option<&'static str>,
+    pub(crate) peer_certificates: Vec<Vec<u8>>,
+}
+
+impl TlsInfo {
+    /// server name(SNI) client asked for in tls handshake.
+    #[inline]
+    pub fn server_name(&self) -> Option<&str> {
+        self.server_name.as_deref()
+    }
+
+    /// application protocol negotiated through ALPN. e.g. `b"h2"` or `b"http/1.1"`.
+    #[inline]
+    pub fn alpn_protocol(&self) -> Option<&[u8]> {
+        self.alpn_protocol.as_deref()
+    }
+
+    /// name of negotiated tls protocol version. e.g. `TLSv1_3` for rustls and `TLSv1.3` for openssl.
+    #[inline]
+    pub fn protocol_version(&self) -> Option<&str> {
+        self.protocol_version
+    }
+
+    /// name of negotiated cipher suite. naming follows the convention of tls backend in use.
+    #[inline]
+    pub fn cipher_suite(&self) -> Option<&str> {
+        self.cipher_suite
+    }
+
+    /// client's end entity certificate in DER format. only available when client presented it
+    /// (mutual tls) and [HttpServiceConfig::tls_peer_certificates] is enabled.
+    ///
+    /// certificate can be parsed with a x509 parser for it's subject, issuer and other fields.
+    ///
+    /// [HttpServiceConfig::tls_peer_certificates]: crate::config::HttpServiceConfig::tls_peer_certificates
+    #[inline]
+    pub fn peer_certificate(&self) -> Option<&[u8]> {
+        self.peer_certificates.first().map(Vec::as_slice)
+    }
+
+    /// client's certificate chain in DER format. the first certificate is the end entity
+    /// certificate and the rest are intermediates. empty when client did not present any or
+    /// [HttpServiceConfig::tls_peer_certificates] is not enabled.
+    ///
+    /// native-tls backend can only provide the end entity certificate.
+    ///
+    /// [HttpServiceConfig::tls_peer_certificates]: crate::config::HttpServiceConfig::tls_peer_certificates
+    #[inline]
+    pub fn peer_certificate_chain(&self) -> &[Vec<u8>] {
+        &self.peer_certificates
+    }
+}
+
+/// PROXY protocol header received from load balancer in front of server. it's shared by all
+/// requests from the same connection.
+///
+/// See <https://www.haproxy.org/download/2.9/doc/proxy-protocol.txt> for detail of the protocol.
+#[derive(Clone, Debug, Default)]
+pub struct ProxyHeader {
+    pub(crate) version: u8,
+    pub(crate) source: Option<SocketAddr>,
+    pub(crate) destination: Option<SocketAddr>,
+    pub(crate) tlvs: Vec<(u8, Box<[u8]>)>,
+}
+
+impl ProxyHeader {
+    /// version of PROXY protocol. `1` for text header and `2` for binary header.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// address of client connected to load balancer. `None` when load balancer did not provide it.
+    /// (e.g. `PROXY UNKNOWN` of v1 header and `LOCAL` command of v2 header)
+    #[inline]
+    pub fn source(&self) -> Option<SocketAddr> {
+        self.source
+    }
+
+    /// address client connected to on load balancer.
+    #[inline]
+    pub fn destination(&self) -> Option<SocketAddr> {
+        self.destination
+    }
+
+    /// value of the first TLV extension with given type. only v2 header can carry extensions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use xitca_http::http::ProxyHeader;
+    /// fn authority(header: &ProxyHeader) -> Option<&str> {
+    ///     // PP2_TYPE_AUTHORITY
+    ///     header.tlv(0x02).and_then(|value| std::str::from_utf8(value).ok())
+    /// }
+    /// ```
+    pub fn tlv(&self, ty: u8) -> Option<&[u8]> {
+        self.tlvs.iter().find(|(t, _)| *t == ty).map(|(_, value)| &**value)
+    }
+
+    /// iterate over all TLV extensions in (type, value) pairs in order of appearance.
+    pub fn tlvs(&self) -> impl Iterator<Item = (u8, &[u8])> {
+        self.tlvs.iter().map(|(ty, value)| (*ty, &**value))
+    }
 }
 
 impl<B> RequestExt<B> {
@@ -182,6 +313,27 @@ impl<B> RequestExt<B> {
         &mut self.ext.0.addr
     }
 
+    /// retrieve information of tls connection request is received from.
+    ///
+    /// # Default
+    /// `None` is returned when request is received from plain text connection, Http/3 connection
+    /// or connection served by io-uring services.
+    #[inline]
+    pub fn tls_info(&self) -> Option<&TlsInfo> {
+        self.ext.0.conn.tls_info.as_deref()
+    }
+
+    /// retrieve PROXY protocol header of connection request is received from. socket address
+    /// provided by the header overrides [RequestExt::socket_addr].
+    ///
+    /// # Default
+    /// `None` is returned when PROXY protocol is not enabled. See
+    /// [HttpServiceConfig::proxy_protocol](crate::config::HttpServiceConfig::proxy_protocol) for detail.
+    #[inline]
+    pub fn proxy_header(&self) -> Option<&ProxyHeader> {
+        self.ext.0.conn.proxy_header.as_deref()
+    }
+
     /// map body type of self to another type with given function closure.
     #[inline]
     pub fn map_body<F, B1>(self, func: F) -> RequestExt<B1>
@@ -253,6 +405,21 @@ mod router {
         pub fn params_mut(&mut self) -> &mut Params {
             &mut self.ext.0.params
         }
+
+        /// retrieve shared reference of [RouteNames] for generating path of named route.
+        ///
+        /// # Default
+        /// empty [RouteNames] is returned when request is not routed or no route is named.
+        #[inline]
+        pub fn route_names(&self) -> &RouteNames {
+            &self.ext.0.route_names
+        }
+
+        /// retrieve exclusive reference of [RouteNames].
+        #[inline]
+        pub fn route_names_mut(&mut self) -> &mut RouteNames {
+            &mut self.ext.0.route_names
+        }
     }
 
     impl<B> Borrow<Params> for RequestExt<B> {
@@ -268,6 +435,20 @@ mod router {
             self.params_mut()
         }
     }
+
+    impl<B> Borrow<RouteNames> for RequestExt<B> {
+        #[inline]
+        fn borrow(&self) -> &RouteNames {
+            self.route_names()
+        }
+    }
+
+    impl<B> BorrowMut<RouteNames> for RequestExt<B> {
+        #[inline]
+        fn borrow_mut(&mut self) -> &mut RouteNames {
+            self.route_names_mut()
+        }
+    }
 }
 
 /// trait for Borrow &T from &Self.
@@ -296,6 +477,13 @@ impl<Ext> BorrowReq<Method> for Request<Ext> {
     }
 }
 
+impl<Ext> BorrowReqMut<Method> for Request<Ext> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut Method {
+        self.method_mut()
+    }
+}
+
 impl<Ext> BorrowReq<HeaderMap> for Request<Ext> {
     #[inline]
     fn borrow(&self) -> &HeaderMap {
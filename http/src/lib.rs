@@ -41,6 +41,8 @@ mod tls;
 #[cfg(feature = "runtime")]
 mod builder;
 #[cfg(feature = "runtime")]
+mod proxy;
+#[cfg(feature = "runtime")]
 mod service;
 #[cfg(feature = "runtime")]
 mod version;
@@ -67,6 +69,7 @@ pub use self::{
     body::{RequestBody, ResponseBody},
     error::{BodyError, HttpServiceError},
     http::{Request, Response},
+    tls::AsTlsInfo,
 };
 
 #[cfg(feature = "runtime")]
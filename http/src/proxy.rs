@@ -0,0 +1,372 @@
+//! PROXY protocol v1 and v2 header decoding.
+//!
+//! See <https://www.haproxy.org/download/2.9/doc/proxy-protocol.txt> for the specification.
+
+use core::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    str,
+};
+
+use std::io;
+
+use xitca_io::{
+    io::{AsyncIo, Interest},
+    net::TcpStream,
+};
+
+use crate::http::ProxyHeader;
+
+const V1_PREFIX: &[u8] = b"PROXY ";
+// max length of v1 header including the ending CRLF.
+const V1_MAX_LEN: usize = 107;
+
+const V2_SIGNATURE: &[u8; 12] = b"\r\n\r\n\0\r\nQUIT\n";
+const V2_HEADER_LEN: usize = 16;
+// v2 header can carry arbitrary length of TLV extensions. limit it to a reasonable size.
+const V2_MAX_LEN: usize = 4096;
+
+/// read PROXY protocol header from io. bytes after the header are left untouched in io.
+///
+/// io is peeked for a complete header before it's consumed so no extra bytes are read.
+pub(crate) async fn read_header(io: &mut TcpStream) -> io::Result<ProxyHeader> {
+    let mut buf = Vec::new();
+    let mut peek = [0; 512];
+
+    loop {
+        let n = io.peek(&mut peek).await?;
+        if n == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        let start = buf.len();
+        buf.extend_from_slice(&peek[..n]);
+
+        match decode(&buf)? {
+            Some((len, header)) => {
+                consume(io, len - start).await?;
+                return Ok(header);
+            }
+            // every peeked byte belongs to the header. consume them and wait for more.
+            None => consume(io, n).await?,
+        }
+    }
+}
+
+#[cfg(any(feature = "http1", feature = "http2"))]
+/// read PROXY protocol header from io that can not be peeked. bytes after the header are left
+/// untouched in io.
+///
+/// io is read in exact amount of bytes needed by the header. v2 header is read with it's length
+/// field and v1 header is read byte by byte until it's ending CRLF.
+pub(crate) async fn read_header_exact<Io: AsyncIo>(io: &mut Io) -> io::Result<ProxyHeader> {
+    // v2 signature is longer than v1 prefix and shorter than the shortest v1 header(`PROXY UNKNOWN\r\n`).
+    let mut buf = vec![0; V2_SIGNATURE.len()];
+    read_exact(io, &mut buf).await?;
+
+    loop {
+        if let Some((_, header)) = decode(&buf)? {
+            return Ok(header);
+        }
+
+        let start = buf.len();
+        let len = if !buf.starts_with(V2_SIGNATURE) {
+            start + 1
+        } else if start < V2_HEADER_LEN {
+            V2_HEADER_LEN
+        } else {
+            // length is already checked by decode_v2.
+            V2_HEADER_LEN + port(&buf[14..16]) as usize
+        };
+        buf.resize(len, 0);
+        read_exact(io, &mut buf[start..]).await?;
+    }
+}
+
+#[cfg(any(feature = "http1", feature = "http2"))]
+async fn read_exact<Io: AsyncIo>(io: &mut Io, mut buf: &mut [u8]) -> io::Result<()> {
+    while !buf.is_empty() {
+        match io.read(buf) {
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => buf = &mut buf[n..],
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                io.ready(Interest::READABLE).await?;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+// read and drop exact given amount of bytes that are already peeked.
+async fn consume(io: &mut TcpStream, mut len: usize) -> io::Result<()> {
+    let mut buf = [0; 512];
+
+    while len > 0 {
+        let max = len.min(buf.len());
+        match io::Read::read(io, &mut buf[..max]) {
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => len -= n,
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                io.ready(Interest::READABLE).await?;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+/// decode PROXY protocol header from given bytes. return length of header and decoded header
+/// when it's complete. `None` is returned when more bytes are needed.
+pub(crate) fn decode(buf: &[u8]) -> io::Result<Option<(usize, ProxyHeader)>> {
+    let len = buf.len().min(V2_SIGNATURE.len());
+
+    if buf[..len] == V2_SIGNATURE[..len] {
+        return if len < V2_SIGNATURE.len() {
+            Ok(None)
+        } else {
+            decode_v2(buf)
+        };
+    }
+
+    let len = buf.len().min(V1_PREFIX.len());
+
+    if buf[..len] == V1_PREFIX[..len] {
+        return decode_v1(buf);
+    }
+
+    Err(invalid("missing PROXY protocol header"))
+}
+
+fn decode_v1(buf: &[u8]) -> io::Result<Option<(usize, ProxyHeader)>> {
+    let Some(end) = buf.windows(2).position(|w| w == b"\r\n") else {
+        return if buf.len() < V1_MAX_LEN {
+            Ok(None)
+        } else {
+            Err(invalid("PROXY v1 header too long"))
+        };
+    };
+
+    let len = end + 2;
+
+    if len > V1_MAX_LEN {
+        return Err(invalid("PROXY v1 header too long"));
+    }
+
+    let line = str::from_utf8(&buf[V1_PREFIX.len()..end]).map_err(|_| invalid("PROXY v1 header is not ascii"))?;
+
+    let mut parts = line.split(' ');
+
+    let mut header = ProxyHeader {
+        version: 1,
+        ..Default::default()
+    };
+
+    let is_v4 = match parts.next() {
+        // address is ignored for unknown protocol.
+        Some("UNKNOWN") => return Ok(Some((len, header))),
+        Some("TCP4") => true,
+        Some("TCP6") => false,
+        _ => return Err(invalid("PROXY v1 header has unknown protocol")),
+    };
+
+    let mut next = || parts.next().ok_or_else(|| invalid("PROXY v1 header is incomplete"));
+
+    let src_ip = next()?
+        .parse::<IpAddr>()
+        .map_err(|_| invalid("PROXY v1 header has invalid address"))?;
+    let dst_ip = next()?
+        .parse::<IpAddr>()
+        .map_err(|_| invalid("PROXY v1 header has invalid address"))?;
+    let src_port = next()?
+        .parse::<u16>()
+        .map_err(|_| invalid("PROXY v1 header has invalid port"))?;
+    let dst_port = next()?
+        .parse::<u16>()
+        .map_err(|_| invalid("PROXY v1 header has invalid port"))?;
+
+    if parts.next().is_some() {
+        return Err(invalid("PROXY v1 header has trailing data"));
+    }
+
+    if src_ip.is_ipv4() != is_v4 || dst_ip.is_ipv4() != is_v4 {
+        return Err(invalid("PROXY v1 header has address not matching protocol"));
+    }
+
+    header.source = Some(SocketAddr::new(src_ip, src_port));
+    header.destination = Some(SocketAddr::new(dst_ip, dst_port));
+
+    Ok(Some((len, header)))
+}
+
+fn decode_v2(buf: &[u8]) -> io::Result<Option<(usize, ProxyHeader)>> {
+    if buf.len() < V2_HEADER_LEN {
+        return Ok(None);
+    }
+
+    let ver_cmd = buf[12];
+    let family = buf[13];
+    let len = V2_HEADER_LEN + u16::from_be_bytes([buf[14], buf[15]]) as usize;
+
+    if ver_cmd >> 4 != 2 {
+        return Err(invalid("PROXY v2 header has unsupported version"));
+    }
+
+    if len > V2_MAX_LEN {
+        return Err(invalid("PROXY v2 header too long"));
+    }
+
+    if buf.len() < len {
+        return Ok(None);
+    }
+
+    let mut header = ProxyHeader {
+        version: 2,
+        ..Default::default()
+    };
+
+    let mut rest = &buf[V2_HEADER_LEN..len];
+
+    match ver_cmd & 0x0f {
+        // LOCAL command. connection is established by proxy itself and address block is ignored.
+        0x0 => return Ok(Some((len, header))),
+        // PROXY command.
+        0x1 => {}
+        _ => return Err(invalid("PROXY v2 header has unsupported command")),
+    }
+
+    // upper 4 bits is address family and lower 4 bits is transport protocol.
+    match family >> 4 {
+        // AF_UNSPEC
+        0x0 => {}
+        // AF_INET
+        0x1 => {
+            let addr = split(&mut rest, 12)?;
+            let src = Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3]);
+            let dst = Ipv4Addr::new(addr[4], addr[5], addr[6], addr[7]);
+            header.source = Some(SocketAddr::new(src.into(), port(&addr[8..10])));
+            header.destination = Some(SocketAddr::new(dst.into(), port(&addr[10..12])));
+        }
+        // AF_INET6
+        0x2 => {
+            let addr = split(&mut rest, 36)?;
+            let src = Ipv6Addr::from(<[u8; 16]>::try_from(&addr[..16]).unwrap());
+            let dst = Ipv6Addr::from(<[u8; 16]>::try_from(&addr[16..32]).unwrap());
+            header.source = Some(SocketAddr::new(src.into(), port(&addr[32..34])));
+            header.destination = Some(SocketAddr::new(dst.into(), port(&addr[34..36])));
+        }
+        // AF_UNIX. unix socket path can not be represented by SocketAddr.
+        0x3 => {
+            split(&mut rest, 216)?;
+        }
+        _ => return Err(invalid("PROXY v2 header has unsupported address family")),
+    }
+
+    while !rest.is_empty() {
+        let head = split(&mut rest, 3)?;
+        let value = split(&mut rest, port(&head[1..3]) as usize)?;
+        header.tlvs.push((head[0], value.into()));
+    }
+
+    Ok(Some((len, header)))
+}
+
+fn split<'a>(buf: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(invalid("PROXY v2 header is truncated"));
+    }
+    let (head, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(head)
+}
+
+fn port(buf: &[u8]) -> u16 {
+    u16::from_be_bytes([buf[0], buf[1]])
+}
+
+fn invalid(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn v1() {
+        let buf = b"PROXY TCP4 192.168.0.1 192.168.0.11 56324 443\r\nGET / HTTP/1.1\r\n";
+        let (len, header) = decode(buf).unwrap().unwrap();
+        assert_eq!(&buf[len..], b"GET / HTTP/1.1\r\n");
+        assert_eq!(header.version(), 1);
+        assert_eq!(header.source(), Some("192.168.0.1:56324".parse().unwrap()));
+        assert_eq!(header.destination(), Some("192.168.0.11:443".parse().unwrap()));
+
+        let buf = b"PROXY TCP6 ::1 ::2 1 2\r\n";
+        let (_, header) = decode(buf).unwrap().unwrap();
+        assert_eq!(header.source(), Some("[::1]:1".parse().unwrap()));
+
+        let (len, header) = decode(b"PROXY UNKNOWN\r\n").unwrap().unwrap();
+        assert_eq!(len, 15);
+        assert!(header.source().is_none());
+
+        assert!(decode(b"PRO").unwrap().is_none());
+        assert!(decode(b"PROXY TCP4 192.168.0.1").unwrap().is_none());
+
+        assert!(decode(b"GET / HTTP/1.1\r\n").is_err());
+        assert!(decode(b"PROXY TCP4 192.168.0.1 192.168.0.11 56324\r\n").is_err());
+        assert!(decode(b"PROXY TCP4 192.168.0.1 192.168.0.11 56324 99999\r\n").is_err());
+        assert!(decode(b"PROXY TCP4 ::1 ::2 1 2\r\n").is_err());
+        assert!(decode(b"PROXY TCP6 192.168.0.1 192.168.0.11 1 2\r\n").is_err());
+        assert!(decode(b"PROXY TCP6 ::1 192.168.0.11 1 2\r\n").is_err());
+
+        let mut buf = V1_PREFIX.to_vec();
+        buf.resize(V1_MAX_LEN, b'a');
+        assert!(decode(&buf).is_err());
+    }
+
+    #[test]
+    fn v2() {
+        let mut buf = V2_SIGNATURE.to_vec();
+        // PROXY command over TCP4.
+        buf.extend_from_slice(&[0x21, 0x11]);
+        // address block and a PP2_TYPE_AUTHORITY TLV.
+        buf.extend_from_slice(&(12u16 + 3 + 9).to_be_bytes());
+        buf.extend_from_slice(&[127, 0, 0, 1, 127, 0, 0, 2]);
+        buf.extend_from_slice(&8080u16.to_be_bytes());
+        buf.extend_from_slice(&443u16.to_be_bytes());
+        buf.extend_from_slice(&[0x02, 0, 9]);
+        buf.extend_from_slice(b"localhost");
+
+        for i in 0..buf.len() {
+            assert!(decode(&buf[..i]).unwrap().is_none());
+        }
+
+        buf.extend_from_slice(b"GET");
+
+        let (len, header) = decode(&buf).unwrap().unwrap();
+        assert_eq!(&buf[len..], b"GET");
+        assert_eq!(header.version(), 2);
+        assert_eq!(header.source(), Some("127.0.0.1:8080".parse().unwrap()));
+        assert_eq!(header.destination(), Some("127.0.0.2:443".parse().unwrap()));
+        assert_eq!(header.tlv(0x02), Some(&b"localhost"[..]));
+        assert_eq!(header.tlvs().count(), 1);
+
+        // LOCAL command ignores address block.
+        let mut buf = V2_SIGNATURE.to_vec();
+        buf.extend_from_slice(&[0x20, 0x00, 0, 0]);
+        let (len, header) = decode(&buf).unwrap().unwrap();
+        assert_eq!(len, V2_HEADER_LEN);
+        assert!(header.source().is_none());
+
+        // unsupported version.
+        let mut buf = V2_SIGNATURE.to_vec();
+        buf.extend_from_slice(&[0x31, 0x11, 0, 0]);
+        assert!(decode(&buf).is_err());
+
+        // truncated address block.
+        let mut buf = V2_SIGNATURE.to_vec();
+        buf.extend_from_slice(&[0x21, 0x11, 0, 4, 127, 0, 0, 1]);
+        assert!(decode(&buf).is_err());
+    }
+}
@@ -1,4 +1,12 @@
-use core::{fmt, marker::PhantomData, pin::pin};
+use core::{
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    net::SocketAddr,
+    pin::{pin, Pin},
+};
+
+use std::{io, sync::Arc};
 
 use futures_core::Stream;
 use xitca_io::{
@@ -8,14 +16,15 @@ use xitca_io::{
 use xitca_service::{ready::ReadyService, Service};
 
 use super::{
-    body::RequestBody,
-    bytes::Bytes,
+    body::{Frame, RequestBody},
     config::HttpServiceConfig,
     date::{DateTime, DateTimeService},
     error::{HttpServiceError, TimeoutError},
-    http::{Request, RequestExt, Response},
+    http::{ConnectionInfo, ProxyHeader, Request, RequestExt, Response, Version},
+    proxy,
+    tls::AsTlsInfo,
     util::timer::{KeepAlive, Timeout},
-    version::AsVersion,
+    version::{self, AsVersion, PeekedIo},
 };
 
 pub struct HttpService<
@@ -51,7 +60,6 @@ impl<St, S, ReqB, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, con
         }
     }
 
-    #[cfg(feature = "http2")]
     pub(crate) fn update_first_request_deadline(&self, timer: core::pin::Pin<&mut KeepAlive>) {
         let request_dur = self.config.request_head_timeout;
         let deadline = self.date.get().now() + request_dur;
@@ -68,16 +76,17 @@ impl<St, S, ReqB, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, con
     }
 }
 
-impl<S, ResB, BE, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize>
+impl<S, ResB, BE, BF, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize>
     Service<ServerStream>
     for HttpService<ServerStream, S, RequestBody, A, HEADER_LIMIT, READ_BUF_LIMIT, WRITE_BUF_LIMIT>
 where
     S: Service<Request<RequestExt<RequestBody>>, Response = Response<ResB>>,
     A: Service<TcpStream>,
-    A::Response: AsyncIo + AsVersion,
+    A::Response: AsyncIo + AsVersion + AsTlsInfo,
     HttpServiceError<S::Error, BE>: From<A::Error>,
     S::Error: fmt::Debug,
-    ResB: Stream<Item = Result<Bytes, BE>>,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
     BE: fmt::Debug,
 {
     type Response = ();
@@ -94,8 +103,15 @@ where
                 .run()
                 .await
                 .map_err(From::from),
-            ServerStream::Tcp(io, _addr) => {
-                let io = TcpStream::from_std(io).expect("TODO: handle io error");
+            ServerStream::Tcp(io, mut _addr) => {
+                let mut io = TcpStream::from_std(io).expect("TODO: handle io error");
+
+                let proxy_header = if self.config.proxy_protocol {
+                    let header = proxy_header(proxy::read_header(&mut io), &mut _addr, timer.as_mut()).await?;
+                    Some(header)
+                } else {
+                    None
+                };
                 let mut _tls_stream = self
                     .tls_acceptor
                     .call(io)
@@ -103,78 +119,203 @@ where
                     .await
                     .map_err(|_| HttpServiceError::Timeout(TimeoutError::TlsAccept))??;
 
-                let version = if self.config.peek_protocol {
+                let conn = ConnectionInfo {
+                    tls_info: _tls_stream.tls_info(self.config.tls_peer_certificates).map(Arc::new),
+                    proxy_header,
+                };
+
+                if self.config.peek_protocol {
                     // peek version from connection to figure out the real protocol used
                     // regardless of AsVersion's outcome.
-                    todo!("peek version is not implemented yet!")
+                    let (version, io) = self.peek_version(_tls_stream, timer.as_mut()).await?;
+                    self.dispatch(io, _addr, conn, version, timer).await
                 } else {
-                    _tls_stream.as_version()
-                };
+                    let version = _tls_stream.as_version();
+                    self.dispatch(_tls_stream, _addr, conn, version, timer).await
+                }
+            }
+            #[cfg(unix)]
+            ServerStream::Unix(io, _) => {
+                let io = xitca_io::net::UnixStream::from_std(io).expect("TODO: handle io error");
+                let addr = crate::unspecified_socket_addr();
 
-                match version {
-                    #[cfg(feature = "http1")]
-                    super::http::Version::HTTP_11 | super::http::Version::HTTP_10 => super::h1::dispatcher::run(
-                        &mut _tls_stream,
-                        _addr,
-                        timer.as_mut(),
-                        self.config,
-                        &self.service,
-                        self.date.get(),
+                if self.config.peek_protocol {
+                    let (version, io) = self.peek_version(io, timer.as_mut()).await?;
+                    self.dispatch(io, addr, ConnectionInfo::default(), version, timer).await
+                } else {
+                    self.dispatch(
+                        io,
+                        addr,
+                        ConnectionInfo::default(),
+                        super::http::Version::HTTP_11,
+                        timer,
                     )
                     .await
-                    .map_err(From::from),
-                    #[cfg(feature = "http2")]
-                    super::http::Version::HTTP_2 => {
+                }
+            }
+        }
+    }
+}
+
+// await PROXY protocol header with timer. source address in header overrides given address.
+pub(crate) async fn proxy_header<F, SE, BE>(
+    read: F,
+    addr: &mut SocketAddr,
+    timer: Pin<&mut KeepAlive>,
+) -> Result<Arc<ProxyHeader>, HttpServiceError<SE, BE>>
+where
+    F: Future<Output = io::Result<ProxyHeader>>,
+{
+    let header = read
+        .timeout(timer)
+        .await
+        .map_err(|_| HttpServiceError::Timeout(TimeoutError::ProxyProtocol))?
+        .map_err(HttpServiceError::ProxyProtocol)?;
+    if let Some(source) = header.source() {
+        *addr = source;
+    }
+    Ok(Arc::new(header))
+}
+
+impl<S, ResB, BE, BF, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize>
+    HttpService<ServerStream, S, RequestBody, A, HEADER_LIMIT, READ_BUF_LIMIT, WRITE_BUF_LIMIT>
+where
+    S: Service<Request<RequestExt<RequestBody>>, Response = Response<ResB>>,
+    S::Error: fmt::Debug,
+    ResB: Stream<Item = Result<BF, BE>>,
+    BF: Into<Frame>,
+    BE: fmt::Debug,
+{
+    async fn peek_version<Io>(
+        &self,
+        io: Io,
+        mut timer: Pin<&mut KeepAlive>,
+    ) -> Result<(Version, PeekedIo<Io>), HttpServiceError<S::Error, BE>>
+    where
+        Io: AsyncIo,
+    {
+        // client may open connection ahead of time. wait for the first request instead of tls accept.
+        self.update_first_request_deadline(timer.as_mut());
+
+        version::peek_version(io)
+            .timeout(timer.as_mut())
+            .await
+            .map_err(|_| HttpServiceError::Timeout(TimeoutError::PeekProtocol))?
+            // io error before any request is received is not interesting. connection is simply dropped.
+            .map_err(|_| HttpServiceError::Ignored)
+    }
+
+    async fn dispatch<Io>(
+        &self,
+        #[allow(unused_mut)] mut _io: Io,
+        _addr: SocketAddr,
+        _conn: ConnectionInfo,
+        version: Version,
+        #[allow(unused_mut)] mut _timer: Pin<&mut KeepAlive>,
+    ) -> Result<(), HttpServiceError<S::Error, BE>>
+    where
+        Io: AsyncIo,
+    {
+        match version {
+            #[cfg(all(feature = "http1", not(feature = "http2")))]
+            Version::HTTP_11 | Version::HTTP_10 => super::h1::dispatcher::run(
+                &mut _io,
+                _addr,
+                _conn.clone(),
+                _timer.as_mut(),
+                self.config,
+                &self.service,
+                self.date.get(),
+            )
+            .await
+            .map_err(From::from),
+            // h2c upgrade is only allowed on plain text connection. see RFC 9113 section 3.1
+            #[cfg(all(feature = "http1", feature = "http2"))]
+            Version::HTTP_11 | Version::HTTP_10 if !self.config.h2c_upgrade || _conn.tls_info.is_some() => {
+                super::h1::dispatcher::run(
+                    &mut _io,
+                    _addr,
+                    _conn.clone(),
+                    _timer.as_mut(),
+                    self.config,
+                    &self.service,
+                    self.date.get(),
+                )
+                .await
+                .map_err(From::from)
+            }
+            #[cfg(all(feature = "http1", feature = "http2"))]
+            Version::HTTP_11 | Version::HTTP_10 => {
+                let upgrade = super::h1::dispatcher::run_h2c_upgradable(
+                    &mut _io,
+                    _addr,
+                    _conn.clone(),
+                    _timer.as_mut(),
+                    self.config,
+                    &self.service,
+                    self.date.get(),
+                )
+                .await?;
+
+                match upgrade {
+                    Some(upgrade) => {
                         // update timer to first request timeout.
-                        self.update_first_request_deadline(timer.as_mut());
+                        self.update_first_request_deadline(_timer.as_mut());
 
-                        let mut conn = ::h2::server::Builder::new()
-                            .enable_connect_protocol()
-                            .handshake(xitca_io::io::PollIoAdapter(_tls_stream))
-                            .timeout(timer.as_mut())
+                        let io = super::h2::h2c::upgrade(_io, upgrade)
+                            .timeout(_timer.as_mut())
                             .await
-                            .map_err(|_| HttpServiceError::Timeout(TimeoutError::H2Handshake))??;
-
-                        super::h2::Dispatcher::new(
-                            &mut conn,
-                            _addr,
-                            timer.as_mut(),
-                            self.config.keep_alive_timeout,
-                            &self.service,
-                            self.date.get(),
-                        )
-                        .run()
-                        .await
-                        .map_err(Into::into)
-                    }
-                    version => Err(HttpServiceError::UnSupportedVersion(version)),
-                }
-            }
-            #[cfg(unix)]
-            ServerStream::Unix(_io, _) => {
-                #[cfg(not(feature = "http1"))]
-                {
-                    Err(HttpServiceError::UnSupportedVersion(super::http::Version::HTTP_11))
-                }
+                            .map_err(|_| HttpServiceError::Timeout(TimeoutError::H2Handshake))?
+                            // io error before http/2 connection is established is not interesting.
+                            .map_err(|_| HttpServiceError::Ignored)?;
 
-                #[cfg(feature = "http1")]
-                {
-                    let mut io = xitca_io::net::UnixStream::from_std(_io).expect("TODO: handle io error");
-
-                    super::h1::dispatcher::run(
-                        &mut io,
-                        crate::unspecified_socket_addr(),
-                        timer.as_mut(),
-                        self.config,
-                        &self.service,
-                        self.date.get(),
-                    )
-                    .await
-                    .map_err(From::from)
+                        self.dispatch_h2(io, _addr, _conn, _timer).await
+                    }
+                    None => Ok(()),
                 }
             }
+            #[cfg(feature = "http2")]
+            Version::HTTP_2 => self.dispatch_h2(_io, _addr, _conn, _timer).await,
+            version => Err(HttpServiceError::UnSupportedVersion(version)),
         }
     }
+
+    #[cfg(feature = "http2")]
+    async fn dispatch_h2<Io>(
+        &self,
+        io: Io,
+        addr: SocketAddr,
+        conn_info: ConnectionInfo,
+        mut timer: Pin<&mut KeepAlive>,
+    ) -> Result<(), HttpServiceError<S::Error, BE>>
+    where
+        Io: AsyncIo,
+    {
+        // update timer to first request timeout.
+        self.update_first_request_deadline(timer.as_mut());
+
+        let mut conn = self
+            .config
+            .h2
+            .builder()
+            .handshake(xitca_io::io::PollIoAdapter(io))
+            .timeout(timer.as_mut())
+            .await
+            .map_err(|_| HttpServiceError::Timeout(TimeoutError::H2Handshake))??;
+
+        super::h2::Dispatcher::new(
+            &mut conn,
+            addr,
+            conn_info,
+            timer.as_mut(),
+            self.config.h2_keep_alive(),
+            &self.service,
+            self.date.get(),
+        )
+        .run()
+        .await
+        .map_err(Into::into)
+    }
 }
 
 impl<St, S, ReqB, A, const HEADER_LIMIT: usize, const READ_BUF_LIMIT: usize, const WRITE_BUF_LIMIT: usize> ReadyService
@@ -19,6 +19,24 @@ pub use error::TlsError;
 
 use xitca_service::Service;
 
+use crate::http::TlsInfo;
+
+/// A helper trait for collecting [TlsInfo] from tls stream types.
+pub trait AsTlsInfo {
+    /// `None` when connection is not tls protected. client's certificates are only collected when
+    /// `peer_certificates` is true.
+    fn tls_info(&self, peer_certificates: bool) -> Option<TlsInfo> {
+        let _ = peer_certificates;
+        None
+    }
+}
+
+#[cfg(feature = "runtime")]
+impl AsTlsInfo for xitca_io::net::TcpStream {}
+
+#[cfg(all(feature = "runtime", unix))]
+impl AsTlsInfo for xitca_io::net::UnixStream {}
+
 /// A NoOp Tls Acceptor pass through input Stream type.
 #[derive(Copy, Clone)]
 pub struct NoOpTlsAcceptorBuilder;
@@ -14,9 +14,12 @@ use native_tls::{Error, HandshakeError};
 use xitca_io::io::{AsyncIo, Interest, Ready};
 use xitca_service::Service;
 
-use crate::{http::Version, version::AsVersion};
+use crate::{
+    http::{TlsInfo, Version},
+    version::AsVersion,
+};
 
-use super::error::TlsError;
+use super::{error::TlsError, AsTlsInfo};
 
 /// A wrapper type for [TlsStream](native_tls::TlsStream).
 ///
@@ -36,6 +39,22 @@ impl<Io: AsyncIo> AsVersion for TlsStream<Io> {
     }
 }
 
+impl<Io: AsyncIo> AsTlsInfo for TlsStream<Io> {
+    fn tls_info(&self, peer_certificates: bool) -> Option<TlsInfo> {
+        // native-tls does not expose sni, protocol version and cipher suite.
+        Some(TlsInfo {
+            alpn_protocol: self.io.negotiated_alpn().ok().flatten(),
+            peer_certificates: peer_certificates
+                .then(|| self.io.peer_certificate().ok().flatten())
+                .flatten()
+                .and_then(|cert| cert.to_der().ok())
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct TlsAcceptorBuilder {
     acceptor: TlsAcceptor,
@@ -6,9 +6,12 @@ use xitca_io::io::AsyncIo;
 use xitca_service::Service;
 use xitca_tls::openssl::ssl;
 
-use crate::{http::Version, version::AsVersion};
+use crate::{
+    http::{TlsInfo, Version},
+    version::AsVersion,
+};
 
-use super::error::TlsError;
+use super::{error::TlsError, AsTlsInfo};
 
 pub type TlsStream<Io> = xitca_tls::openssl::TlsStream<Io>;
 
@@ -24,6 +27,35 @@ where
     }
 }
 
+impl<Io> AsTlsInfo for TlsStream<Io>
+where
+    Io: AsyncIo,
+{
+    fn tls_info(&self, peer_certificates: bool) -> Option<TlsInfo> {
+        let session = self.session();
+
+        // peer_cert_chain does not contain client's end entity certificate on server side.
+        let peer_certificates = if peer_certificates {
+            session
+                .peer_certificate()
+                .into_iter()
+                .chain(session.peer_cert_chain().into_iter().flatten().map(ToOwned::to_owned))
+                .filter_map(|cert| cert.to_der().ok())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Some(TlsInfo {
+            server_name: session.servername(ssl::NameType::HOST_NAME).map(Into::into),
+            alpn_protocol: session.selected_alpn_protocol().map(Into::into),
+            protocol_version: Some(session.version_str()),
+            cipher_suite: session.current_cipher().map(|c| c.name()),
+            peer_certificates,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct TlsAcceptorBuilder {
     acceptor: TlsAcceptor,
@@ -6,9 +6,12 @@ use xitca_io::io::AsyncIo;
 use xitca_service::Service;
 use xitca_tls::rustls::{Error, ServerConfig, ServerConnection, TlsStream as _TlsStream};
 
-use crate::{http::Version, version::AsVersion};
+use crate::{
+    http::{TlsInfo, Version},
+    version::AsVersion,
+};
 
-use super::error::TlsError;
+use super::{error::TlsError, AsTlsInfo};
 
 pub(crate) type RustlsConfig = Arc<ServerConfig>;
 
@@ -27,6 +30,26 @@ where
     }
 }
 
+impl<Io> AsTlsInfo for TlsStream<Io>
+where
+    Io: AsyncIo,
+{
+    fn tls_info(&self, peer_certificates: bool) -> Option<TlsInfo> {
+        let session = self.session();
+        Some(TlsInfo {
+            server_name: session.server_name().map(Into::into),
+            alpn_protocol: session.alpn_protocol().map(Into::into),
+            protocol_version: session.protocol_version().and_then(|v| v.as_str()),
+            cipher_suite: session.negotiated_cipher_suite().and_then(|s| s.suite().as_str()),
+            peer_certificates: session
+                .peer_certificates()
+                .filter(|_| peer_certificates)
+                .map(|certs| certs.iter().map(|cert| cert.to_vec()).collect())
+                .unwrap_or_default(),
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct TlsAcceptorBuilder {
     acceptor: Arc<ServerConfig>,
@@ -161,7 +161,10 @@ mod service {
 mod router_impl {
     use xitca_service::object::ServiceObject;
 
-    use crate::util::service::router::{IntoObject, PathGen, RouteGen, RouteObject};
+    use crate::{
+        http::Method,
+        util::service::router::{IntoObject, PathGen, RouteGen, RouteInfo, RouteNames, RouteObject},
+    };
 
     use super::*;
 
@@ -187,6 +190,18 @@ mod router_impl {
                 fn path_gen(&mut self, prefix: &str) -> String {
                     self.0.path_gen(prefix)
                 }
+
+                fn name_gen(&mut self) -> RouteNames {
+                    self.0.name_gen()
+                }
+
+                fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+                    self.0.routes_gen()
+                }
+
+                fn methods_gen(&self) -> Vec<Method> {
+                    self.0.methods_gen()
+                }
             }
 
             impl<I, Req, C> RouteGen for Builder<I, Req, C>
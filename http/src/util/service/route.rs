@@ -4,11 +4,18 @@ use core::{fmt, marker::PhantomData};
 
 use std::error;
 
+use futures_core::stream::Stream;
 use xitca_service::{ready::ReadyService, Service};
 
-use crate::http::{BorrowReq, Method};
+use crate::{
+    body::BodySize,
+    http::{
+        header::{HeaderValue, ALLOW, CONTENT_LENGTH},
+        BorrowReq, BorrowReqMut, Method, Response, StatusCode,
+    },
+};
 
-use super::router::RouterError;
+use super::router::{PathGen, RouterError};
 
 macro_rules! method {
     ($method_fn: ident, $method: ident) => {
@@ -32,9 +39,9 @@ method!(trace, TRACE);
 
 /// a tree type able of routing multiple [Method] against multiple [Service] types in linear manner.
 pub struct Route<R, N, const M: usize> {
-    methods: [Method; M],
+    pub(super) methods: [Method; M],
     route: R,
-    next: N,
+    pub(super) next: N,
 }
 
 type DefaultRoute<R, const M: usize> = Route<R, MethodNotAllowedBuilder<R>, M>;
@@ -109,6 +116,25 @@ impl<R, N, const M: usize> Route<R, N, M> {
     route_method!(connect, CONNECT);
     route_method!(patch, PATCH);
     route_method!(trace, TRACE);
+
+    /// enable automatic handling of [Method::HEAD]. when Route contains [Method::GET] and not
+    /// [Method::HEAD] a HEAD request is served by GET route with it's response body discarded.
+    /// `content-length` header set by GET route is kept and when absent it's derived from the size
+    /// of discarded body.
+    ///
+    /// automatic method handling must be enabled after all methods are added to Route.
+    pub fn auto_head(self) -> AutoRoute<Self> {
+        AutoRoute::new(self).auto_head()
+    }
+
+    /// enable automatic handling of [Method::OPTIONS]. when Route does not contain
+    /// [Method::OPTIONS] an OPTIONS request is responded with `204 No Content` status and `allow`
+    /// header listing all methods of Route.
+    ///
+    /// automatic method handling must be enabled after all methods are added to Route.
+    pub fn auto_options(self) -> AutoRoute<Self> {
+        AutoRoute::new(self).auto_options()
+    }
 }
 
 impl<Arg, R, N, const M: usize> Service<Arg> for Route<R, N, M>
@@ -164,10 +190,31 @@ where
 fn try_append_allowed<E>(mut e: RouterError<E>, methods: &[Method]) -> RouterError<E> {
     if let RouterError::NotAllowed(ref mut e) = e {
         e.0.extend_from_slice(methods);
+        sort_methods(&mut e.0);
     }
     e
 }
 
+// sort methods in canonical order so allowed methods of 405 response and allow header of automatic
+// OPTIONS response are listed the same way regardless of the order routes are chained.
+// standard methods are ordered as they are defined in RFC 9110 followed by PATCH and extension
+// methods in lexicographic order.
+fn sort_methods(methods: &mut [Method]) {
+    const ORDER: [Method; 9] = [
+        Method::GET,
+        Method::HEAD,
+        Method::POST,
+        Method::PUT,
+        Method::DELETE,
+        Method::CONNECT,
+        Method::OPTIONS,
+        Method::TRACE,
+        Method::PATCH,
+    ];
+    let rank = |m: &Method| ORDER.iter().position(|o| o == m).unwrap_or(ORDER.len());
+    methods.sort_by(|a, b| rank(a).cmp(&rank(b)).then_with(|| a.as_str().cmp(b.as_str())));
+}
+
 impl<R, N, const M: usize> ReadyService for RouteService<R, N, M> {
     type Ready = ();
 
@@ -179,7 +226,8 @@ impl<R, N, const M: usize> ReadyService for RouteService<R, N, M> {
 pub struct MethodNotAllowed(pub Box<Vec<Method>>);
 
 impl MethodNotAllowed {
-    /// slice of allowed methods of current route.
+    /// slice of allowed methods of current route. standard methods are listed in the order of
+    /// GET, HEAD, POST, PUT, DELETE, CONNECT, OPTIONS, TRACE and PATCH followed by extension methods.
     pub fn allowed_methods(&self) -> &[Method] {
         &self.0
     }
@@ -233,6 +281,150 @@ where
     }
 }
 
+/// [Route] with automatic [Method::HEAD] and/or [Method::OPTIONS] handling.
+/// See [Route::auto_head] and [Route::auto_options] for detail.
+pub struct AutoRoute<R> {
+    route: R,
+    head: bool,
+    options: bool,
+}
+
+impl<R> AutoRoute<R> {
+    const fn new(route: R) -> Self {
+        Self {
+            route,
+            head: false,
+            options: false,
+        }
+    }
+
+    /// enable automatic handling of [Method::HEAD]. See [Route::auto_head] for detail.
+    pub fn auto_head(mut self) -> Self {
+        self.head = true;
+        self
+    }
+
+    /// enable automatic handling of [Method::OPTIONS]. See [Route::auto_options] for detail.
+    pub fn auto_options(mut self) -> Self {
+        self.options = true;
+        self
+    }
+}
+
+impl<R> AutoRoute<R>
+where
+    R: PathGen,
+{
+    // methods of route and methods handled automatically.
+    pub(super) fn methods(&self) -> (Vec<Method>, Vec<Method>) {
+        let methods = self.route.methods_gen();
+        let mut auto = Vec::new();
+        // empty methods means route accept any method and there is nothing to handle automatically.
+        if !methods.is_empty() {
+            if self.head && methods.contains(&Method::GET) && !methods.contains(&Method::HEAD) {
+                auto.push(Method::HEAD);
+            }
+            if self.options && !methods.contains(&Method::OPTIONS) {
+                auto.push(Method::OPTIONS);
+            }
+        }
+        (methods, auto)
+    }
+}
+
+impl<Arg, R> Service<Arg> for AutoRoute<R>
+where
+    R: Service<Arg> + PathGen,
+{
+    type Response = AutoRouteService<R::Response>;
+    type Error = R::Error;
+
+    async fn call(&self, arg: Arg) -> Result<Self::Response, Self::Error> {
+        let service = self.route.call(arg).await?;
+        let (mut methods, auto) = self.methods();
+        methods.extend_from_slice(&auto);
+        sort_methods(&mut methods);
+        Ok(AutoRouteService {
+            head: auto.contains(&Method::HEAD),
+            options: auto.contains(&Method::OPTIONS),
+            allow: allow_header(&methods),
+            auto,
+            service,
+        })
+    }
+}
+
+fn allow_header(methods: &[Method]) -> HeaderValue {
+    let mut allow = String::new();
+    for method in methods {
+        allow.push_str(method.as_str());
+        allow.push(',');
+    }
+    allow.pop();
+    // method is consisted of token characters that are always valid header value.
+    HeaderValue::try_from(allow).unwrap()
+}
+
+pub struct AutoRouteService<S> {
+    head: bool,
+    options: bool,
+    allow: HeaderValue,
+    auto: Vec<Method>,
+    service: S,
+}
+
+impl<S, Req, B, E> Service<Req> for AutoRouteService<S>
+where
+    S: Service<Req, Response = Response<B>, Error = RouterError<E>>,
+    Req: BorrowReq<Method> + BorrowReqMut<Method>,
+    B: Stream + Default,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+
+    async fn call(&self, mut req: Req) -> Result<Self::Response, Self::Error> {
+        let method: &Method = req.borrow();
+
+        if self.head && *method == Method::HEAD {
+            *req.borrow_mut() = Method::GET;
+            return self.service.call(req).await.map(discard_body);
+        }
+
+        if self.options && *method == Method::OPTIONS {
+            let mut res = Response::new(B::default());
+            *res.status_mut() = StatusCode::NO_CONTENT;
+            res.headers_mut().insert(ALLOW, self.allow.clone());
+            return Ok(res);
+        }
+
+        self.service
+            .call(req)
+            .await
+            .map_err(|e| try_append_allowed(e, &self.auto))
+    }
+}
+
+impl<S> ReadyService for AutoRouteService<S> {
+    type Ready = ();
+
+    #[inline]
+    async fn ready(&self) -> Self::Ready {}
+}
+
+fn discard_body<B>(res: Response<B>) -> Response<B>
+where
+    B: Stream + Default,
+{
+    let (mut parts, body) = res.into_parts();
+    if let BodySize::Sized(size) = BodySize::from_stream(&body) {
+        parts
+            .headers
+            .entry(CONTENT_LENGTH)
+            .or_insert_with(|| HeaderValue::from(size));
+    }
+    Response::from_parts(parts, B::default())
+}
+
 #[cfg(test)]
 mod test {
     use std::convert::Infallible;
@@ -348,13 +540,12 @@ mod test {
         let allowed = e.allowed_methods();
 
         assert_eq!(allowed.len(), 5);
-        // strict allowed method order does not matter.
-        // as long as the test can produce deterministic prediction it's fine.
+        // allowed methods are listed in canonical order regardless of the order of routes.
         assert_eq!(allowed[0], Method::GET);
-        assert_eq!(allowed[1], Method::OPTIONS);
-        assert_eq!(allowed[2], Method::TRACE);
-        assert_eq!(allowed[3], Method::POST);
-        assert_eq!(allowed[4], Method::PUT);
+        assert_eq!(allowed[1], Method::POST);
+        assert_eq!(allowed[2], Method::PUT);
+        assert_eq!(allowed[3], Method::OPTIONS);
+        assert_eq!(allowed[4], Method::TRACE);
 
         let mut req = Request::new(RequestBody::None);
         *req.method_mut() = Method::PUT;
@@ -367,6 +558,79 @@ mod test {
         assert_eq!(res.status().as_u16(), 200);
     }
 
+    #[test]
+    fn route_auto_head() {
+        async fn body(req: Request<RequestBody>) -> Result<Response<ResponseBody>, Infallible> {
+            assert_eq!(req.method(), Method::GET);
+            Ok(Response::new(ResponseBody::bytes("hello")))
+        }
+
+        let service = get(fn_service(body))
+            .post(fn_service(index))
+            .auto_head()
+            .call(())
+            .now_or_panic()
+            .ok()
+            .unwrap();
+
+        let mut req = Request::new(RequestBody::None);
+        *req.method_mut() = Method::HEAD;
+        let res = service.call(req).now_or_panic().ok().unwrap();
+        assert_eq!(res.status().as_u16(), 200);
+        assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "5");
+        assert!(matches!(BodySize::from_stream(res.body()), BodySize::None));
+
+        let mut req = Request::new(RequestBody::None);
+        *req.method_mut() = Method::OPTIONS;
+        let RouterError::NotAllowed(e) = service.call(req).now_or_panic().err().unwrap() else {
+            panic!("route does not return error on unallowed method request");
+        };
+        assert_eq!(e.allowed_methods(), [Method::GET, Method::HEAD, Method::POST]);
+
+        // explicit head route takes priority.
+        let service = get(fn_service(body))
+            .head(fn_service(index))
+            .auto_head()
+            .call(())
+            .now_or_panic()
+            .ok()
+            .unwrap();
+
+        let mut req = Request::new(RequestBody::None);
+        *req.method_mut() = Method::HEAD;
+        let res = service.call(req).now_or_panic().ok().unwrap();
+        assert!(res.headers().get(CONTENT_LENGTH).is_none());
+    }
+
+    #[test]
+    fn route_auto_options() {
+        let route = put(fn_service(index))
+            .post(fn_service(index))
+            .auto_options()
+            .auto_head();
+
+        assert_eq!(route.methods_gen(), [Method::PUT, Method::POST, Method::OPTIONS]);
+
+        let service = route.call(()).now_or_panic().ok().unwrap();
+
+        let mut req = Request::new(RequestBody::None);
+        *req.method_mut() = Method::OPTIONS;
+        let res = service.call(req).now_or_panic().ok().unwrap();
+        assert_eq!(res.status().as_u16(), 204);
+        let allow = res.headers().get(ALLOW).unwrap().clone();
+        assert_eq!(allow, "POST,PUT,OPTIONS");
+
+        // no get route for automatic head.
+        let mut req = Request::new(RequestBody::None);
+        *req.method_mut() = Method::HEAD;
+        let RouterError::NotAllowed(e) = service.call(req).now_or_panic().err().unwrap() else {
+            panic!("route does not return error on unallowed method request");
+        };
+        assert_eq!(e.allowed_methods(), [Method::POST, Method::PUT, Method::OPTIONS]);
+        // 405 and OPTIONS responses list allowed methods the same way.
+        assert_eq!(allow_header(e.allowed_methods()), allow);
+    }
+
     #[test]
     fn route_accept_crate_request() {
         get(fn_service(|_: Request<()>| async {
@@ -1,3 +1,7 @@
+mod host;
+mod info;
+mod names;
+
 pub use xitca_router::{params::Params, MatchError};
 
 use core::{fmt, marker::PhantomData};
@@ -6,14 +10,19 @@ use std::{collections::HashMap, error};
 
 use xitca_service::{object::BoxedServiceObject, pipeline::PipelineT, BoxFuture, FnService, Service};
 
-use crate::http::Request;
+use crate::http::{Extensions, Method, Request};
 
 use super::{
     handler::HandlerService,
-    route::{MethodNotAllowed, Route},
+    route::{AutoRoute, MethodNotAllowed, MethodNotAllowedBuilder, Route},
 };
 
-pub use self::object::RouteObject;
+pub use self::{
+    info::RouteInfo,
+    names::{RouteNames, UrlForError},
+    object::RouteObject,
+    service::RouterService,
+};
 
 /// Simple router for matching path and call according service.
 ///
@@ -23,6 +32,10 @@ pub struct Router<Obj> {
     // record for last time PathGen is called with certain route string prefix.
     prefix: Option<usize>,
     routes: HashMap<String, Obj>,
+    names: RouteNames,
+    hosts: Vec<(&'static str, Router<Obj>)>,
+    // registered routes in insertion order. nested router's routes are moved to self.
+    table: Vec<RouteInfo>,
 }
 
 impl<Obj> Default for Router<Obj> {
@@ -36,6 +49,9 @@ impl<Obj> Router<Obj> {
         Router {
             prefix: None,
             routes: HashMap::new(),
+            names: RouteNames::default(),
+            hosts: Vec::new(),
+            table: Vec::new(),
         }
     }
 }
@@ -48,13 +64,72 @@ impl<Obj> Router<Obj> {
     /// # Panic:
     ///
     /// When multiple services inserted to the same path.
-    pub fn insert<F, Arg, Req>(mut self, path: &'static str, mut builder: F) -> Self
+    pub fn insert<F, Arg, Req>(self, path: &'static str, builder: F) -> Self
+    where
+        F: Service<Arg> + RouteGen + Send + Sync,
+        F::Response: Service<Req>,
+        Req: IntoObject<F::Route<F>, Arg, Object = Obj>,
+    {
+        self._insert(None, path, builder)
+    }
+
+    /// Insert a new service builder to given path with a name. The name can be used for generating
+    /// path of the route with [RouteNames]. See [Router::insert] for detail.
+    ///
+    /// # Panic:
+    ///
+    /// When multiple services inserted to the same path or multiple routes registered with the same name.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// # use xitca_http::{
+    /// #   http::{Request, RequestExt, Response},
+    /// #   util::service::router::Router
+    /// # };
+    /// # use xitca_service::fn_service;
+    /// Router::new().insert_named(
+    ///     "user",
+    ///     "/users/:id",
+    ///     fn_service(|req: Request<RequestExt<()>>| async move {
+    ///         // generate path of named route from request.
+    ///         let path = req.body().route_names().url_for("user", [("id", "996")]).unwrap();
+    ///         assert_eq!(path, "/users/996");
+    ///         Ok::<_, Infallible>(Response::new(()))
+    ///     }),
+    /// );
+    /// ```
+    pub fn insert_named<F, Arg, Req>(self, name: &'static str, path: &'static str, builder: F) -> Self
+    where
+        F: Service<Arg> + RouteGen + Send + Sync,
+        F::Response: Service<Req>,
+        Req: IntoObject<F::Route<F>, Arg, Object = Obj>,
+    {
+        self._insert(Some(name), path, builder)
+    }
+
+    fn _insert<F, Arg, Req>(mut self, name: Option<&'static str>, path: &'static str, mut builder: F) -> Self
     where
         F: Service<Arg> + RouteGen + Send + Sync,
         F::Response: Service<Req>,
         Req: IntoObject<F::Route<F>, Arg, Object = Obj>,
     {
         let path = builder.path_gen(path);
+        // names and routes of nested router are moved to self.
+        self.names.extend(builder.name_gen());
+        if let Some(name) = name {
+            self.names.insert(name, path.clone());
+        }
+        match builder.routes_gen() {
+            Some(routes) => self.table.extend(routes),
+            None => self.table.push(RouteInfo {
+                path: path.clone(),
+                methods: builder.methods_gen(),
+                name,
+                host: None,
+                extensions: Extensions::new(),
+            }),
+        }
         assert!(self
             .routes
             .insert(path, Req::into_object(F::route_gen(builder)))
@@ -62,14 +137,114 @@ impl<Obj> Router<Obj> {
         self
     }
 
+    /// Insert a router serving requests to given host. Requests are routed by their host before path
+    /// and request with host not matching any inserted one falls back to routes of Self.
+    ///
+    /// Host is read from uri authority (Http/2 `:authority` pseudo header or absolute form uri of
+    /// Http/1) and `host` header in order. It's matched case-insensitively with port removed.
+    ///
+    /// Host pattern supports the same parameter syntax as path with labels separated by `.`:
+    /// - exact host: `api.example.com`.
+    /// - parameter matching one label: `{tenant}.example.com`. Parameters of host are available
+    ///   together with path parameters.
+    /// - leading catch-all matching one or more labels: `*.tenant.example.com` or `*sub.example.com`
+    ///   where the latter captures the labels to `sub` parameter.
+    ///
+    /// Exact labels have priority over parameter and catch-all ones.
+    ///
+    /// # Panic:
+    ///
+    /// When the same host inserted multiple times or given router contains host routers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// # use xitca_http::{
+    /// #   http::{Request, RequestExt, Response},
+    /// #   util::service::router::Router
+    /// # };
+    /// # use xitca_service::fn_service;
+    /// # async fn handler(_: Request<RequestExt<()>>) -> Result<Response<()>, Infallible> {
+    /// #   Ok(Response::new(()))
+    /// # }
+    /// Router::new()
+    ///     // routes for request to api.example.com
+    ///     .insert_host("api.example.com", Router::new().insert("/users", fn_service(handler)))
+    ///     // routes for request to any sub domain of example.com with it's name captured as parameter.
+    ///     .insert_host("{tenant}.example.com", Router::new().insert("/users", fn_service(handler)))
+    ///     // routes for request to other hosts.
+    ///     .insert("/users", fn_service(handler));
+    /// ```
+    pub fn insert_host(mut self, host: &'static str, mut router: Router<Obj>) -> Self {
+        assert!(
+            router.hosts.is_empty(),
+            "router of host {host} can not contain host routers"
+        );
+        assert!(
+            self.hosts.iter().all(|(h, _)| *h != host),
+            "host {host} is already inserted"
+        );
+        self.names.extend(core::mem::take(&mut router.names));
+        self.table.extend(router.table.drain(..).map(|mut info| {
+            info.host = Some(host);
+            info
+        }));
+        self.hosts.push((host, router));
+        self
+    }
+
+    /// Routes registered to Self in insertion order. Routes of nested routers are listed with full
+    /// path including the prefix of nesting in place of the nested router itself.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// # use xitca_http::{
+    /// #   http::{Method, Request, RequestExt, Response},
+    /// #   util::service::{route::get, router::Router}
+    /// # };
+    /// # use xitca_service::fn_service;
+    /// # async fn handler(_: Request<RequestExt<()>>) -> Result<Response<()>, Infallible> {
+    /// #   Ok(Response::new(()))
+    /// # }
+    /// let router = Router::new()
+    ///     .insert_named("index", "/", get(fn_service(handler)))
+    ///     .insert("/v2", Router::new().insert("/users/:id", fn_service(handler)));
+    ///
+    /// let routes = router.routes();
+    /// assert_eq!(routes[0].path(), "/");
+    /// assert_eq!(routes[0].methods(), &[Method::GET]);
+    /// assert_eq!(routes[0].name(), Some("index"));
+    /// assert_eq!(routes[1].path(), "/v2/users/:id");
+    /// assert!(routes[1].methods().is_empty());
+    /// ```
+    pub fn routes(&self) -> &[RouteInfo] {
+        &self.table
+    }
+
+    /// mutable reference of routes registered to Self. See [Router::routes] for detail.
+    ///
+    /// only [RouteInfo::extensions_mut] is mutable and the route table itself can not be changed.
+    pub fn routes_mut(&mut self) -> &mut [RouteInfo] {
+        &mut self.table
+    }
+
     #[doc(hidden)]
     /// See [TypedRoute] for detail.
     pub fn insert_typed<T, M>(mut self, _: T) -> Router<Obj>
     where
         T: TypedRoute<M, Route = Obj>,
+        Obj: PathGen,
     {
         let path = T::path();
         let route = T::route();
+        self.table.push(RouteInfo {
+            path: String::from(path),
+            methods: route.methods_gen(),
+            name: None,
+            host: None,
+            extensions: Extensions::new(),
+        });
         assert!(self.routes.insert(String::from(path), route).is_none());
         self
     }
@@ -84,6 +259,42 @@ where
     type Error = Obj::Error;
 
     async fn call(&self, arg: Arg) -> Result<Self::Response, Self::Error> {
+        let router = self.tree(arg.clone()).await?;
+
+        let hosts = if self.hosts.is_empty() {
+            None
+        } else {
+            let mut hosts = service::Hosts {
+                router: xitca_router::Router::new(),
+                routers: Vec::with_capacity(self.hosts.len()),
+            };
+
+            for (host, router) in self.hosts.iter() {
+                hosts
+                    .router
+                    .insert(host::pattern_to_path(host), hosts.routers.len())
+                    .unwrap();
+                hosts.routers.push((host, router.tree(arg.clone()).await?));
+            }
+
+            Some(hosts)
+        };
+
+        Ok(service::RouterService {
+            prefix: self.prefix,
+            router,
+            names: self.names.clone(),
+            hosts,
+        })
+    }
+}
+
+impl<Obj> Router<Obj> {
+    async fn tree<Arg>(&self, arg: Arg) -> Result<xitca_router::Router<Obj::Response>, Obj::Error>
+    where
+        Obj: Service<Arg>,
+        Arg: Clone,
+    {
         let mut router = xitca_router::Router::new();
 
         for (path, service) in self.routes.iter() {
@@ -91,10 +302,7 @@ where
             router.insert(path.to_string(), service).unwrap();
         }
 
-        Ok(service::RouterService {
-            prefix: self.prefix,
-            router,
-        })
+        Ok(router)
     }
 }
 
@@ -149,6 +357,31 @@ pub trait PathGen {
     fn path_gen(&mut self, prefix: &str) -> String {
         String::from(prefix)
     }
+
+    /// named route generator. called after [PathGen::path_gen] and returns names registered to
+    /// Self and their full path templates.
+    ///
+    /// default to no named route. types containing nested router must forward it for the names
+    /// to be visible to root router.
+    fn name_gen(&mut self) -> RouteNames {
+        RouteNames::default()
+    }
+
+    /// route table generator. called after [PathGen::path_gen] and returns routes registered to
+    /// Self with their full path.
+    ///
+    /// default to None where Self is treated as a single route. types containing nested router
+    /// must forward it for the routes to be visible to root router.
+    fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+        None
+    }
+
+    /// allowed methods generator. called when Self is treated as a single route.
+    ///
+    /// default to empty where Self is not guarded by method.
+    fn methods_gen(&self) -> Vec<Method> {
+        Vec::new()
+    }
 }
 
 /// trait for specialized route generation when utilizing [Router::insert].
@@ -185,14 +418,30 @@ where
         let prefix = self.prefix.get_or_insert(0);
         *prefix += path.len();
 
-        self.routes.iter_mut().for_each(|(_, v)| {
-            v.path_gen(path.as_str());
-        });
+        self.names.prefix(path.as_str());
+        self.table
+            .iter_mut()
+            .for_each(|info| info.path.insert_str(0, path.as_str()));
+
+        self.routes
+            .iter_mut()
+            .chain(self.hosts.iter_mut().flat_map(|(_, router)| router.routes.iter_mut()))
+            .for_each(|(_, v)| {
+                v.path_gen(path.as_str());
+            });
 
         path.push_str("/*");
 
         path
     }
+
+    fn name_gen(&mut self) -> RouteNames {
+        core::mem::take(&mut self.names)
+    }
+
+    fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+        Some(core::mem::take(&mut self.table))
+    }
 }
 
 impl<Obj> RouteGen for Router<Obj>
@@ -206,9 +455,45 @@ where
     }
 }
 
-impl<R, N, const M: usize> PathGen for Route<R, N, M> {}
+impl<R, N, const M: usize> PathGen for Route<R, N, M>
+where
+    N: PathGen,
+{
+    fn methods_gen(&self) -> Vec<Method> {
+        let mut methods = self.methods.to_vec();
+        methods.extend(self.next.methods_gen());
+        methods
+    }
+}
+
+impl<R> PathGen for MethodNotAllowedBuilder<R> {}
+
+impl<R, N, const M: usize> RouteGen for Route<R, N, M>
+where
+    N: PathGen,
+{
+    type Route<R1> = R1;
+
+    fn route_gen<R1>(route: R1) -> Self::Route<R1> {
+        route
+    }
+}
+
+impl<R> PathGen for AutoRoute<R>
+where
+    R: PathGen,
+{
+    fn methods_gen(&self) -> Vec<Method> {
+        let (mut methods, auto) = self.methods();
+        methods.extend(auto);
+        methods
+    }
+}
 
-impl<R, N, const M: usize> RouteGen for Route<R, N, M> {
+impl<R> RouteGen for AutoRoute<R>
+where
+    R: PathGen,
+{
     type Route<R1> = R1;
 
     fn route_gen<R1>(route: R1) -> Self::Route<R1> {
@@ -243,6 +528,18 @@ where
     fn path_gen(&mut self, prefix: &str) -> String {
         self.first.path_gen(prefix)
     }
+
+    fn name_gen(&mut self) -> RouteNames {
+        self.first.name_gen()
+    }
+
+    fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+        self.first.routes_gen()
+    }
+
+    fn methods_gen(&self) -> Vec<Method> {
+        self.first.methods_gen()
+    }
 }
 
 impl<F, S, M> RouteGen for PipelineT<F, S, M>
@@ -266,6 +563,18 @@ where
     fn path_gen(&mut self, prefix: &str) -> String {
         self.0.path_gen(prefix)
     }
+
+    fn name_gen(&mut self) -> RouteNames {
+        self.0.name_gen()
+    }
+
+    fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+        self.0.routes_gen()
+    }
+
+    fn methods_gen(&self) -> Vec<Method> {
+        self.0.methods_gen()
+    }
 }
 
 impl<S> RouteGen for RouterMapErr<S>
@@ -342,6 +651,18 @@ mod object {
         fn path_gen(&mut self, prefix: &str) -> String {
             self.0.path_gen(prefix)
         }
+
+        fn name_gen(&mut self) -> RouteNames {
+            self.0.name_gen()
+        }
+
+        fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+            self.0.routes_gen()
+        }
+
+        fn methods_gen(&self) -> Vec<Method> {
+            self.0.methods_gen()
+        }
     }
 
     impl<Arg, S, E> RouteGen for RouteObject<Arg, S, E> {
@@ -380,6 +701,18 @@ where
             fn path_gen(&mut self, prefix: &str) -> String {
                 self.0.path_gen(prefix)
             }
+
+            fn name_gen(&mut self) -> RouteNames {
+                self.0.name_gen()
+            }
+
+            fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+                self.0.routes_gen()
+            }
+
+            fn methods_gen(&self) -> Vec<Method> {
+                self.0.methods_gen()
+            }
         }
 
         impl<T, Req> RouteGen for Builder<T, Req>
@@ -428,21 +761,63 @@ pub trait TypedRoute<M = ()> {
 mod service {
     use xitca_service::ready::ReadyService;
 
-    use crate::http::{BorrowReq, BorrowReqMut, Uri};
+    use crate::http::{BorrowReq, BorrowReqMut, HeaderMap, Uri};
 
-    use super::{Params, RouterError, Service};
+    use super::{host, Params, RouteNames, RouterError, Service};
 
+    /// service type produced by [Router](super::Router).
     pub struct RouterService<S> {
         // a length record of prefix of current router.
         // when it's Some the request path has to be sliced to exclude the string path prefix.
         pub(super) prefix: Option<usize>,
         pub(super) router: xitca_router::Router<S>,
+        // named routes of root router. nested router's names are moved to root so it's always
+        // empty for them.
+        pub(super) names: RouteNames,
+        pub(super) hosts: Option<Hosts<S>>,
+    }
+
+    pub(super) struct Hosts<S> {
+        // host patterns in form of path and their index in routers.
+        pub(super) router: xitca_router::Router<usize>,
+        // host patterns and their routers.
+        pub(super) routers: Vec<(&'static str, xitca_router::Router<S>)>,
+    }
+
+    impl<S> Hosts<S> {
+        fn at<Req>(&self, req: &Req) -> Option<(&xitca_router::Router<S>, Params)>
+        where
+            Req: BorrowReq<Uri> + BorrowReq<HeaderMap>,
+        {
+            let path = host::req_to_path(req)?;
+            let xitca_router::Match { value, params } = self.router.at(&path).ok()?;
+            let (pattern, router) = &self.routers[*value];
+            let params = match host::catch_all(pattern) {
+                Some(name) => host::restore_catch_all(params, name),
+                None => params,
+            };
+            Some((router, params))
+        }
+    }
+
+    impl<S> RouterService<S> {
+        /// host pattern registered with [Router::insert_host](super::Router::insert_host) that
+        /// request would be routed with. None when request is routed with routes without host.
+        pub fn matched_host<Req>(&self, req: &Req) -> Option<&'static str>
+        where
+            Req: BorrowReq<Uri> + BorrowReq<HeaderMap>,
+        {
+            let hosts = self.hosts.as_ref()?;
+            let path = host::req_to_path(req)?;
+            let idx = *hosts.router.at(&path).ok()?.value;
+            Some(hosts.routers[idx].0)
+        }
     }
 
     impl<S, Req, E> Service<Req> for RouterService<S>
     where
         S: Service<Req, Error = RouterError<E>>,
-        Req: BorrowReq<Uri> + BorrowReqMut<Params>,
+        Req: BorrowReq<Uri> + BorrowReq<HeaderMap> + BorrowReqMut<Params> + BorrowReqMut<RouteNames>,
     {
         type Response = S::Response;
         type Error = S::Error;
@@ -453,14 +828,28 @@ mod service {
         #[inline]
         fn call(&self, mut req: Req) -> impl core::future::Future<Output = Result<Self::Response, Self::Error>> {
             async {
-                let mut path = req.borrow().path();
+                let (router, host_params) = match self.hosts.as_ref().and_then(|hosts| hosts.at(&req)) {
+                    Some((router, params)) => (router, Some(params)),
+                    None => (&self.router, None),
+                };
+
+                let mut path = BorrowReq::<Uri>::borrow(&req).path();
 
                 if let Some(prefix) = self.prefix {
                     path = &path[prefix..];
                 }
 
-                let xitca_router::Match { value, params } = self.router.at(path).map_err(RouterError::Match)?;
-                *req.borrow_mut() = params;
+                let xitca_router::Match { value, params } = router.at(path).map_err(RouterError::Match)?;
+                *req.borrow_mut() = match host_params {
+                    Some(mut host_params) => {
+                        host_params.extend(params.iter());
+                        host_params
+                    }
+                    None => params,
+                };
+                if !self.names.is_empty() {
+                    *req.borrow_mut() = self.names.clone();
+                }
                 Service::call(value, req).await
             }
         }
@@ -637,6 +1026,155 @@ mod test {
             .unwrap();
     }
 
+    #[test]
+    fn router_named() {
+        async fn url_for(req: Request<RequestExt<()>>) -> Result<Response<()>, Infallible> {
+            let names = req.body().route_names();
+            assert_eq!(names.url_for("root", [("id", "1")]).unwrap(), "/root/1");
+            assert_eq!(names.url_for("nest", [("id", "2")]).unwrap(), "/api/v2/nest/2");
+            assert_eq!(names.url_for("scope", [("", "")]).unwrap(), "/api/v2/");
+            assert!(names.url_for("nest", [("name", "2")]).is_err());
+            Ok(Response::new(()))
+        }
+
+        let service = Router::new()
+            .insert_named("root", "/root/:id", fn_service(url_for))
+            .insert(
+                "/api",
+                Router::new().insert_named(
+                    "scope",
+                    "/v2",
+                    Router::new().insert_named("nest", "/nest/:id", fn_service(url_for)),
+                ),
+            )
+            .call(())
+            .now_or_panic()
+            .unwrap();
+
+        for uri in ["/root/1", "/api/v2/nest/2"] {
+            let req = Request::builder().uri(uri).body(Default::default()).unwrap();
+            service.call(req).now_or_panic().unwrap();
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn router_named_duplicate() {
+        Router::new()
+            .insert_named("root", "/", fn_service(func))
+            .insert("/api", Router::new().insert_named("root", "/", fn_service(func)));
+    }
+
+    #[test]
+    fn router_host() {
+        fn host(
+            name: &'static str,
+        ) -> impl Fn(Request<RequestExt<()>>) -> core::future::Ready<Result<Response<String>, Infallible>> + Clone
+        {
+            move |req| {
+                let params = req
+                    .body()
+                    .params()
+                    .iter()
+                    .map(|(k, v)| format!("{k}={v}"))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                core::future::ready(Ok(Response::new(format!("{name}:{params}"))))
+            }
+        }
+
+        let service = Router::new()
+            .insert_host(
+                "api.example.com",
+                Router::new().insert("/users/:id", fn_service(host("api"))),
+            )
+            .insert_host(
+                "{tenant}.example.com",
+                Router::new().insert("/users/:id", fn_service(host("tenant"))),
+            )
+            .insert_host(
+                "*sub.tenant.example.com",
+                Router::new().insert("/", fn_service(host("sub"))),
+            )
+            .insert_host("*.example.org", Router::new().insert("/", fn_service(host("org"))))
+            .insert("/users/:id", fn_service(host("default")))
+            .call(())
+            .now_or_panic()
+            .unwrap();
+
+        let call = |uri: &str, host: Option<&str>| {
+            let mut req = Request::builder().uri(uri);
+            if let Some(host) = host {
+                req = req.header(crate::http::header::HOST, host);
+            }
+            match service.call(req.body(Default::default()).unwrap()).now_or_panic() {
+                Ok(res) => res.into_body(),
+                Err(RouterError::Match(_)) => String::from("404"),
+                Err(_) => unreachable!(),
+            }
+        };
+
+        assert_eq!(call("/users/1", Some("api.example.com")), "api:id=1");
+        assert_eq!(call("/users/1", Some("API.example.com:8080")), "api:id=1");
+        // Http/2 :authority is carried by uri.
+        assert_eq!(call("https://api.example.com/users/1", None), "api:id=1");
+        assert_eq!(call("/users/1", Some("acme.example.com")), "tenant:tenant=acme,id=1");
+        assert_eq!(call("/", Some("a.b.tenant.example.com")), "sub:sub=a.b");
+        assert_eq!(call("/", Some("a.b.example.org")), "org:");
+        assert_eq!(call("/users/1", Some("example.com")), "default:id=1");
+        assert_eq!(call("/users/1", None), "default:id=1");
+        // host matched but path does not. no fall back to default routes.
+        assert_eq!(call("/", Some("api.example.com")), "404");
+    }
+
+    #[test]
+    fn router_routes() {
+        let router = Router::new()
+            .insert_named("index", "/", get(fn_service(func)).post(fn_service(func)))
+            .insert(
+                "/v2",
+                Router::new()
+                    .insert("/users/:id", fn_service(func).enclosed_fn(enclosed))
+                    .insert(
+                        "/v3/*",
+                        Router::new().insert_named("post", "/posts/{id}", get(fn_service(func))),
+                    ),
+            )
+            .insert_host(
+                "api.example.com",
+                Router::new().insert("/api", Router::new().insert("/users", get(fn_service(func)))),
+            );
+
+        let routes = router
+            .routes()
+            .iter()
+            .map(|r| (r.host(), r.path(), r.methods(), r.name()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            routes,
+            [
+                (None, "/", &[Method::GET, Method::POST][..], Some("index")),
+                (None, "/v2/users/:id", &[], None),
+                (None, "/v2/v3/posts/{id}", &[Method::GET], Some("post")),
+                (Some("api.example.com"), "/api/users", &[Method::GET], None),
+            ]
+        );
+
+        // nested router is listed with prefix of nesting.
+        let router = Router::new().insert("/v1", router);
+        assert_eq!(router.routes()[2].path(), "/v1/v2/v3/posts/{id}");
+        assert_eq!(router.routes()[3].path(), "/v1/api/users");
+    }
+
+    #[test]
+    #[should_panic]
+    fn router_host_duplicate() {
+        Router::new()
+            .insert_host("example.com", Router::new().insert("/", fn_service(func)))
+            .insert_host("example.com", Router::new().insert("/", fn_service(func)));
+    }
+
     #[test]
     fn router_service_call_size() {
         let service = Router::new()
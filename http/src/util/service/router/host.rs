@@ -0,0 +1,148 @@
+//! host matching of [Router](super::Router).
+//!
+//! host is matched with the same tree as path by reversing it's labels into path segments.
+//! `{tenant}.example.com` becomes `/com/example/{tenant}` so parameters, constraints and routing
+//! priority of path are applied to host as is. a leading `*` label becomes catch-all parameter.
+
+use crate::http::{header::HOST, BorrowReq, HeaderMap, Uri};
+
+use super::Params;
+
+// convert host pattern to path pattern. static labels are lower cased.
+pub(super) fn pattern_to_path(pattern: &str) -> String {
+    let mut path = String::with_capacity(pattern.len() + 1);
+    for label in labels(pattern.trim_end_matches('.')).rev() {
+        path.push('/');
+        if label.contains([':', '{', '*']) {
+            path.push_str(label);
+        } else {
+            path.extend(label.chars().map(|c| c.to_ascii_lowercase()));
+        }
+    }
+    path
+}
+
+// name of catch-all parameter of host pattern.
+pub(super) fn catch_all(pattern: &str) -> Option<&str> {
+    pattern
+        .strip_prefix('*')
+        .map(|rest| rest.split('.').next().unwrap_or(""))
+}
+
+// restore the value of catch-all parameter to host labels. `b/a` becomes `a.b`. unnamed catch-all
+// is removed.
+pub(super) fn restore_catch_all(params: Params, name: &str) -> Params {
+    let mut restored = Params::default();
+    restored.extend(params.iter().filter_map(|(key, value)| {
+        if key != name {
+            Some((key, value.into()))
+        } else if name.is_empty() {
+            None
+        } else {
+            Some((key, value.rsplit('/').collect::<Vec<_>>().join(".")))
+        }
+    }));
+    restored
+}
+
+// extract host from request and convert it to path. host from uri authority (Http/2 `:authority`
+// pseudo header or absolute form uri of Http/1) takes priority over `host` header.
+pub(super) fn req_to_path<Req>(req: &Req) -> Option<String>
+where
+    Req: BorrowReq<Uri> + BorrowReq<HeaderMap>,
+{
+    let uri: &Uri = req.borrow();
+    let host = match uri.host() {
+        Some(host) => host,
+        None => {
+            let headers: &HeaderMap = req.borrow();
+            strip_port(headers.get(HOST)?.to_str().ok()?)
+        }
+    };
+
+    let host = host.trim_end_matches('.');
+    if host.is_empty() {
+        return None;
+    }
+
+    let mut path = String::with_capacity(host.len() + 1);
+    // ip v6 literal is treated as a single label.
+    if host.starts_with('[') {
+        path.push('/');
+        path.push_str(host);
+    } else {
+        for label in host.rsplit('.') {
+            path.push('/');
+            path.extend(label.chars().map(|c| c.to_ascii_lowercase()));
+        }
+    }
+
+    Some(path)
+}
+
+fn strip_port(host: &str) -> &str {
+    match host.strip_prefix('[') {
+        Some(rest) => rest.find(']').map_or(host, |i| &host[..i + 2]),
+        None => host.split_once(':').map_or(host, |(host, _)| host),
+    }
+}
+
+// split host pattern by `.` outside of braced parameter.
+fn labels(pattern: &str) -> impl DoubleEndedIterator<Item = &str> {
+    let mut labels = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in pattern.bytes().enumerate() {
+        match c {
+            b'{' => depth += 1,
+            b'}' => depth -= 1,
+            b'.' if depth == 0 => {
+                labels.push(&pattern[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    labels.push(&pattern[start..]);
+    labels.into_iter()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::Request;
+
+    use super::*;
+
+    #[test]
+    fn pattern() {
+        assert_eq!(pattern_to_path("API.Example.com"), "/com/example/api");
+        assert_eq!(pattern_to_path("{tenant}.example.com."), "/com/example/{tenant}");
+        assert_eq!(pattern_to_path("{id:[a-z.]+}.example.com"), "/com/example/{id:[a-z.]+}");
+        assert_eq!(pattern_to_path("*.tenant.example.com"), "/com/example/tenant/*");
+        assert_eq!(catch_all("*sub.example.com"), Some("sub"));
+        assert_eq!(catch_all("*.example.com"), Some(""));
+        assert_eq!(catch_all("api.example.com"), None);
+    }
+
+    #[test]
+    fn request() {
+        let req = |uri: &str, host: &str| {
+            let mut req = Request::builder().uri(uri);
+            if !host.is_empty() {
+                req = req.header(HOST, host);
+            }
+            req_to_path(&req.body(()).unwrap())
+        };
+
+        assert_eq!(req("/", "Api.Example.com:8080").as_deref(), Some("/com/example/api"));
+        assert_eq!(req("/", "example.com.").as_deref(), Some("/com/example"));
+        assert_eq!(req("/", "[::1]:8080").as_deref(), Some("/[::1]"));
+        assert_eq!(req("/", "127.0.0.1").as_deref(), Some("/1/0/0/127"));
+        assert_eq!(req("/", ""), None);
+        // uri authority takes priority.
+        assert_eq!(
+            req("https://api.example.com/", "foo.com").as_deref(),
+            Some("/com/example/api")
+        );
+    }
+}
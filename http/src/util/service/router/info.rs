@@ -0,0 +1,44 @@
+use crate::http::{Extensions, Method};
+
+/// information of a route registered to [Router](super::Router). See [Router::routes](super::Router::routes).
+#[derive(Clone, Debug)]
+pub struct RouteInfo {
+    pub(super) path: String,
+    pub(super) methods: Vec<Method>,
+    pub(super) name: Option<&'static str>,
+    pub(super) host: Option<&'static str>,
+    pub(super) extensions: Extensions,
+}
+
+impl RouteInfo {
+    /// full path template of route including prefixes of nested routers and path generated by
+    /// [PathGen](super::PathGen).
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// methods allowed by route. empty slice means route is not guarded by method.
+    pub fn methods(&self) -> &[Method] {
+        &self.methods
+    }
+
+    /// name of route registered with [Router::insert_named](super::Router::insert_named).
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// host pattern of route registered with [Router::insert_host](super::Router::insert_host).
+    pub fn host(&self) -> Option<&'static str> {
+        self.host
+    }
+    /// extensions of route for attaching user defined metadata. extensions are moved together with
+    /// the route when it's router is nested in another one.
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// mutable reference of [RouteInfo::extensions].
+    pub fn extensions_mut(&mut self) -> &mut Extensions {
+        &mut self.extensions
+    }
+}
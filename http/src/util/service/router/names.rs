@@ -0,0 +1,307 @@
+use core::fmt;
+
+use std::{collections::HashMap, error, sync::Arc};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+// characters left as is in generated path segment and query. everything except unreserved
+// characters of RFC 3986 are percent-encoded.
+const ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+/// table of named routes and their path templates. used for reverse url generation.
+///
+/// table is populated with [Router::insert_named](super::Router::insert_named) and is available
+/// from [RequestExt::route_names](crate::http::RequestExt::route_names) when a request is routed
+/// by root [Router](super::Router).
+///
+/// # Examples
+/// ```rust
+/// # use xitca_http::util::service::router::{RouteNames, UrlForError};
+/// fn user_url(names: &RouteNames) -> Result<String, UrlForError> {
+///     // route registered with Router::insert_named("user", "/users/:id", ..)
+///     names.url_for("user", [("id", "996")])
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RouteNames(Option<Arc<HashMap<&'static str, String>>>);
+
+impl RouteNames {
+    /// path template of route with given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.as_ref().and_then(|names| names.get(name)).map(String::as_str)
+    }
+
+    /// iterate over all named routes in (name, path template) pairs in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.0
+            .iter()
+            .flat_map(|names| names.iter())
+            .map(|(name, path)| (*name, path.as_str()))
+    }
+
+    /// returns true when no route is named.
+    pub fn is_empty(&self) -> bool {
+        match self.0 {
+            Some(ref names) => names.is_empty(),
+            None => true,
+        }
+    }
+
+    /// generate path of route with given name. parameters of path template are substituted by
+    /// value with the same key from `params`. substituted values are percent-encoded except
+    /// for `/` in catch-all parameters.
+    ///
+    /// # Errors
+    /// When no route is registered with given name or any parameter of it's path template is
+    /// missing from `params`. Extra `params` are ignored.
+    pub fn url_for<I, K, V>(&self, name: &str, params: I) -> Result<String, UrlForError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let path = self
+            .get(name)
+            .ok_or_else(|| UrlForError::UnknownName { name: name.into() })?;
+        let params = params.into_iter().collect::<Vec<_>>();
+
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k.as_ref() == key)
+                .map(|(_, v)| v.as_ref())
+                .ok_or_else(|| UrlForError::MissingParam {
+                    name: name.into(),
+                    param: key.into(),
+                })
+        };
+
+        let mut url = String::with_capacity(path.len());
+        let mut rest = path;
+
+        while let Some(i) = rest.find([':', '{', '*']) {
+            url.push_str(&rest[..i]);
+            rest = &rest[i..];
+
+            match rest.as_bytes()[0] {
+                b':' => {
+                    let len = 1 + rest[1..].bytes().take_while(|c| is_param_name(*c)).count();
+                    url.extend(utf8_percent_encode(get(&rest[1..len])?, ENCODE_SET));
+                    rest = &rest[len..];
+                }
+                b'{' => {
+                    let len = braced_len(rest).unwrap_or(rest.len());
+                    let inner = rest[1..len].trim_end_matches('}');
+                    let key = inner.split_once(':').map_or(inner, |(key, _)| key);
+                    url.extend(utf8_percent_encode(get(key)?, ENCODE_SET));
+                    rest = &rest[len..];
+                }
+                _ => {
+                    // catch-all is always the last part of path. an unnamed one is used by nested
+                    // router and it expands to nothing.
+                    let key = &rest[1..];
+                    if !key.is_empty() {
+                        for (i, segment) in get(key)?.split('/').enumerate() {
+                            if i > 0 {
+                                url.push('/');
+                            }
+                            url.extend(utf8_percent_encode(segment, ENCODE_SET));
+                        }
+                    }
+                    rest = "";
+                }
+            }
+        }
+
+        url.push_str(rest);
+
+        Ok(url)
+    }
+
+    /// generate path of route with given name and append `query` to it. See [RouteNames::url_for]
+    /// for detail. query is percent-encoded and omitted when empty.
+    pub fn url_for_with_query<I, K, V, Q, QK, QV>(&self, name: &str, params: I, query: Q) -> Result<String, UrlForError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        Q: IntoIterator<Item = (QK, QV)>,
+        QK: AsRef<str>,
+        QV: AsRef<str>,
+    {
+        let mut url = self.url_for(name, params)?;
+
+        for (i, (key, value)) in query.into_iter().enumerate() {
+            url.push(if i == 0 { '?' } else { '&' });
+            url.extend(utf8_percent_encode(key.as_ref(), ENCODE_SET));
+            url.push('=');
+            url.extend(utf8_percent_encode(value.as_ref(), ENCODE_SET));
+        }
+
+        Ok(url)
+    }
+
+    pub(super) fn insert(&mut self, name: &'static str, path: String) {
+        let names = Arc::make_mut(self.0.get_or_insert_with(Default::default));
+        assert!(
+            names.insert(name, path).is_none(),
+            "route name {name} is already registered"
+        );
+    }
+
+    pub(super) fn extend(&mut self, other: Self) {
+        if let Some(other) = other.0 {
+            let other = Arc::try_unwrap(other).unwrap_or_else(|other| (*other).clone());
+            for (name, path) in other {
+                self.insert(name, path);
+            }
+        }
+    }
+
+    // prepend prefix to all path templates. used when the router is nested in another one.
+    pub(super) fn prefix(&mut self, prefix: &str) {
+        if let Some(names) = self.0.as_mut() {
+            for path in Arc::make_mut(names).values_mut() {
+                path.insert_str(0, prefix);
+            }
+        }
+    }
+}
+
+fn is_param_name(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+// length of braced parameter including the braces. constraint can contain nested braces.
+fn braced_len(path: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in path.bytes().enumerate() {
+        match c {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// error type of reverse url generation of [RouteNames].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UrlForError {
+    /// no route is registered with given name.
+    UnknownName {
+        /// name of route.
+        name: String,
+    },
+    /// parameter of named route is not provided.
+    MissingParam {
+        /// name of route.
+        name: String,
+        /// name of missing parameter.
+        param: String,
+    },
+}
+
+impl fmt::Display for UrlForError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownName { name } => write!(f, "no route is named {name}"),
+            Self::MissingParam { name, param } => write!(f, "parameter {param} of route {name} is missing"),
+        }
+    }
+}
+
+impl error::Error for UrlForError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn names(routes: &[(&'static str, &str)]) -> RouteNames {
+        let mut names = RouteNames::default();
+        for (name, path) in routes {
+            names.insert(name, path.to_string());
+        }
+        names
+    }
+
+    #[test]
+    fn url_for() {
+        let names = names(&[
+            ("index", "/"),
+            ("user", "/users/:id"),
+            ("post", "/users/{id:u64}/posts/{post}"),
+            ("file", "/file.{ext}"),
+            ("static", "/static/*path"),
+            ("nest", "/api/*"),
+        ]);
+
+        assert_eq!(names.url_for("index", [("id", "1")]).unwrap(), "/");
+        assert_eq!(names.url_for("user", [("id", "1")]).unwrap(), "/users/1");
+        assert_eq!(
+            names.url_for("post", [("post", "hello world"), ("id", "1")]).unwrap(),
+            "/users/1/posts/hello%20world"
+        );
+        assert_eq!(names.url_for("file", [("ext", "tar.gz")]).unwrap(), "/file.tar.gz");
+        assert_eq!(
+            names.url_for("static", [("path", "css/a b.css")]).unwrap(),
+            "/static/css/a%20b.css"
+        );
+        assert_eq!(names.url_for("nest", [("", "")]).unwrap(), "/api/");
+
+        // parameter value can not escape it's segment.
+        assert_eq!(names.url_for("user", [("id", "1/2?a")]).unwrap(), "/users/1%2F2%3Fa");
+    }
+
+    #[test]
+    fn url_for_with_query() {
+        let names = names(&[("user", "/users/:id")]);
+
+        assert_eq!(
+            names
+                .url_for_with_query("user", [("id", "1")], [("page", "2"), ("q", "a&b")])
+                .unwrap(),
+            "/users/1?page=2&q=a%26b"
+        );
+        assert_eq!(
+            names
+                .url_for_with_query("user", [("id", "1")], [("page", "2")].into_iter().take(0))
+                .unwrap(),
+            "/users/1"
+        );
+    }
+
+    #[test]
+    fn url_for_error() {
+        let names = names(&[("user", "/users/:id")]);
+
+        assert_eq!(
+            names.url_for("users", [("id", "1")]).unwrap_err(),
+            UrlForError::UnknownName { name: "users".into() }
+        );
+        assert_eq!(
+            names.url_for("user", [("name", "1")]).unwrap_err(),
+            UrlForError::MissingParam {
+                name: "user".into(),
+                param: "id".into()
+            }
+        );
+        assert!(RouteNames::default().url_for("user", [("id", "1")]).is_err());
+    }
+
+    #[test]
+    fn prefix() {
+        let mut names = names(&[("user", "/users/:id")]);
+        names.prefix("/api");
+        assert_eq!(names.get("user"), Some("/api/users/:id"));
+
+        let mut root = RouteNames::default();
+        root.extend(names);
+        assert_eq!(root.url_for("user", [("id", "1")]).unwrap(), "/api/users/1");
+    }
+}
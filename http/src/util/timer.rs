@@ -62,7 +62,6 @@ impl KeepAlive {
         }
     }
 
-    #[cfg(any(feature = "http1", feature = "http2"))]
     #[inline]
     pub fn update(self: Pin<&mut Self>, deadline: Instant) {
         *self.project().deadline = deadline;
@@ -1,4 +1,15 @@
-use crate::http::Version;
+use core::{
+    cmp,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use std::io;
+
+use xitca_io::io::{AsyncIo, Interest, Ready};
+
+use crate::{bytes::Bytes, http::Version};
 
 /// A helper trait for get a protocol from certain types.
 pub trait AsVersion {
@@ -32,3 +43,119 @@ impl AsVersion for xitca_io::net::TcpStream {
         Version::HTTP_11
     }
 }
+
+// connection preface of http/2 prior knowledge. client must send it as the first bytes of connection.
+// See RFC 9113 section 3.4 for detail.
+pub(crate) const H2_PREFACE: &[u8; 24] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+
+/// read the first bytes from io and figure out it's protocol by matching them against http/2
+/// connection preface. every connection not starting with the preface is treated as http/1.
+///
+/// bytes read from io are not lost and would be replayed by the returned [PeekedIo].
+pub(crate) async fn peek_version<Io>(mut io: Io) -> io::Result<(Version, PeekedIo<Io>)>
+where
+    Io: AsyncIo,
+{
+    let mut buf = [0; H2_PREFACE.len()];
+    let mut len = 0;
+
+    let version = loop {
+        match io::Read::read(&mut io, &mut buf[len..]) {
+            // connection closed early. let http/1 dispatcher handle it.
+            Ok(0) => break Version::HTTP_11,
+            Ok(n) => {
+                len += n;
+                if buf[..len] != H2_PREFACE[..len] {
+                    break Version::HTTP_11;
+                }
+                if len == H2_PREFACE.len() {
+                    break Version::HTTP_2;
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                io.ready(Interest::READABLE).await?;
+            }
+            Err(e) => return Err(e),
+        }
+    };
+
+    Ok((version, PeekedIo::new(Bytes::copy_from_slice(&buf[..len]), io)))
+}
+
+/// io type replaying bytes already read from it by [peek_version] before reading from io again.
+pub(crate) struct PeekedIo<Io> {
+    buf: Bytes,
+    io: Io,
+}
+
+impl<Io> PeekedIo<Io> {
+    pub(crate) fn new(buf: Bytes, io: Io) -> Self {
+        Self { buf, io }
+    }
+}
+
+impl<Io> AsyncIo for PeekedIo<Io>
+where
+    Io: AsyncIo,
+{
+    fn ready(&mut self, interest: Interest) -> impl Future<Output = io::Result<Ready>> + Send {
+        let buffered = !self.buf.is_empty() && interest.is_readable();
+        let ready = self.io.ready(interest);
+        async move {
+            if buffered {
+                Ok(Ready::READABLE)
+            } else {
+                ready.await
+            }
+        }
+    }
+
+    fn poll_ready(&mut self, interest: Interest, cx: &mut Context<'_>) -> Poll<io::Result<Ready>> {
+        if !self.buf.is_empty() && interest.is_readable() {
+            return Poll::Ready(Ok(Ready::READABLE));
+        }
+        self.io.poll_ready(interest, cx)
+    }
+
+    fn is_vectored_write(&self) -> bool {
+        self.io.is_vectored_write()
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().io).poll_shutdown(cx)
+    }
+}
+
+impl<Io> io::Read for PeekedIo<Io>
+where
+    Io: AsyncIo,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.buf.is_empty() {
+            return self.io.read(buf);
+        }
+        let len = cmp::min(self.buf.len(), buf.len());
+        buf[..len].copy_from_slice(&self.buf.split_to(len));
+        Ok(len)
+    }
+}
+
+impl<Io> io::Write for PeekedIo<Io>
+where
+    Io: AsyncIo,
+{
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.io.write(buf)
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+        self.io.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.io.flush()
+    }
+}
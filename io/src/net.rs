@@ -3,12 +3,14 @@
 #[cfg(feature = "runtime-uring")]
 pub mod io_uring;
 
+mod in_flight;
 #[cfg(feature = "quic")]
 mod quic;
 mod tcp;
 #[cfg(unix)]
 mod unix;
 
+pub use in_flight::{InFlight, InFlightGuard};
 #[cfg(feature = "quic")]
 pub use quic::*;
 #[cfg(not(target_family = "wasm"))]
@@ -0,0 +1,62 @@
+use core::{
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use std::sync::Arc;
+
+tokio::task_local! {
+    static IN_FLIGHT: InFlight;
+}
+
+/// Counter of in-flight requests of the connection served by current task.
+///
+/// A connection server(for example xitca-server) counts connections and has no knowledge of requests
+/// of upper level protocol. It can run the service call of connection in [InFlight::scope] and protocol
+/// implementation(for example xitca-http) calls [InFlight::guard] for every request it dispatches.
+#[derive(Clone, Debug)]
+pub struct InFlight {
+    counters: Arc<[Arc<AtomicUsize>]>,
+}
+
+impl InFlight {
+    /// construct a new in-flight counter increasing and decreasing all given counters together.
+    pub fn new(counters: impl IntoIterator<Item = Arc<AtomicUsize>>) -> Self {
+        Self {
+            counters: counters.into_iter().collect(),
+        }
+    }
+
+    /// run given future with self as the in-flight counter of current task.
+    pub fn scope<F>(self, fut: F) -> impl Future<Output = F::Output>
+    where
+        F: Future,
+    {
+        IN_FLIGHT.scope(self, fut)
+    }
+
+    /// count one in-flight request of current task until the returned guard is dropped.
+    ///
+    /// `None` is returned when current task is not running in [InFlight::scope].
+    pub fn guard() -> Option<InFlightGuard> {
+        IN_FLIGHT
+            .try_with(|in_flight| {
+                for counter in in_flight.counters.iter() {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+                InFlightGuard(in_flight.clone())
+            })
+            .ok()
+    }
+}
+
+/// Guard of one in-flight request. See [InFlight::guard].
+pub struct InFlightGuard(InFlight);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        for counter in self.0.counters.iter() {
+            counter.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
@@ -30,6 +30,12 @@ impl TcpStream {
     pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
         self.0.set_nodelay(nodelay)
     }
+
+    /// receive data from socket without removing it from the queue. wait for socket to be readable
+    /// when there is no data available.
+    pub async fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf).await
+    }
 }
 
 impl From<tokio::net::TcpStream> for TcpStream {
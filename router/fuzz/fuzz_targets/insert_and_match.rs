@@ -1,21 +1,49 @@
 #![no_main]
 use libfuzzer_sys::fuzz_target;
+use xitca_router::Router;
 
-fuzz_target!(|data: (Vec<(String, i32)>, String, Option<bool>)| {
-    let mut matcher = matchit::Node::new();
+fuzz_target!(|data: (Vec<(String, i32)>, Vec<usize>, String)| {
+    let (routes, removes, path) = data;
 
-    for (key, item) in data.0 {
-        if matcher.insert(key, item).is_err() {
+    let mut router = Router::new();
+
+    for (route, item) in routes.iter() {
+        if router.insert(route.as_str(), *item).is_err() {
             return;
         }
     }
 
-    match data.2 {
-        None => {
-            let _ = matcher.at(&data.1);
+    let _ = router.at(&path);
+
+    // remove routes and compare against a router with only the remaining routes inserted.
+    let mut removed = vec![false; routes.len()];
+    for i in removes {
+        let Some(i) = i.checked_rem(routes.len()) else {
+            return;
+        };
+        let res = router.remove(routes[i].0.as_str());
+        assert_eq!(res.is_some(), !removed[i]);
+        removed[i] = true;
+        router.check_priorities().unwrap();
+    }
+
+    let mut expected = Router::new();
+    for ((route, item), removed) in routes.iter().zip(removed) {
+        if !removed {
+            expected.insert(route.as_str(), *item).unwrap();
         }
-        Some(b) => {
-            let _ = matcher.path_ignore_case(&data.1, b);
+    }
+
+    match (router.at(&path), expected.at(&path)) {
+        (Ok(a), Ok(b)) => {
+            assert_eq!(a.value, b.value);
+            assert!(a.params.iter().eq(b.params.iter()));
         }
+        (Err(_), Err(_)) => {}
+        _ => panic!("match mismatch for path {path:?}"),
+    }
+
+    if let Ok(matched) = router.at_mut(&path) {
+        *matched.value += 1;
     }
 });
@@ -0,0 +1,138 @@
+use core::fmt;
+
+use super::InsertError;
+
+/// Constraint of route parameter checked against parameter value at match time.
+#[derive(Clone)]
+pub(crate) struct Constraint {
+    src: Box<str>,
+    kind: Kind,
+}
+
+#[derive(Clone)]
+enum Kind {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl Constraint {
+    pub(crate) fn new(src: &str) -> Result<Self, InsertError> {
+        let kind = match src {
+            "u8" => Kind::U8,
+            "u16" => Kind::U16,
+            "u32" => Kind::U32,
+            "u64" => Kind::U64,
+            "u128" => Kind::U128,
+            "usize" => Kind::Usize,
+            "i8" => Kind::I8,
+            "i16" => Kind::I16,
+            "i32" => Kind::I32,
+            "i64" => Kind::I64,
+            "i128" => Kind::I128,
+            "isize" => Kind::Isize,
+            #[cfg(feature = "regex")]
+            _ => {
+                // regex must match the whole parameter value.
+                let regex =
+                    regex::Regex::new(&format!("^(?:{src})$")).map_err(|_| InsertError::invalid_constraint(src))?;
+                Kind::Regex(regex)
+            }
+            #[cfg(not(feature = "regex"))]
+            _ => return Err(InsertError::invalid_constraint(src)),
+        };
+
+        Ok(Self { src: src.into(), kind })
+    }
+
+    pub(crate) fn is_match(&self, value: &str) -> bool {
+        match self.kind {
+            Kind::U8 => value.parse::<u8>().is_ok(),
+            Kind::U16 => value.parse::<u16>().is_ok(),
+            Kind::U32 => value.parse::<u32>().is_ok(),
+            Kind::U64 => value.parse::<u64>().is_ok(),
+            Kind::U128 => value.parse::<u128>().is_ok(),
+            Kind::Usize => value.parse::<usize>().is_ok(),
+            Kind::I8 => value.parse::<i8>().is_ok(),
+            Kind::I16 => value.parse::<i16>().is_ok(),
+            Kind::I32 => value.parse::<i32>().is_ok(),
+            Kind::I64 => value.parse::<i64>().is_ok(),
+            Kind::I128 => value.parse::<i128>().is_ok(),
+            Kind::Isize => value.parse::<isize>().is_ok(),
+            #[cfg(feature = "regex")]
+            Kind::Regex(ref regex) => regex.is_match(value),
+        }
+    }
+}
+
+// constraints are compared by their source.
+impl PartialEq for Constraint {
+    fn eq(&self, other: &Self) -> bool {
+        self.src == other.src
+    }
+}
+
+impl fmt::Debug for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn integer() {
+        let c = Constraint::new("u8").unwrap();
+        assert!(c.is_match("255"));
+        assert!(!c.is_match("256"));
+        assert!(!c.is_match("-1"));
+        assert!(!c.is_match("a"));
+
+        let c = Constraint::new("i64").unwrap();
+        assert!(c.is_match("-1"));
+        assert!(!c.is_match("1.0"));
+
+        assert_eq!(Constraint::new("u64").unwrap(), Constraint::new("u64").unwrap());
+        assert_ne!(Constraint::new("u64").unwrap(), Constraint::new("u32").unwrap());
+    }
+
+    #[cfg(not(feature = "regex"))]
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            Constraint::new("[0-9]+").unwrap_err(),
+            InsertError::InvalidConstraint {
+                constraint: "[0-9]+".into()
+            }
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex() {
+        let c = Constraint::new("[a-z]+").unwrap();
+        assert!(c.is_match("abc"));
+        // regex is anchored to whole value.
+        assert!(!c.is_match("abc1"));
+        assert!(!c.is_match("1abc"));
+
+        let c = Constraint::new("a|b").unwrap();
+        assert!(c.is_match("a"));
+        assert!(!c.is_match("ab"));
+
+        assert!(Constraint::new("(").is_err());
+    }
+}
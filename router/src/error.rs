@@ -13,12 +13,23 @@ pub enum InsertError {
     },
     /// Route path is not in utf-8 format.
     Parse(Utf8Error),
-    /// Only one parameter per route segment is allowed.
+    /// Parameters must be separated by static characters. e.g. `/:foo:bar` or `/:foo*bar`.
     TooManyParams,
     /// Parameters must be registered with a name.
     UnnamedParam,
+    /// Parameter names must consist of ASCII alphanumeric characters and `_`. Parameters in the form
+    /// of `:name` must be followed by `/`, `.` or the end of route. e.g. `/:file-name` or `/{file-name}`.
+    InvalidParamName {
+        /// The parameter failed to parse.
+        name: String,
+    },
     /// Catch-all parameters are only allowed at the end of a path.
     InvalidCatchAll,
+    /// Parameter constraint is unknown or malformed.
+    InvalidConstraint {
+        /// The constraint failed to parse.
+        constraint: String,
+    },
 }
 
 impl fmt::Display for InsertError {
@@ -31,9 +42,11 @@ impl fmt::Display for InsertError {
                 )
             }
             Self::Parse(ref e) => fmt::Display::fmt(e, f),
-            Self::TooManyParams => f.write_str("only one parameter is allowed per path segment"),
+            Self::TooManyParams => f.write_str("parameters must be separated by static characters"),
             Self::UnnamedParam => f.write_str("parameters must be registered with a name"),
+            Self::InvalidParamName { name } => write!(f, "invalid parameter name: {name}"),
             Self::InvalidCatchAll => f.write_str("catch-all parameters are only allowed at the end of a route"),
+            Self::InvalidConstraint { constraint } => write!(f, "invalid parameter constraint: {constraint}"),
         }
     }
 }
@@ -47,6 +60,18 @@ impl From<Utf8Error> for InsertError {
 }
 
 impl InsertError {
+    pub(crate) fn invalid_param_name(name: &[u8]) -> Self {
+        Self::InvalidParamName {
+            name: String::from_utf8_lossy(name).into_owned(),
+        }
+    }
+
+    pub(crate) fn invalid_constraint(constraint: &str) -> Self {
+        Self::InvalidConstraint {
+            constraint: constraint.into(),
+        }
+    }
+
     pub(crate) fn conflict<T>(route: &[u8], prefix: &[u8], current: &Node<T>) -> Self {
         let mut route = route[..route.len() - prefix.len()].to_owned();
 
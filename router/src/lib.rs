@@ -41,6 +41,70 @@
 //! # }
 //! ```
 //!
+//! Parameter names consist of ASCII alphanumeric characters and `_`. A parameter can share a segment
+//! with static prefix and a static suffix starting with `.`. Route with any other character following
+//! the name of a parameter is rejected with [InsertError::InvalidParamName] so `/:file-name` is never
+//! mistaken for parameter `file` with suffix `-name`. Braced parameters(see below) accept any static
+//! suffix:
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut m = xitca_router::Router::new();
+//! m.insert("/files/:name.:ext", true)?;
+//! m.insert("/avatars/:id.png", true)?;
+//! m.insert("/v:version/users", true)?;
+//!
+//! let matched = m.at("/files/archive.tar.gz")?;
+//! assert_eq!(matched.params.get("name"), Some("archive.tar"));
+//! assert_eq!(matched.params.get("ext"), Some("gz"));
+//!
+//! assert_eq!(m.at("/avatars/1.png")?.params.get("id"), Some("1"));
+//! assert!(m.at("/avatars/1.jpg").is_err());
+//! assert_eq!(m.at("/v2/users")?.params.get("version"), Some("2"));
+//!
+//! assert!(m.insert("/:file-name", true).is_err());
+//! m.insert("/range/{from}-{to}", true)?;
+//! assert_eq!(m.at("/range/1-10")?.params.get("to"), Some("10"));
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Parameters never match empty value and two parameters must be separated by static characters.
+//!
+//! ### Parameter Constraints
+//!
+//! Named parameters can be written as `{name:constraint}`. A path only matches the route when the
+//! parameter value satisfies the constraint, otherwise other routes are tried. Built in constraints
+//! are integer types: `u8`, `u16`, `u32`, `u64`, `u128`, `usize`, `i8`, `i16`, `i32`, `i64`,
+//! `i128` and `isize`. With `regex` feature enabled any other constraint is a regular expression
+//! that must match the whole parameter value.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut m = xitca_router::Router::new();
+//! m.insert("/users/{id:u64}", "id")?;
+//! m.insert("/users/:name", "name")?;
+//!
+//! let matched = m.at("/users/978")?;
+//! assert_eq!(*matched.value, "id");
+//! assert_eq!(matched.params.get("id"), Some("978"));
+//!
+//! let matched = m.at("/users/alice")?;
+//! assert_eq!(*matched.value, "name");
+//! assert_eq!(matched.params.get("name"), Some("alice"));
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Parameters with different constraints can share the same position of route. They are tried in
+//! the order of insertion and parameter without constraint is always tried last. `{name}` without
+//! constraint is the same as `:name`.
+//!
+//! `{` always begins a braced parameter and can not be used as static character of route. Use it's
+//! percent-encoded form `%7B` for matching request path containing encoded `{`.
+//!
 //! ### Catch-all Parameters
 //!
 //! Catch-all parameters start with `*` and match everything after the `/`.
@@ -90,9 +154,31 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! Parameters with static suffix in the same segment are tried before parameter taking the whole
+//! segment. When multiple values of a parameter can satisfy it's static suffix the longest one is
+//! tried first:
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut m = xitca_router::Router::new();
+//! m.insert("/:name.:ext", "file")?;
+//! m.insert("/:name.png", "image")?;
+//! m.insert("/:name", "anything")?;
+//! m.insert("/index.html", "index")?;
+//!
+//! assert_eq!(*m.at("/index.html")?.value, "index");
+//! assert_eq!(*m.at("/a.png")?.value, "image");
+//! assert_eq!(m.at("/a.b.jpg")?.params.get("name"), Some("a.b"));
+//! assert_eq!(*m.at("/a")?.value, "anything");
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 #![forbid(unsafe_code)]
 
+mod constraint;
 mod error;
 mod router;
 mod tree;
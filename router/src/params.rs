@@ -105,6 +105,19 @@ impl Params {
     }
 }
 
+impl<K, V> Extend<(K, V)> for Params
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        iter.into_iter().for_each(|(k, v)| self.push(k.as_ref(), v.as_ref()));
+    }
+}
+
 impl IntoIterator for Params {
     type Item = (SmallBoxedStr, SmallBoxedStr);
     type IntoIter = IntoIter;
@@ -167,4 +180,14 @@ mod tests {
         let params = Params::new();
         assert!(params.get("").is_none());
     }
+
+    #[test]
+    fn extend() {
+        let mut params = Params::new();
+        params.push("a", "1");
+        params.extend([("b", "2"), ("a", "3")]);
+        assert_eq!(params.len(), 3);
+        assert_eq!(params.get("a"), Some("1"));
+        assert_eq!(params.get("b"), Some("2"));
+    }
 }
@@ -52,6 +52,77 @@ impl<T> Router<T> {
         self.root.at(path).map(|(value, params)| Match { value, params })
     }
 
+    /// Tries to find a value in the router matching the given path, returning a mutable reference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use xitca_router::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut router = Router::new();
+    /// router.insert("/users/:id", 0)?;
+    ///
+    /// *router.at_mut("/users/1")?.value += 1;
+    /// assert_eq!(*router.at("/users/2")?.value, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn at_mut(&mut self, path: &str) -> Result<Match<&mut T>, MatchError> {
+        self.root.at_mut(path).map(|(value, params)| Match { value, params })
+    }
+
+    /// Get a mutable reference to the value of an inserted route.
+    ///
+    /// Unlike [`Router::at_mut`] the route is matched exactly as it's inserted, including the
+    /// name of it's parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use xitca_router::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut router = Router::new();
+    /// router.insert("/users/:id", "A User")?;
+    ///
+    /// assert!(router.get_mut("/users/:name").is_none());
+    /// *router.get_mut("/users/:id").unwrap() = "Another User";
+    /// assert_eq!(*router.at("/users/1")?.value, "Another User");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_mut(&mut self, route: impl Into<String>) -> Option<&mut T> {
+        self.root.get_mut(route)
+    }
+
+    /// Remove a route and return it's value. `None` is returned when the route is not found.
+    ///
+    /// The route is matched exactly as it's inserted, including the name of it's parameters.
+    /// Removing a route frees up it's path for inserting a new route that would otherwise
+    /// conflict with it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use xitca_router::Router;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut router = Router::new();
+    /// router.insert("/home", "Welcome!")?;
+    /// router.insert("/users/:id", "A User")?;
+    ///
+    /// assert_eq!(router.remove("/users/:name"), None);
+    /// assert_eq!(router.remove("/users/:id"), Some("A User"));
+    /// assert!(router.at("/users/1").is_err());
+    ///
+    /// router.insert("/users/:name", "Another User")?;
+    /// assert_eq!(router.at("/users/1")?.params.get("name"), Some("1"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove(&mut self, route: impl Into<String>) -> Option<T> {
+        self.root.remove(route)
+    }
+
     #[cfg(feature = "__test_helpers")]
     pub fn check_priorities(&self) -> Result<u32, (u32, u32)> {
         self.root.check_priorities()
@@ -59,7 +130,7 @@ impl<T> Router<T> {
 }
 
 /// A successful match consisting of the registered value
-/// and URL parameters, returned by [`Router::at`](Router::at).
+/// and URL parameters, returned by [`Router::at`](Router::at) and [`Router::at_mut`](Router::at_mut).
 #[derive(Debug)]
 pub struct Match<V> {
     /// The value stored under the matched node.
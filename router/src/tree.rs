@@ -1,6 +1,6 @@
 use core::{cmp::min, mem, str::from_utf8};
 
-use super::{params::Params, InsertError, MatchError};
+use super::{constraint::Constraint, params::Params, InsertError, MatchError};
 
 /// The types of nodes the tree can hold
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
@@ -24,6 +24,7 @@ pub struct Node<T> {
     wild_child: bool,
     indices: Vec<u8>,
     value: Option<T>,
+    constraint: Option<Constraint>,
     pub(crate) param_remapping: ParamRemapping,
     pub(crate) node_type: NodeType,
     pub(crate) prefix: String,
@@ -43,6 +44,7 @@ impl<T> Node<T> {
             wild_child: false,
             indices: Vec::new(),
             value: None,
+            constraint: None,
             param_remapping: ParamRemapping::new(),
             node_type: NodeType::Static,
             prefix: String::new(),
@@ -52,14 +54,14 @@ impl<T> Node<T> {
 
     pub fn insert(&mut self, route: impl Into<String>, val: T) -> Result<(), InsertError> {
         let route = route.into().into_bytes();
-        let (route, param_remapping) = normalize_params(route)?;
+        let (route, param_remapping, constraints) = normalize_params(route)?;
         let mut prefix = route.as_ref();
 
         self.priority += 1;
 
         // the tree is empty
         if self.prefix.is_empty() && self.children.is_empty() {
-            let last = self.insert_child(prefix, val)?;
+            let last = self.insert_child(prefix, &constraints, val)?;
             last.param_remapping = param_remapping;
             self.node_type = NodeType::Root;
             return Ok(());
@@ -100,14 +102,6 @@ impl<T> Node<T> {
 
                 let next = prefix[0];
 
-                // `/` after param
-                if current.node_type == NodeType::Param && next == b'/' && current.children.len() == 1 {
-                    current = &mut current.children[0];
-                    current.priority += 1;
-
-                    continue 'walk;
-                }
-
                 // find a child that matches the next path byte
                 for mut i in 0..current.indices.len() {
                     // found a match
@@ -125,34 +119,32 @@ impl<T> Node<T> {
                     child = current.update_child_priority(child);
 
                     // insert into the new node
-                    let last = current.children[child].insert_child(prefix, val)?;
+                    let last = current.children[child].insert_child(prefix, &constraints, val)?;
                     last.param_remapping = param_remapping;
                     return Ok(());
                 }
 
-                // inserting a wildcard, and this node already has a wildcard child
+                // inserting a wildcard, and this node already has wildcard children
                 if current.wild_child {
                     // wildcards are always at the end
-                    current = current.children.last_mut().unwrap();
-                    current.priority += 1;
+                    let wild = current.indices.len();
 
                     // make sure the wildcard matches
-                    if prefix.len() < current.prefix.len()
-                        || current.prefix.as_bytes() != &prefix[..current.prefix.len()]
-                        // catch-alls cannot have children 
-                        || current.node_type == NodeType::CatchAll
-                        // check for longer wildcard, e.g. :name and :names
-                        || (current.prefix.len() < prefix.len()
-                            && prefix[current.prefix.len()] != b'/')
-                    {
-                        return Err(InsertError::conflict(&route, prefix, current));
+                    match current.wild_child_of(prefix, &constraints) {
+                        Some(i) => {
+                            current = &mut current.children[i];
+                            current.priority += 1;
+                            continue 'walk;
+                        }
+                        // parameters with different constraint can share the same position
+                        None if next == b':' && current.children[wild].node_type == NodeType::Param => {}
+                        // catch-alls cannot have siblings
+                        None => return Err(InsertError::conflict(&route, prefix, &current.children[wild])),
                     }
-
-                    continue 'walk;
                 }
 
                 // otherwise, create the wildcard node
-                let last = current.insert_child(prefix, val)?;
+                let last = current.insert_child(prefix, &constraints, val)?;
                 last.param_remapping = param_remapping;
                 return Ok(());
             }
@@ -170,17 +162,38 @@ impl<T> Node<T> {
         }
     }
 
-    // add a child node, keeping wildcards at the end
+    // add a child node, keeping wildcards at the end and parameters with constraint before the
+    // one without
     fn add_child(&mut self, child: Node<T>) -> usize {
-        let len = self.children.len();
-
-        if self.wild_child && len > 0 {
-            self.children.insert(len - 1, child);
-            len - 1
-        } else {
-            self.children.push(child);
-            len
+        let is_wild = |node: &Node<T>| matches!(node.node_type, NodeType::Param | NodeType::CatchAll);
+
+        let i = match child.node_type {
+            NodeType::Param if child.constraint.is_some() => self
+                .children
+                .iter()
+                .position(|node| node.node_type == NodeType::Param && node.constraint.is_none()),
+            NodeType::Param | NodeType::CatchAll => None,
+            _ => self.children.iter().position(is_wild),
         }
+        .unwrap_or(self.children.len());
+
+        self.children.insert(i, child);
+        i
+    }
+
+    // index of wildcard child the next wildcard of a normalized route belongs to.
+    fn wild_child_of(&self, route: &[u8], constraints: &Constraints) -> Option<usize> {
+        let constraint = match route {
+            [b':', name, ..] => constraints[(name - b'a') as usize].as_ref(),
+            _ => None,
+        };
+
+        self.children
+            .iter()
+            .enumerate()
+            .skip(self.indices.len())
+            .find(|(_, child)| route.starts_with(child.prefix.as_bytes()) && child.constraint.as_ref() == constraint)
+            .map(|(i, _)| i)
     }
 
     // increments priority of the given child and reorders if necessary.
@@ -207,12 +220,17 @@ impl<T> Node<T> {
     }
 
     // insert a child node at this node
-    fn insert_child(&mut self, mut prefix: &[u8], val: T) -> Result<&mut Node<T>, InsertError> {
+    fn insert_child(
+        &mut self,
+        mut prefix: &[u8],
+        constraints: &Constraints,
+        val: T,
+    ) -> Result<&mut Node<T>, InsertError> {
         let mut current = self;
 
         loop {
             // search for a wildcard segment
-            let (wildcard, wildcard_index) = match find_wildcard(prefix)? {
+            let (wildcard, wildcard_index) = match find_wildcard(prefix) {
                 Some((w, i)) => (w, i),
                 // no wildcard, simply use the current node
                 None => {
@@ -233,6 +251,7 @@ impl<T> Node<T> {
                 let child = Self {
                     node_type: NodeType::Param,
                     prefix: from_utf8(wildcard)?.into(),
+                    constraint: constraints[(wildcard[1] - b'a') as usize].clone(),
                     ..Self::default()
                 };
 
@@ -241,8 +260,8 @@ impl<T> Node<T> {
                 current = &mut current.children[child];
                 current.priority += 1;
 
-                // if the route doesn't end with the wildcard, then there
-                // will be another non-wildcard subroute starting with '/'
+                // if the route doesn't end with the wildcard, then there will be another
+                // non-wildcard subroute starting with '/' or static suffix of the parameter
                 if wildcard.len() < prefix.len() {
                     prefix = &prefix[wildcard.len()..];
                     let child = Self {
@@ -250,6 +269,7 @@ impl<T> Node<T> {
                         ..Self::default()
                     };
 
+                    current.indices.push(prefix[0]);
                     let child = current.add_child(child);
                     current = &mut current.children[child];
                     continue;
@@ -294,17 +314,148 @@ impl<T> Node<T> {
             }
         }
     }
+
+    pub fn remove(&mut self, route: impl Into<String>) -> Option<T> {
+        let route = route.into().into_bytes();
+        let (route, param_remapping, constraints) = normalize_params(route).ok()?;
+
+        let val = self.remove_inner(&route, &param_remapping, &constraints)?;
+
+        if self.value.is_none() {
+            if self.children.is_empty() {
+                // the tree is empty
+                *self = Self::new();
+            } else {
+                self.merge_child();
+            }
+        }
+
+        Some(val)
+    }
+
+    // remove value of route from node or it's descendants. priorities of all nodes along the path
+    // are decremented and nodes left without value are pruned or merged.
+    fn remove_inner(&mut self, route: &[u8], param_remapping: &ParamRemapping, constraints: &Constraints) -> Option<T> {
+        let rest = route.strip_prefix(self.prefix.as_bytes())?;
+
+        let val = match rest.first() {
+            // exact match, param names must match the inserted route
+            None => {
+                if self.param_remapping != *param_remapping {
+                    return None;
+                }
+                let val = self.value.take()?;
+                self.param_remapping = ParamRemapping::new();
+                val
+            }
+            Some(_) => {
+                let i = self.route_child(rest, constraints)?;
+                let val = self.children[i].remove_inner(rest, param_remapping, constraints)?;
+                self.clean_child(i);
+                val
+            }
+        };
+
+        self.priority -= 1;
+
+        Some(val)
+    }
+
+    pub fn get_mut(&mut self, route: impl Into<String>) -> Option<&mut T> {
+        let route = route.into().into_bytes();
+        let (route, param_remapping, constraints) = normalize_params(route).ok()?;
+        let mut route = route.as_slice();
+
+        let mut current = self;
+
+        loop {
+            route = route.strip_prefix(current.prefix.as_bytes())?;
+
+            match route.first() {
+                None => {
+                    if current.param_remapping != param_remapping {
+                        return None;
+                    }
+                    return current.value.as_mut();
+                }
+                Some(_) => {
+                    let i = current.route_child(route, &constraints)?;
+                    current = &mut current.children[i];
+                }
+            }
+        }
+    }
+
+    // index of child node the rest of a normalized route belongs to.
+    fn route_child(&self, route: &[u8], constraints: &Constraints) -> Option<usize> {
+        match route[0] {
+            b':' | b'*' => self.wild_child_of(route, constraints),
+            next => self.indices.iter().position(|&c| c == next),
+        }
+    }
+
+    // prune the given child when it's left empty after removal. otherwise merge it with it's only
+    // child and reorder it by it's decremented priority.
+    fn clean_child(&mut self, i: usize) {
+        let child = &mut self.children[i];
+
+        if child.value.is_none() && child.children.is_empty() {
+            let child = self.children.remove(i);
+            match child.node_type {
+                NodeType::Param | NodeType::CatchAll => self.wild_child = self.children.len() > self.indices.len(),
+                _ => {
+                    self.indices.remove(i);
+                }
+            }
+            return;
+        }
+
+        if child.node_type == NodeType::Static {
+            child.merge_child();
+        }
+
+        // static children are ordered by priority, wildcard child stays at the end
+        if i < self.indices.len() {
+            let priority = self.children[i].priority;
+
+            let mut updated = i;
+            while updated + 1 < self.indices.len() && self.children[updated + 1].priority > priority {
+                self.children.swap(updated, updated + 1);
+                updated += 1;
+            }
+
+            if updated != i {
+                self.indices[i..=updated].rotate_left(1);
+            }
+        }
+    }
+
+    // merge node without value with it's only static child.
+    fn merge_child(&mut self) {
+        if self.value.is_some() || self.wild_child || self.children.len() != 1 {
+            return;
+        }
+
+        let child = self.children.pop().unwrap();
+        self.prefix.push_str(&child.prefix);
+        self.children = child.children;
+        self.indices = child.indices;
+        self.wild_child = child.wild_child;
+        self.value = child.value;
+        self.param_remapping = child.param_remapping;
+    }
 }
 
 struct Skipped<'n, 'p, T> {
     path: &'p str,
     node: &'n Node<T>,
     params: usize,
+    trail: usize,
 }
 
 #[rustfmt::skip]
 macro_rules! backtracker {
-    ($skipped_nodes:ident, $path:ident, $current:ident, $params:ident, $backtracking:ident, $walk:lifetime) => {
+    ($skipped_nodes:ident, $path:ident, $current:ident, $params:ident, $trail:ident, $backtracking:ident, $walk:lifetime) => {
         macro_rules! try_backtrack {
             () => {
                 // try backtracking to any matching wildcard nodes we skipped while traversing
@@ -314,6 +465,7 @@ macro_rules! backtracker {
                         $path = skipped.path;
                         $current = &skipped.node;
                         $params.truncate(skipped.params);
+                        $trail.truncate(skipped.trail);
                         $backtracking = true;
                         continue $walk;
                     }
@@ -324,18 +476,49 @@ macro_rules! backtracker {
 }
 
 impl<T> Node<T> {
-    // it's a bit sad that we have to introduce unsafe here but rust doesn't really have a way
-    // to abstract over mutability, so `UnsafeCell` lets us avoid having to duplicate logic between
-    // `at` and `at_mut`
-    pub fn at(&self, full_path: &str) -> Result<(&T, Params), MatchError> {
+    #[inline]
+    pub fn at(&self, path: &str) -> Result<(&T, Params), MatchError> {
+        self.find::<false>(path).map(|(value, params, _)| (value, params))
+    }
+
+    pub fn at_mut(&mut self, path: &str) -> Result<(&mut T, Params), MatchError> {
+        let (_, params, trail) = self.find::<true>(path)?;
+
+        // rust doesn't really have a way to abstract over mutability. walk the tree again with
+        // child indices recorded by immutable match.
+        let mut current = self;
+        for i in trail {
+            current = &mut current.children[i];
+        }
+
+        current.value.as_mut().map(|value| (value, params)).ok_or(MatchError)
+    }
+
+    // when TRAIL is true the indices of children walked through to the matched node are recorded.
+    fn find<const TRAIL: bool>(&self, path: &str) -> Result<(&T, Params, Vec<usize>), MatchError> {
+        let mut params = Params::new();
+        let mut trail = Vec::new();
+        self.walk::<TRAIL>(path, &mut params, &mut trail)
+            .map(|value| (value, params, trail))
+            .ok_or(MatchError)
+    }
+
+    fn walk<const TRAIL: bool>(&self, full_path: &str, params: &mut Params, trail: &mut Vec<usize>) -> Option<&T> {
         let mut current = self;
         let mut path = full_path;
         let mut backtracking = false;
-        let mut params = Params::new();
         let mut skipped_nodes = Vec::new();
 
+        macro_rules! trail {
+            ($i: expr) => {
+                if TRAIL {
+                    trail.push($i);
+                }
+            };
+        }
+
         'walk: loop {
-            backtracker!(skipped_nodes, path, current, params, backtracking, 'walk);
+            backtracker!(skipped_nodes, path, current, params, trail, backtracking, 'walk);
 
             // the path is longer than this node's prefix, we are expecting a child node
             if path.len() > current.prefix.len() {
@@ -358,10 +541,12 @@ impl<T> Node<T> {
                                     path: consumed,
                                     node: current,
                                     params: params.len(),
+                                    trail: trail.len(),
                                 });
                             }
 
                             // continue with the child node
+                            trail!(i);
                             current = &current.children[i];
                             continue 'walk;
                         }
@@ -375,23 +560,60 @@ impl<T> Node<T> {
                         break;
                     }
 
-                    // handle the wildcard child, which is always at the end of the list
-                    current = current.children.last().unwrap();
+                    // handle the wildcard children, which are always at the end of the list. parameters
+                    // with constraint are tried before the last one.
+                    let last = current.children.len() - 1;
+                    for (i, child) in current
+                        .children
+                        .iter()
+                        .enumerate()
+                        .take(last)
+                        .skip(current.indices.len())
+                    {
+                        let (params_len, trail_len) = (params.len(), trail.len());
+
+                        trail!(i);
+                        if let Some(value) = child.walk_param::<TRAIL>(path, params, trail) {
+                            return Some(value);
+                        }
+
+                        params.truncate(params_len);
+                        trail.truncate(trail_len);
+                    }
+
+                    trail!(last);
+                    current = &current.children[last];
 
                     match current.node_type {
                         NodeType::Param => {
-                            // check if there are more segments in the path other than this parameter
-                            match path.chars().position(|c| c == '/') {
-                                Some(i) => {
-                                    let (param, rest) = path.split_at(i);
+                            let end = path.find('/').unwrap_or(path.len());
+
+                            // parameter can not be empty
+                            if end == 0 {
+                                try_backtrack!();
+                                break;
+                            }
+
+                            if let Some(value) = current.walk_suffix::<TRAIL>(path, end, params, trail) {
+                                return Some(value);
+                            }
 
-                                    if let [child] = current.children.as_slice() {
+                            if !current.is_match(&path[..end]) {
+                                try_backtrack!();
+                                break;
+                            }
+
+                            // check if there are more segments in the path other than this parameter
+                            match (end < path.len()).then(|| &path[end..]) {
+                                Some(rest) => {
+                                    if let Some(child) = current.indices.iter().position(|&c| c == b'/') {
                                         // store the parameter value
-                                        params.push(&current.prefix[1..], param);
+                                        params.push(&current.prefix[1..], &path[..end]);
 
                                         // continue with the child node
                                         path = rest;
-                                        current = child;
+                                        trail!(child);
+                                        current = &current.children[child];
                                         backtracking = false;
                                         continue 'walk;
                                     }
@@ -405,11 +627,8 @@ impl<T> Node<T> {
 
                                     // found the matching value
                                     if let Some(ref value) = current.value {
-                                        // remap parameter keys
-                                        params
-                                            .for_each_key_mut(|(i, key)| *key = current.param_remapping[i][1..].into());
-
-                                        return Ok((value, params));
+                                        current.remap_params(params);
+                                        return Some(value);
                                     }
 
                                     try_backtrack!();
@@ -422,13 +641,12 @@ impl<T> Node<T> {
                             // catch all segments are only allowed at the end of the route,
                             // either this node has the value or there is no match
                             if let Some(ref value) = current.value {
-                                // remap parameter keys
-                                params.for_each_key_mut(|(i, key)| *key = current.param_remapping[i][1..].into());
+                                current.remap_params(params);
 
                                 // store the final catch-all parameter
                                 params.push(&current.prefix[1..], path);
 
-                                return Ok((value, params));
+                                return Some(value);
                             }
                         }
                         _ => unreachable!(),
@@ -441,9 +659,8 @@ impl<T> Node<T> {
             // this is it, we should have reached the node containing the value
             if current.prefix == path {
                 if let Some(ref value) = current.value {
-                    // remap parameter keys
-                    params.for_each_key_mut(|(i, key)| *key = current.param_remapping[i][1..].into());
-                    return Ok((value, params));
+                    current.remap_params(params);
+                    return Some(value);
                 }
             }
 
@@ -457,13 +674,101 @@ impl<T> Node<T> {
             if let Some(val) = current.children.first() {
                 if val.prefix == "*" {
                     if let Some(ref val) = val.value {
-                        return Ok((val, params));
+                        trail!(0);
+                        return Some(val);
                     }
                 }
             }
         }
 
-        Err(MatchError)
+        None
+    }
+
+    // match parameter node against path starting with parameter value.
+    fn walk_param<const TRAIL: bool>(&self, path: &str, params: &mut Params, trail: &mut Vec<usize>) -> Option<&T> {
+        let end = path.find('/').unwrap_or(path.len());
+
+        // parameter can not be empty
+        if end == 0 {
+            return None;
+        }
+
+        if let Some(value) = self.walk_suffix::<TRAIL>(path, end, params, trail) {
+            return Some(value);
+        }
+
+        if !self.is_match(&path[..end]) {
+            return None;
+        }
+
+        params.push(&self.prefix[1..], &path[..end]);
+
+        // this is the last path segment
+        if end == path.len() {
+            let value = self.value.as_ref()?;
+            self.remap_params(params);
+            return Some(value);
+        }
+
+        let child = self.indices.iter().position(|&c| c == b'/')?;
+        if TRAIL {
+            trail.push(child);
+        }
+        self.children[child].walk::<TRAIL>(&path[end..], params, trail)
+    }
+
+    // try static suffixes of parameter inside current segment. longer parameter value is tried
+    // first.
+    fn walk_suffix<const TRAIL: bool>(
+        &self,
+        path: &str,
+        end: usize,
+        params: &mut Params,
+        trail: &mut Vec<usize>,
+    ) -> Option<&T> {
+        if self.indices.iter().all(|&c| c == b'/') {
+            return None;
+        }
+
+        for i in (1..end).rev() {
+            let next = path.as_bytes()[i];
+            let Some(child) = self.indices.iter().position(|&c| c == next) else {
+                continue;
+            };
+
+            if !self.is_match(&path[..i]) {
+                continue;
+            }
+
+            let (params_len, trail_len) = (params.len(), trail.len());
+
+            params.push(&self.prefix[1..], &path[..i]);
+            if TRAIL {
+                trail.push(child);
+            }
+
+            if let Some(value) = self.children[child].walk::<TRAIL>(&path[i..], params, trail) {
+                return Some(value);
+            }
+
+            params.truncate(params_len);
+            trail.truncate(trail_len);
+        }
+
+        None
+    }
+
+    // check parameter value against constraint of node.
+    fn is_match(&self, value: &str) -> bool {
+        match self.constraint {
+            Some(ref constraint) => constraint.is_match(value),
+            None => true,
+        }
+    }
+
+    // remap normalized parameter keys to their original names.
+    fn remap_params(&self, params: &mut Params) {
+        params.for_each_key_mut(|(i, key)| *key = param_name(&self.param_remapping[i]).into());
     }
 
     #[cfg(feature = "__test_helpers")]
@@ -486,42 +791,93 @@ impl<T> Node<T> {
 }
 
 /// An ordered list of route parameters keys for a specific route, stored at leaf nodes.
+///
+/// Parameters are stored in their original form. e.g. `:id` or `{id:u64}`.
 type ParamRemapping = Vec<Box<str>>;
 
-/// Returns `path` with normalized route parameters, and a parameter remapping
-/// to store at the leaf node for this route.
-fn normalize_params(mut path: Vec<u8>) -> Result<(Vec<u8>, ParamRemapping), InsertError> {
-    let mut start = 0;
+/// An ordered list of constraints of route parameters.
+type Constraints = Vec<Option<Constraint>>;
+
+/// Returns `path` with normalized route parameters, a parameter remapping to store at the leaf
+/// node for this route and constraints of parameters.
+fn normalize_params(path: Vec<u8>) -> Result<(Vec<u8>, ParamRemapping, Constraints), InsertError> {
+    let mut normalized = Vec::with_capacity(path.len());
     let mut original = ParamRemapping::new();
+    let mut constraints = Constraints::new();
 
     // parameter names are normalized alphabetically
     let mut next = b'a';
 
-    loop {
-        let (wildcard, mut wildcard_index) = match find_wildcard(&path[start..])? {
-            Some((w, i)) => (w, i),
-            None => return Ok((path, original)),
+    let mut start = 0;
+
+    while start < path.len() {
+        let (len, name, constraint) = match path[start] {
+            b':' => {
+                let len = 1 + path[start + 1..].iter().take_while(|c| is_param_name(**c)).count();
+                // static suffix of parameter must start with '.'. e.g. "/:name.json"
+                if !matches!(path.get(start + len), None | Some(b'/' | b'.' | b':' | b'*' | b'{')) {
+                    let end = path[start..]
+                        .iter()
+                        .position(|c| *c == b'/')
+                        .map_or(path.len(), |i| start + i);
+                    return Err(InsertError::invalid_param_name(&path[start..end]));
+                }
+                (len, &path[start + 1..start + len], None)
+            }
+            b'{' => {
+                let len = braced_len(&path[start..])
+                    .ok_or_else(|| InsertError::invalid_constraint(&String::from_utf8_lossy(&path[start..])))?;
+                let inner = &path[start + 1..start + len - 1];
+                match inner.iter().position(|c| *c == b':') {
+                    Some(i) => (len, &inner[..i], Some(&inner[i + 1..])),
+                    None => (len, inner, None),
+                }
+            }
+            b'*' => {
+                // don't need to normalize catch-all parameters
+                let len = 1 + path[start + 1..].iter().take_while(|c| **c != b'/').count();
+                if path[start + 1..start + len]
+                    .iter()
+                    .any(|c| matches!(c, b':' | b'*' | b'{'))
+                {
+                    return Err(InsertError::TooManyParams);
+                }
+                normalized.extend_from_slice(&path[start..start + len]);
+                start += len;
+                continue;
+            }
+            c => {
+                normalized.push(c);
+                start += 1;
+                continue;
+            }
         };
 
-        // makes sure the param has a valid name unless it's * catch all
-        if wildcard.len() < 2 && wildcard.first().filter(|w| **w == b'*').is_none() {
+        // makes sure the param has a valid name
+        if name.is_empty() {
             return Err(InsertError::UnnamedParam);
         }
 
-        // don't need to normalize catch-all parameters
-        if wildcard[0] == b'*' {
-            start += wildcard_index + wildcard.len();
-            continue;
+        if !name.iter().all(|c| is_param_name(*c)) {
+            return Err(InsertError::invalid_param_name(&path[start..start + len]));
         }
 
-        wildcard_index += start;
+        // wildcard right after param can not be separated from it. e.g. "/:foo:bar"
+        if matches!(path.get(start + len), Some(b':' | b'*' | b'{')) {
+            return Err(InsertError::TooManyParams);
+        }
+
+        let constraint = match constraint {
+            Some(constraint) => Some(Constraint::new(from_utf8(constraint)?)?),
+            None => None,
+        };
 
         // normalize the parameter
-        let removed = path.splice((wildcard_index)..(wildcard_index + wildcard.len()), vec![b':', next]);
+        normalized.extend_from_slice(&[b':', next]);
 
         // remember the original name for remappings
-        let removed = removed.collect::<Vec<u8>>();
-        original.push(from_utf8(&removed[..])?.into());
+        original.push(from_utf8(&path[start..start + len])?.into());
+        constraints.push(constraint);
 
         // get the next key
         next += 1;
@@ -529,7 +885,36 @@ fn normalize_params(mut path: Vec<u8>) -> Result<(Vec<u8>, ParamRemapping), Inse
             panic!("too many route parameters");
         }
 
-        start = wildcard_index + 2;
+        start += len;
+    }
+
+    Ok((normalized, original, constraints))
+}
+
+// length of braced parameter including the braces. constraint can contain nested braces.
+fn braced_len(path: &[u8]) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in path.iter().enumerate() {
+        match c {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            b'/' => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+// name of parameter in it's original form.
+fn param_name(param: &str) -> &str {
+    match param.strip_prefix('{') {
+        Some(param) => param.split([':', '}']).next().unwrap_or_default(),
+        None => &param[1..],
     }
 }
 
@@ -540,7 +925,7 @@ pub(crate) fn denormalize_params(route: &mut Vec<u8>, params: &ParamRemapping) {
 
     loop {
         // find the next wildcard
-        let (wildcard, mut wildcard_index) = match find_wildcard(&route[start..]).unwrap() {
+        let (wildcard, mut wildcard_index) = match find_wildcard(&route[start..]) {
             Some((w, i)) => (w, i),
             None => return,
         };
@@ -559,30 +944,33 @@ pub(crate) fn denormalize_params(route: &mut Vec<u8>, params: &ParamRemapping) {
         );
 
         i += 1;
-        start = wildcard_index + 2;
+        start = wildcard_index + next.len();
     }
 }
 
-// Searches for a wildcard segment and checks the path for invalid characters.
-fn find_wildcard(path: &[u8]) -> Result<Option<(&[u8], usize)>, InsertError> {
+// Searches for a wildcard segment of normalized route.
+fn find_wildcard(path: &[u8]) -> Option<(&[u8], usize)> {
     for (start, &c) in path.iter().enumerate() {
-        // a wildcard starts with ':' (param) or '*' (catch-all)
-        if c != b':' && c != b'*' {
-            continue;
-        }
-
-        for (end, &c) in path[start + 1..].iter().enumerate() {
-            match c {
-                b'/' => return Ok(Some((&path[start..start + 1 + end], start))),
-                b':' | b'*' => return Err(InsertError::TooManyParams),
-                _ => {}
+        match c {
+            // normalized param name is always a single character
+            b':' => return Some((&path[start..start + 2], start)),
+            // catch-all lasts until the end of segment
+            b'*' => {
+                let end = path[start..]
+                    .iter()
+                    .position(|c| *c == b'/')
+                    .unwrap_or(path.len() - start);
+                return Some((&path[start..start + end], start));
             }
+            _ => {}
         }
-
-        return Ok(Some((&path[start..], start)));
     }
 
-    Ok(None)
+    None
+}
+
+fn is_param_name(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
 }
 
 #[cfg(test)]
@@ -603,6 +991,7 @@ const _: () = {
             fmt.field("value", &value);
             fmt.field("prefix", &self.prefix);
             fmt.field("node_type", &self.node_type);
+            fmt.field("constraint", &self.constraint);
             fmt.field("children", &self.children);
             fmt.field("param_names", &param_names);
             fmt.field("indices", &indices);
@@ -610,3 +999,111 @@ const _: () = {
         }
     }
 };
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // structure of tree independent from order of children.
+    fn canonical<T: core::fmt::Debug>(node: &Node<T>) -> String {
+        assert_eq!(
+            node.wild_child,
+            node.children.len() > node.indices.len(),
+            "indices out of sync: {node:?}"
+        );
+        for child in &node.children[node.indices.len()..] {
+            assert!(matches!(child.node_type, NodeType::Param | NodeType::CatchAll));
+        }
+        for (i, c) in node.indices.iter().enumerate() {
+            assert_eq!(
+                node.children[i].prefix.as_bytes()[0],
+                *c,
+                "indices out of sync: {node:?}"
+            );
+        }
+
+        let mut children = node.children.iter().map(canonical).collect::<Vec<_>>();
+        children.sort();
+
+        format!(
+            "{:?}{:?}{:?}{:?}{}{:?}{:?}[{}]",
+            node.prefix,
+            node.node_type,
+            node.constraint,
+            node.value,
+            node.priority,
+            node.wild_child,
+            node.param_remapping,
+            children.join(",")
+        )
+    }
+
+    #[test]
+    fn remove_compress() {
+        let routes = [
+            "/",
+            "/cmd/:tool/",
+            "/cmd/:tool/:sub",
+            "/cmd/whoami",
+            "/cmd/whoami/root",
+            "/src/*filepath",
+            "/search/",
+            "/search/:query",
+            "/search/invalid",
+            "/user_:name",
+            "/user_:name/about",
+            "/files/:dir/*filepath",
+            "/doc/",
+            "/doc/rust_faq.html",
+            "/doc/rust1.26.html",
+            "/info/:user/public",
+            "/info/:user/project/:project",
+            "/info/:user/project/rustlang",
+            "/aa/*xx",
+            "/ab/*xx",
+            "/:cc",
+            "/:cc/cc",
+            "/a",
+            "/b",
+            "/files/:name.:ext",
+            "/files/:name.png",
+            "/files/:name",
+            "/v:version/users",
+            "/users/{id:u64}",
+            "/users/{id:u64}/posts",
+            "/users/{id:i8}.json",
+            "/users/:name",
+        ];
+
+        // remove every route one by one in different orders.
+        for step in [1, 3, 5, 7] {
+            let mut node = Node::new();
+            for route in routes {
+                node.insert(route, route).unwrap();
+            }
+
+            let mut removed = Vec::new();
+            let mut i = 0;
+            while removed.len() < routes.len() {
+                let route = routes[i % routes.len()];
+                i += step;
+                if removed.contains(&route) {
+                    i += 1;
+                    continue;
+                }
+
+                assert_eq!(node.remove(route), Some(route));
+                removed.push(route);
+
+                let mut expected = Node::new();
+                for route in routes.iter().filter(|r| !removed.contains(r)) {
+                    expected.insert(*route, *route).unwrap();
+                }
+
+                assert_eq!(canonical(&node), canonical(&expected), "after removing {removed:?}");
+            }
+
+            assert!(node.prefix.is_empty() && node.children.is_empty() && node.priority == 0);
+        }
+    }
+}
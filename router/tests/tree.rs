@@ -529,6 +529,89 @@ match_tests! {
     },
 }
 
+match_tests! {
+    mid_segment_params {
+        routes = [
+            "/files/:name.:ext",
+            "/files/:name.tar",
+            "/files/:name",
+            "/avatars/:id.png",
+            "/avatars/:id.png/raw",
+            "/avatars/default.png",
+            "/v:version/users",
+            "/v1/users",
+            "/range/{from}-{to}",
+            "/{user}@{host}",
+        ],
+        "/files/a.txt"           :: "/files/:name.:ext"    => { "name" => "a", "ext" => "txt" },
+        "/files/a.b.txt"         :: "/files/:name.:ext"    => { "name" => "a.b", "ext" => "txt" },
+        "/files/a.tar"           :: "/files/:name.tar"     => { "name" => "a" },
+        "/files/a.tar.gz"        :: "/files/:name.:ext"    => { "name" => "a.tar", "ext" => "gz" },
+        "/files/a"               :: "/files/:name"         => { "name" => "a" },
+        "/files/a."              :: "/files/:name"         => { "name" => "a." },
+        "/files/.a"              :: "/files/:name"         => { "name" => ".a" },
+        "/files/a.txt/"          :: "" => None,
+        "/avatars/1.png"         :: "/avatars/:id.png"     => { "id" => "1" },
+        "/avatars/1.2.png"       :: "/avatars/:id.png"     => { "id" => "1.2" },
+        "/avatars/1.png/raw"     :: "/avatars/:id.png/raw" => { "id" => "1" },
+        "/avatars/default.png"   :: "/avatars/default.png" => {},
+        "/avatars/.png"          :: "" => None,
+        "/avatars/1.jpg"         :: "" => None,
+        "/avatars/1.png/"        :: "" => None,
+        "/v2/users"              :: "/v:version/users"     => { "version" => "2" },
+        "/v1/users"              :: "/v1/users"            => {},
+        "/v/users"               :: "" => None,
+        "/range/1-10"            :: "/range/{from}-{to}"     => { "from" => "1", "to" => "10" },
+        "/range/1-2-3"           :: "/range/{from}-{to}"     => { "from" => "1-2", "to" => "3" },
+        "/range/1-"              :: "" => None,
+        "/alice@example.com"     :: "/{user}@{host}"       => { "user" => "alice", "host" => "example.com" },
+        "/alice"                 :: "" => None,
+    },
+}
+
+match_tests! {
+    param_constraints {
+        routes = [
+            "/users/{id:u64}",
+            "/users/{id:u64}/posts",
+            "/users/:name",
+            "/users/:name/posts/{post:u8}",
+            "/files/{id:u32}.:ext",
+            "/files/:name.json",
+            "/offset/{n:i8}",
+            "/v{version:u8}/users",
+        ],
+        "/users/1"             :: "/users/{id:u64}"              => { "id" => "1" },
+        "/users/alice"         :: "/users/:name"                 => { "name" => "alice" },
+        "/users/-1"            :: "/users/:name"                 => { "name" => "-1" },
+        "/users/1/posts"       :: "/users/{id:u64}/posts"        => { "id" => "1" },
+        "/users/alice/posts"   :: "" => None,
+        "/users/1/posts/2"     :: "/users/:name/posts/{post:u8}" => { "name" => "1", "post" => "2" },
+        "/users/1/posts/256"   :: "" => None,
+        "/files/1.json"        :: "/files/{id:u32}.:ext"         => { "id" => "1", "ext" => "json" },
+        "/files/a.json"        :: "/files/:name.json"            => { "name" => "a" },
+        "/files/a.txt"         :: "" => None,
+        "/offset/-128"         :: "/offset/{n:i8}"               => { "n" => "-128" },
+        "/offset/128"          :: "" => None,
+        "/v1/users"            :: "/v{version:u8}/users"         => { "version" => "1" },
+        "/vx/users"            :: "" => None,
+    },
+}
+
+#[cfg(feature = "regex")]
+match_tests! {
+    param_regex_constraints {
+        routes = [
+            "/posts/{slug:[a-z-]+}",
+            "/posts/{date:[0-9]{4}-[0-9]{2}}",
+            "/posts/:any",
+        ],
+        "/posts/hello-world" :: "/posts/{slug:[a-z-]+}"           => { "slug" => "hello-world" },
+        "/posts/2024-01"     :: "/posts/{date:[0-9]{4}-[0-9]{2}}" => { "date" => "2024-01" },
+        "/posts/2024-1"      :: "/posts/:any"                     => { "any" => "2024-1" },
+    },
+}
+
 // https://github.com/ibraheemdev/matchit/issues/12
 #[test]
 fn issue_12() {
@@ -647,6 +730,42 @@ insert_tests! {
         "/con:nection"        => Err(InsertError::Conflict { with: "/con:tact".into() }),
         "/whose/:users/:user" => Err(InsertError::Conflict { with: "/whose/:users/:name".into() }),
     },
+    mid_segment_params_conflict {
+        "/files/:name.:ext"   => Ok(()),
+        "/files/:name.:ext"   => Err(InsertError::Conflict { with: "/files/:name.:ext".into() }),
+        "/files/:n.:e"        => Err(InsertError::Conflict { with: "/files/:name.:ext".into() }),
+        "/files/:name.png"    => Ok(()),
+        "/files/:name"        => Ok(()),
+        "/files/:name.:ext/x" => Ok(()),
+        "/files/*path"        => Err(InsertError::Conflict { with: "/files/:name.png".into() }),
+        "/static/*path"       => Ok(()),
+        "/static/:id.png"     => Err(InsertError::Conflict { with: "/static/*path".into() }),
+        "/:a:b.png"           => Err(InsertError::TooManyParams),
+        "/:a.:b:c"            => Err(InsertError::TooManyParams),
+        "/:file-name"         => Err(InsertError::InvalidParamName { name: ":file-name".into() }),
+        "/:file-name/x"       => Err(InsertError::InvalidParamName { name: ":file-name".into() }),
+        "/v:ver~1"            => Err(InsertError::InvalidParamName { name: ":ver~1".into() }),
+        "/:a.*b"              => Err(InsertError::InvalidCatchAll),
+        "/:.png"              => Err(InsertError::UnnamedParam),
+    },
+    param_constraints_conflict {
+        "/users/{id:u64}"     => Ok(()),
+        "/users/{id:u64}"     => Err(InsertError::Conflict { with: "/users/{id:u64}".into() }),
+        "/users/{uid:u64}"    => Err(InsertError::Conflict { with: "/users/{id:u64}".into() }),
+        "/users/{id:u32}"     => Ok(()),
+        "/users/:name"        => Ok(()),
+        "/users/{name}"       => Err(InsertError::Conflict { with: "/users/:name".into() }),
+        "/users/*rest"        => Err(InsertError::Conflict { with: "/users/{id:u64}".into() }),
+        "/files/*rest"        => Ok(()),
+        "/files/{id:u64}"     => Err(InsertError::Conflict { with: "/files/*rest".into() }),
+        "/{id:(}"             => Err(InsertError::InvalidConstraint { constraint: "(".into() }),
+        "/{id:u64"            => Err(InsertError::InvalidConstraint { constraint: "{id:u64".into() }),
+        "/{:u64}"             => Err(InsertError::UnnamedParam),
+        "/{file-name}"        => Err(InsertError::InvalidParamName { name: "{file-name}".into() }),
+        "/{file-name:u64}"    => Err(InsertError::InvalidParamName { name: "{file-name:u64}".into() }),
+        "/{a}{b}"             => Err(InsertError::TooManyParams),
+        "/{id:u8}px"          => Ok(()),
+    },
     catchall_static_overlap1 {
         "/bar"      => Ok(()),
         "/bar/"     => Ok(()),
@@ -674,6 +793,129 @@ insert_tests! {
     },
 }
 
+remove_tests! {
+    remove_static {
+        routes = ["/home", "/home/about", "/homepage", "/users"],
+        "/home/about" => Some("/home/about"),
+        "/home/about" => None,
+        "/home"       => Some("/home"),
+        "/hom"        => None,
+        "/homepage/"  => None,
+    },
+    remove_param {
+        routes = ["/users/:id", "/users/:id/posts", "/users/new", "/users/:id/posts/:post"],
+        "/users/:name"           => None,
+        "/users/:id"             => Some("/users/:id"),
+        "/users/:id/posts/:post" => Some("/users/:id/posts/:post"),
+        "/users/:id/posts"       => Some("/users/:id/posts"),
+        "/users/new"             => Some("/users/new"),
+    },
+    remove_catch_all {
+        routes = ["/src/*filepath", "/src", "/src/static", "/*"],
+        "/src/*path"     => None,
+        "/src/*filepath" => Some("/src/*filepath"),
+        "/*"             => Some("/*"),
+        "/src"           => Some("/src"),
+    },
+    remove_mid_segment_params {
+        routes = ["/files/:name.:ext", "/files/:name.png", "/files/:name", "/v:version/users", "/v1/users"],
+        "/files/:name.png"  => Some("/files/:name.png"),
+        "/files/:name.:ext" => Some("/files/:name.:ext"),
+        "/v1/users"         => Some("/v1/users"),
+        "/v:v/users"        => None,
+        "/v:version/users"  => Some("/v:version/users"),
+    },
+    remove_param_constraints {
+        routes = ["/users/{id:u64}", "/users/{id:u32}", "/users/:name", "/users/{id:u64}/posts"],
+        "/users/{id:u8}"  => None,
+        "/users/{id:u32}" => Some("/users/{id:u32}"),
+        "/users/:name"    => Some("/users/:name"),
+        "/users/{id:u64}" => Some("/users/{id:u64}"),
+    },
+    remove_root {
+        routes = ["/", "/a", "b", ":c"],
+        "/"  => Some("/"),
+        "b"  => Some("b"),
+        ":c" => Some(":c"),
+        "/a" => Some("/a"),
+        "/a" => None,
+    },
+}
+
+#[test]
+fn remove_and_match() {
+    let mut router = Router::new();
+    router.insert("/users/:id", "user").unwrap();
+    router.insert("/users/:id/posts", "posts").unwrap();
+    router.insert("/users/admin", "admin").unwrap();
+    router.insert("/static/*file", "static").unwrap();
+
+    assert_eq!(router.remove("/users/admin"), Some("admin"));
+    let matched = router.at("/users/admin").unwrap();
+    assert_eq!(*matched.value, "user");
+    assert_eq!(matched.params.get("id"), Some("admin"));
+
+    assert_eq!(router.remove("/users/:id"), Some("user"));
+    assert!(router.at("/users/1").is_err());
+    assert_eq!(*router.at("/users/1/posts").unwrap().value, "posts");
+
+    // removed route no longer conflicts.
+    router.insert("/users/:name", "name").unwrap();
+    assert_eq!(router.at("/users/1").unwrap().params.get("name"), Some("1"));
+    assert_eq!(
+        router.insert("/static/*path", "path"),
+        Err(InsertError::Conflict {
+            with: "/static/*file".into()
+        })
+    );
+    assert_eq!(router.remove("/static/*file"), Some("static"));
+    router.insert("/static/*path", "path").unwrap();
+    assert_eq!(router.at("/static/a/b").unwrap().params.get("path"), Some("a/b"));
+}
+
+#[test]
+fn at_mut_and_get_mut() {
+    let mut router = Router::new();
+    router.insert("/users/:id", 0).unwrap();
+    router.insert("/users/:id/posts", 0).unwrap();
+    router.insert("/users/admin", 0).unwrap();
+    router.insert("/*p", 0).unwrap();
+
+    for path in [
+        "/users/1",
+        "/users/1/posts",
+        "/users/admin",
+        "/users/admin/posts",
+        "/foo",
+    ] {
+        let matched = router.at_mut(path).unwrap();
+        *matched.value += 1;
+        assert_eq!(
+            *router.at(path).unwrap().value,
+            1,
+            "unexpected value for path '{}'",
+            path
+        );
+        *router.at_mut(path).unwrap().value = 0;
+    }
+
+    router.insert("/users/{id:u8}/posts", 0).unwrap();
+    *router.at_mut("/users/1/posts").unwrap().value = 3;
+    assert_eq!(*router.at("/users/1/posts").unwrap().value, 3);
+    assert_eq!(*router.at("/users/256/posts").unwrap().value, 0);
+
+    let matched = router.at_mut("/users/admin/posts").unwrap();
+    assert_eq!(matched.params.get("id"), Some("admin"));
+
+    assert_eq!(router.at_mut("/users").unwrap().params.get("p"), Some("users"));
+
+    *router.get_mut("/users/:id/posts").unwrap() = 2;
+    assert_eq!(*router.at("/users/256/posts").unwrap().value, 2);
+    assert!(router.get_mut("/users/:name/posts").is_none());
+    assert!(router.get_mut("/users/").is_none());
+    assert!(router.get_mut("/*p").is_some());
+}
+
 macro_rules! match_tests {
     ($($name:ident {
         routes = $routes:expr,
@@ -758,4 +1000,47 @@ macro_rules! insert_tests {
    )* };
 }
 
-use {insert_tests, match_tests};
+macro_rules! remove_tests {
+    ($($name:ident {
+        routes = $routes:expr,
+        $($route:literal => $res:expr),* $(,)?
+    }),* $(,)?) => { $(
+        #[test]
+        fn $name() {
+            let mut router = Router::new();
+
+            for route in $routes {
+                router.insert(route, route.to_owned())
+                    .unwrap_or_else(|e| panic!("error when inserting route '{}': {:?}", route, e));
+            }
+
+            $(
+                assert_eq!(router.remove($route), $res.map(str::to_owned), "unexpected result for route '{}'", $route);
+
+                if let Err((got, expected)) = router.check_priorities() {
+                    panic!(
+                        "priority mismatch for node: got '{}', expected '{}'",
+                        got, expected
+                    )
+                }
+            )*
+
+            let removed = [$($route),*];
+
+            for route in $routes {
+                let value = router.get_mut(route);
+                if removed.contains(&route) {
+                    assert!(value.is_none(), "removed route '{}' still exists", route);
+                    // removed route can be inserted again
+                    router.insert(route, route.to_owned()).unwrap();
+                } else {
+                    assert_eq!(value.map(|v| v.as_str()), Some(route), "unexpected value for route '{}'", route);
+                }
+            }
+
+            router.check_priorities().unwrap();
+        }
+   )* };
+}
+
+use {insert_tests, match_tests, remove_tests};
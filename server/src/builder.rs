@@ -8,6 +8,7 @@ use xitca_io::net::{Listener, Stream};
 use crate::{
     net::IntoListener,
     server::{IntoServiceObj, Server, ServerFuture, ServiceObj},
+    worker::{LimitPolicy, Limits},
 };
 
 type ListenerFn = Box<dyn FnOnce() -> io::Result<Listener> + Send>;
@@ -19,8 +20,10 @@ pub struct Builder {
     pub(crate) listeners: HashMap<String, Vec<ListenerFn>>,
     pub(crate) factories: HashMap<String, ServiceObj>,
     pub(crate) enable_signal: bool,
+    pub(crate) enable_restart: bool,
     pub(crate) shutdown_timeout: Duration,
     pub(crate) on_worker_start: Box<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>,
+    pub(crate) limits: Limits,
     backlog: u32,
 }
 
@@ -40,8 +43,10 @@ impl Builder {
             listeners: HashMap::new(),
             factories: HashMap::new(),
             enable_signal: true,
+            enable_restart: false,
             shutdown_timeout: Duration::from_secs(30),
             on_worker_start: Box::new(|| Box::pin(async {})),
+            limits: Limits::new(),
             backlog: 2048,
         }
     }
@@ -98,6 +103,24 @@ impl Builder {
         self
     }
 
+    /// Enable graceful restart of server on `SIGHUP` or `SIGUSR2` signal. Only available on unix
+    /// and has no effect when signal listening is disabled.
+    ///
+    /// On receiving the signal server re-executes current binary with the same arguments and
+    /// environment variables and pass all Tcp and Unix listening sockets to it. After the new process
+    /// is spawned current server starts a graceful shutdown. In-flight connections are drained
+    /// within [Builder::shutdown_timeout] while new connections are accepted by the new process.
+    ///
+    /// New process inherits listening sockets through [Builder::bind] and [Builder::bind_unix] with
+    /// the same address or [Builder::listen_fds] with the same name. Udp listening sockets can not
+    /// be inherited and they are bound again in new process.
+    ///
+    /// Without this option `SIGHUP` is ignored and `SIGUSR2` is not listened.
+    pub fn enable_graceful_restart(mut self) -> Self {
+        self.enable_restart = true;
+        self
+    }
+
     /// Timeout for graceful workers shutdown in seconds.
     ///
     /// After receiving a stop signal, workers have this much time to finish serving requests.
@@ -114,6 +137,68 @@ impl Builder {
         self
     }
 
+    /// Set max number of concurrent connections of server. shared by all workers and listeners.
+    ///
+    /// When limit is reached server would act according to [LimitPolicy]. See
+    /// [Builder::connection_limit_policy] for detail.
+    ///
+    /// By default there is no limit.
+    ///
+    /// # Examples:
+    /// ```
+    /// # use xitca_server::{Builder, LimitPolicy};
+    /// let builder = Builder::new()
+    ///     .max_connections(25_000)
+    ///     .max_connections_per_worker(5_000)
+    ///     .max_connections_per_ip(100)
+    ///     .connection_limit_policy(LimitPolicy::Close);
+    /// ```
+    ///
+    /// # Panics:
+    /// When receive 0 as max number of connections.
+    pub fn max_connections(mut self, num: usize) -> Self {
+        assert_ne!(num, 0, "Max connections must be higher than 0");
+        self.limits.max = num;
+        self
+    }
+
+    /// Set max number of concurrent connections of each worker.
+    ///
+    /// By default there is no limit.
+    ///
+    /// # Panics:
+    /// When receive 0 as max number of connections.
+    pub fn max_connections_per_worker(mut self, num: usize) -> Self {
+        assert_ne!(num, 0, "Max connections must be higher than 0");
+        self.limits.max_per_worker = num;
+        self
+    }
+
+    /// Set max number of concurrent connections from the same ip address. Tcp and Udp connections
+    /// are counted and unix domain socket connections are ignored.
+    ///
+    /// Connection exceeding this limit is always closed right after it's accepted regardless of
+    /// [LimitPolicy].
+    ///
+    /// By default there is no limit.
+    ///
+    /// # Panics:
+    /// When receive 0 as max number of connections.
+    pub fn max_connections_per_ip(mut self, num: usize) -> Self {
+        assert_ne!(num, 0, "Max connections must be higher than 0");
+        self.limits.max_per_ip = num;
+        self
+    }
+
+    /// Set behavior of server when [Builder::max_connections] or [Builder::max_connections_per_worker]
+    /// is reached.
+    ///
+    /// Default to [LimitPolicy::Pause].
+    pub fn connection_limit_policy(mut self, policy: LimitPolicy) -> Self {
+        self.limits.policy = policy;
+        self
+    }
+
     #[doc(hidden)]
     /// Async callback called when worker thread is spawned.
     ///
@@ -182,6 +267,11 @@ impl Builder {
         F: IntoServiceObj<St>,
         St: TryFrom<Stream> + 'static,
     {
+        #[cfg(unix)]
+        if let Some(listener) = crate::net::fd::take_tcp(addr) {
+            return Ok(self.listen(name, listener, service));
+        }
+
         let listener = net::TcpListener::bind(addr)?;
 
         let socket = socket2::SockRef::from(&listener);
@@ -201,6 +291,10 @@ impl Builder {
         F: IntoServiceObj<St>,
         St: TryFrom<Stream> + 'static,
     {
+        if let Some(listener) = crate::net::fd::take_unix(path.as_ref()) {
+            return Ok(self.listen(name, listener, service));
+        }
+
         // The path must not exist when we try to bind.
         // Try to remove it to avoid bind error.
         if let Err(e) = std::fs::remove_file(path.as_ref()) {
@@ -214,6 +308,55 @@ impl Builder {
 
         Ok(self.listen(name, listener, service))
     }
+
+    /// Listen on Tcp and Unix sockets inherited from parent process. Sockets can be passed by
+    /// systemd socket activation(`LISTEN_FDS` environment variable) or by graceful restart of
+    /// xitca-server. See [Builder::enable_graceful_restart] for detail.
+    ///
+    /// Sockets named with `name`(through `FileDescriptorName=` option of systemd socket unit or
+    /// listener name of restarted server) are preferred. When no socket carries the name all
+    /// remaining inherited sockets are used. Sockets already inherited by [Builder::bind] and
+    /// [Builder::bind_unix] are excluded.
+    ///
+    /// # Errors:
+    /// When no socket is inherited an [io::ErrorKind::NotFound] error is returned.
+    ///
+    /// # Examples:
+    /// ```no_run
+    /// # use xitca_io::net::TcpStream;
+    /// # use xitca_server::Builder;
+    /// # use xitca_service::fn_service;
+    /// # fn main() -> std::io::Result<()> {
+    /// let service = || fn_service(|_: TcpStream| async { Ok::<_, ()>(()) });
+    /// // fall back to binding address when process is not socket activated.
+    /// let builder = match Builder::new().listen_fds("app", service()) {
+    ///     Ok(builder) => builder,
+    ///     Err(_) => Builder::new().bind("app", "127.0.0.1:8080", service())?,
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn listen_fds<N, F, St>(mut self, name: N, service: F) -> io::Result<Self>
+    where
+        N: AsRef<str>,
+        F: IntoServiceObj<St>,
+        St: TryFrom<Stream> + 'static,
+    {
+        let listeners = crate::net::fd::take_all(name.as_ref());
+
+        if listeners.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "No inherited listener found"));
+        }
+
+        let list = self.listeners.entry(name.as_ref().to_string()).or_default();
+        for listener in listeners {
+            list.push(Box::new(|| listener.into_listener()));
+        }
+
+        self.factories.insert(name.as_ref().to_string(), service.into_object());
+
+        Ok(self)
+    }
 }
 
 #[cfg(feature = "quic")]
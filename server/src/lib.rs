@@ -1,6 +1,6 @@
 //! Multi-threaded server for Tcp/Udp/UnixDomain handling.
 
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 
 mod builder;
 mod server;
@@ -10,7 +10,8 @@ mod worker;
 pub mod net;
 
 pub use builder::Builder;
-pub use server::{ServerFuture, ServerHandle};
+pub use server::{ListenerStats, ServerFuture, ServerHandle, ServerStats, WorkerStats};
+pub use worker::LimitPolicy;
 
 #[cfg(all(not(target_os = "linux"), feature = "io-uring"))]
 compile_error!("io_uring can only be used on linux system");
@@ -0,0 +1,341 @@
+//! Inheriting listening sockets from parent process.
+//!
+//! Two sources of inherited sockets are supported:
+//! - systemd socket activation. See <https://www.freedesktop.org/software/systemd/man/sd_listen_fds.html>
+//! - graceful restart of xitca-server where a re-executed process receives listening sockets of
+//!   its parent. See [restart] for detail.
+
+use core::net::SocketAddr;
+
+use std::{
+    env, io,
+    os::{
+        fd::{AsRawFd, FromRawFd, OwnedFd, RawFd},
+        unix::net::UnixListener,
+    },
+    path::Path,
+    process::{self, Command},
+    sync::{Mutex, OnceLock},
+};
+
+use socket2::{Domain, SockRef, Type};
+use tracing::info;
+use xitca_io::net::Listener;
+
+use crate::server::Registry;
+
+use super::IntoListener;
+
+// environment variables of systemd socket activation.
+const LISTEN_PID: &str = "LISTEN_PID";
+const LISTEN_FDS: &str = "LISTEN_FDS";
+const LISTEN_FDNAMES: &str = "LISTEN_FDNAMES";
+// first file descriptor passed by systemd.
+const LISTEN_FDS_START: RawFd = 3;
+
+// environment variables of graceful restart. file descriptors keep their number in child process
+// so they are passed explicitly instead of counting from LISTEN_FDS_START.
+const XITCA_LISTEN_PPID: &str = "XITCA_LISTEN_PPID";
+const XITCA_LISTEN_FDS: &str = "XITCA_LISTEN_FDS";
+const XITCA_LISTEN_FDNAMES: &str = "XITCA_LISTEN_FDNAMES";
+
+/// A listening socket inherited from parent process.
+pub(crate) enum InheritedListener {
+    Tcp(std::net::TcpListener),
+    Unix(UnixListener),
+}
+
+impl IntoListener for InheritedListener {
+    fn into_listener(self) -> io::Result<Listener> {
+        match self {
+            Self::Tcp(listener) => listener.into_listener(),
+            Self::Unix(listener) => listener.into_listener(),
+        }
+    }
+}
+
+struct Inherited {
+    name: Option<String>,
+    fd: OwnedFd,
+}
+
+fn inherited() -> &'static Mutex<Vec<Inherited>> {
+    static INHERITED: OnceLock<Mutex<Vec<Inherited>>> = OnceLock::new();
+    INHERITED.get_or_init(|| Mutex::new(from_env(|key| env::var(key).ok())))
+}
+
+/// take inherited tcp listener bound to given address.
+pub(crate) fn take_tcp(addr: SocketAddr) -> Option<std::net::TcpListener> {
+    take_tcp_from(&mut inherited().lock().unwrap(), addr)
+}
+
+/// take inherited unix listener bound to given path.
+pub(crate) fn take_unix(path: &Path) -> Option<UnixListener> {
+    take_unix_from(&mut inherited().lock().unwrap(), path)
+}
+
+/// take all inherited listeners with given name. When no listener carries the name all remaining
+/// inherited listeners are taken.
+pub(crate) fn take_all(name: &str) -> Vec<InheritedListener> {
+    take_all_from(&mut inherited().lock().unwrap(), name)
+}
+
+fn take_tcp_from(inherited: &mut Vec<Inherited>, addr: SocketAddr) -> Option<std::net::TcpListener> {
+    // port 0 is assigned by os and it can not be matched with inherited listener.
+    if addr.port() == 0 {
+        return None;
+    }
+
+    take_from(inherited, |fd| {
+        let local = SockRef::from(fd).local_addr().ok()?.as_socket()?;
+        (local == addr).then_some(())
+    })
+    .map(std::net::TcpListener::from)
+}
+
+fn take_unix_from(inherited: &mut Vec<Inherited>, path: &Path) -> Option<UnixListener> {
+    take_from(inherited, |fd| {
+        let local = SockRef::from(fd).local_addr().ok()?;
+        (local.domain() == Domain::UNIX && local.as_pathname()? == path).then_some(())
+    })
+    .map(UnixListener::from)
+}
+
+fn take_all_from(inherited: &mut Vec<Inherited>, name: &str) -> Vec<InheritedListener> {
+    let named = inherited.iter().any(|i| i.name.as_deref() == Some(name));
+
+    let mut listeners = Vec::new();
+    let mut idx = 0;
+
+    while idx < inherited.len() {
+        if named && inherited[idx].name.as_deref() != Some(name) {
+            idx += 1;
+            continue;
+        }
+
+        match listener_type(&inherited[idx].fd) {
+            Some(Domain::UNIX) => listeners.push(InheritedListener::Unix(inherited.remove(idx).fd.into())),
+            Some(_) => listeners.push(InheritedListener::Tcp(inherited.remove(idx).fd.into())),
+            // udp socket and other types are not supported.
+            None => idx += 1,
+        }
+    }
+
+    listeners
+}
+
+// domain of stream socket. None is returned for other socket types.
+fn listener_type(fd: &OwnedFd) -> Option<Domain> {
+    let sock = SockRef::from(fd);
+    if sock.r#type().ok()? != Type::STREAM {
+        return None;
+    }
+    let domain = sock.local_addr().ok()?.domain();
+    matches!(domain, Domain::IPV4 | Domain::IPV6 | Domain::UNIX).then_some(domain)
+}
+
+fn take_from(inherited: &mut Vec<Inherited>, func: impl Fn(&OwnedFd) -> Option<()>) -> Option<OwnedFd> {
+    let idx = inherited
+        .iter()
+        .position(|i| listener_type(&i.fd).is_some() && func(&i.fd).is_some())?;
+    Some(inherited.remove(idx).fd)
+}
+
+// adopt inherited listeners described by environment variables. environment variable is looked up
+// with given function.
+fn from_env(var: impl Fn(&str) -> Option<String>) -> Vec<Inherited> {
+    let (fds, names) = if var(LISTEN_PID).and_then(|pid| pid.parse().ok()) == Some(process::id()) {
+        let Some(num) = var(LISTEN_FDS).and_then(|num| num.parse::<RawFd>().ok()) else {
+            return Vec::new();
+        };
+        let fds = (LISTEN_FDS_START..LISTEN_FDS_START + num).collect::<Vec<_>>();
+        (fds, var(LISTEN_FDNAMES))
+    } else if var(XITCA_LISTEN_PPID).and_then(|pid| pid.parse().ok()) == Some(std::os::unix::process::parent_id()) {
+        let fds = var(XITCA_LISTEN_FDS)
+            .unwrap_or_default()
+            .split(':')
+            .filter_map(|fd| fd.parse().ok())
+            .collect::<Vec<_>>();
+        (fds, var(XITCA_LISTEN_FDNAMES))
+    } else {
+        return Vec::new();
+    };
+
+    let mut names = names.as_deref().unwrap_or_default().split(':');
+
+    fds.into_iter()
+        .filter(|fd| *fd >= LISTEN_FDS_START)
+        .filter_map(|fd| {
+            let name = names.next().filter(|name| !name.is_empty()).map(String::from);
+            let fd = adopt(fd);
+            // inherited file descriptors do not have close on exec flag set.
+            SockRef::from(&fd).set_cloexec(true).ok()?;
+            info!("Inherited listener from parent process: {:?}", fd.as_raw_fd());
+            Some(Inherited { name, fd })
+        })
+        .collect()
+}
+
+#[allow(unsafe_code)]
+fn adopt(fd: RawFd) -> OwnedFd {
+    // SAFETY:
+    // file descriptors listed in environment variables are passed to this process by it's parent
+    // and nothing else in this process owns them. environment variables are only read once so
+    // every file descriptor is adopted at most once.
+    unsafe { OwnedFd::from_raw_fd(fd) }
+}
+
+/// re-execute current binary with same arguments and pass listening sockets to it.
+///
+/// The child process inherit listeners when calling [Builder::bind](crate::Builder::bind),
+/// [Builder::bind_unix](crate::Builder::bind_unix) with the same address or
+/// [Builder::listen_fds](crate::Builder::listen_fds) with the same name.
+pub(crate) fn restart(registry: &Registry) -> io::Result<()> {
+    let entries = registry
+        .groups()
+        .flat_map(|group| group.listeners().into_iter().map(move |entry| (group.name(), entry)))
+        .collect::<Vec<_>>();
+
+    let listeners = entries
+        .iter()
+        .filter_map(|(name, entry)| {
+            let sock = match *entry.listener {
+                Listener::Tcp(ref l) => SockRef::from(l),
+                Listener::Unix(ref l) => SockRef::from(l),
+                #[allow(unreachable_patterns)]
+                _ => return None,
+            };
+            Some((*name, sock))
+        })
+        .collect::<Vec<_>>();
+
+    // listening sockets must survive exec in order to be inherited by child process.
+    let res = inherit_env(&listeners).and_then(|envs| {
+        Command::new(env::current_exe()?)
+            .args(env::args_os().skip(1))
+            .envs(envs)
+            .spawn()
+    });
+
+    for (_, sock) in listeners.iter() {
+        sock.set_cloexec(true)?;
+    }
+
+    let child = res?;
+
+    info!("Restarted server in new process: {}", child.id());
+
+    Ok(())
+}
+
+// clear close on exec flag of listening sockets and encode them into environment variables of child
+// process.
+fn inherit_env(listeners: &[(&str, SockRef<'_>)]) -> io::Result<[(&'static str, String); 3]> {
+    let mut fds = Vec::with_capacity(listeners.len());
+    let mut names = Vec::with_capacity(listeners.len());
+
+    for (name, sock) in listeners.iter() {
+        sock.set_cloexec(false)?;
+        fds.push(sock.as_raw_fd().to_string());
+        names.push(*name);
+    }
+
+    Ok([
+        (XITCA_LISTEN_PPID, process::id().to_string()),
+        (XITCA_LISTEN_FDS, fds.join(":")),
+        (XITCA_LISTEN_FDNAMES, names.join(":")),
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use std::os::fd::IntoRawFd;
+
+    use super::*;
+
+    #[test]
+    fn inherit() {
+        let tcp = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = tcp.local_addr().unwrap();
+
+        let path = env::temp_dir().join(format!("xitca-server-inherit-{}.sock", process::id()));
+        let _ = std::fs::remove_file(&path);
+        let unix = UnixListener::bind(&path).unwrap();
+
+        let fds = format!("{}:{}", tcp.into_raw_fd(), unix.into_raw_fd());
+
+        let mut inherited = from_env(|key| match key {
+            XITCA_LISTEN_PPID => Some(std::os::unix::process::parent_id().to_string()),
+            XITCA_LISTEN_FDS => Some(fds.clone()),
+            XITCA_LISTEN_FDNAMES => Some(String::from("tcp:unix")),
+            _ => None,
+        });
+
+        assert!(take_tcp_from(&mut inherited, "127.0.0.1:1".parse().unwrap()).is_none());
+        let tcp = take_tcp_from(&mut inherited, addr).unwrap();
+        assert_eq!(tcp.local_addr().unwrap(), addr);
+        assert!(take_tcp_from(&mut inherited, addr).is_none());
+
+        let unix = take_all_from(&mut inherited, "unix");
+        assert_eq!(unix.len(), 1);
+        assert!(matches!(unix[0], InheritedListener::Unix(_)));
+        assert!(take_all_from(&mut inherited, "unix").is_empty());
+        assert!(take_all_from(&mut inherited, "tcp").is_empty());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn inherit_env_round_trip() {
+        fn cloexec(fd: RawFd) -> bool {
+            const O_CLOEXEC: u32 = 0o2000000;
+            let info = std::fs::read_to_string(format!("/proc/self/fdinfo/{fd}")).unwrap();
+            let flags = info.lines().find_map(|line| line.strip_prefix("flags:")).unwrap();
+            u32::from_str_radix(flags.trim(), 8).unwrap() & O_CLOEXEC != 0
+        }
+
+        let tcp = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = tcp.local_addr().unwrap();
+
+        let path = env::temp_dir().join(format!("xitca-server-inherit-env-{}.sock", process::id()));
+        let _ = std::fs::remove_file(&path);
+        let unix = UnixListener::bind(&path).unwrap();
+
+        let (tcp_fd, unix_fd) = (tcp.as_raw_fd(), unix.as_raw_fd());
+        assert!(cloexec(tcp_fd) && cloexec(unix_fd));
+
+        let envs = inherit_env(&[("tcp", SockRef::from(&tcp)), ("unix", SockRef::from(&unix))]).unwrap();
+
+        assert!(!cloexec(tcp_fd) && !cloexec(unix_fd));
+        assert_eq!(
+            envs,
+            [
+                (XITCA_LISTEN_PPID, process::id().to_string()),
+                (XITCA_LISTEN_FDS, format!("{tcp_fd}:{unix_fd}")),
+                (XITCA_LISTEN_FDNAMES, String::from("tcp:unix")),
+            ]
+        );
+
+        // ownership of file descriptors is transferred to adopted listeners.
+        let _ = (tcp.into_raw_fd(), unix.into_raw_fd());
+
+        // child process see current process as parent.
+        let inherited = from_env(|key| match key {
+            XITCA_LISTEN_PPID => Some(std::os::unix::process::parent_id().to_string()),
+            key => envs.iter().find(|(k, _)| *k == key).map(|(_, v)| v.clone()),
+        });
+
+        assert_eq!(inherited.len(), 2);
+        assert_eq!(inherited[0].name.as_deref(), Some("tcp"));
+        assert_eq!(inherited[0].fd.as_raw_fd(), tcp_fd);
+        assert_eq!(inherited[1].name.as_deref(), Some("unix"));
+        assert_eq!(inherited[1].fd.as_raw_fd(), unix_fd);
+        assert!(inherited.iter().all(|i| cloexec(i.fd.as_raw_fd())));
+
+        let local = SockRef::from(&inherited[0].fd).local_addr().unwrap();
+        assert_eq!(local.as_socket(), Some(addr));
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(unix)]
+pub(crate) mod fd;
+
 use std::io;
 
 #[cfg(feature = "quic")]
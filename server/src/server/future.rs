@@ -45,12 +45,8 @@ impl ServerFuture {
     /// ```
     pub fn handle(&mut self) -> io::Result<ServerHandle> {
         match *self {
-            Self::Init { ref server, .. } => Ok(ServerHandle {
-                tx: server.tx_cmd.clone(),
-            }),
-            Self::Running(ref inner) => Ok(ServerHandle {
-                tx: inner.server.tx_cmd.clone(),
-            }),
+            Self::Init { ref server, .. } => Ok(ServerHandle::new(server)),
+            Self::Running(ref inner) => Ok(ServerHandle::new(&inner.server)),
             Self::Error(_) => match mem::take(self) {
                 Self::Error(e) => Err(e),
                 _ => unreachable!(),
@@ -117,10 +113,8 @@ impl Default for ServerFuture {
 impl ServerFutureInner {
     #[inline(never)]
     fn new(server: Server, enable_signal: bool) -> Self {
-        Self {
-            server,
-            signals: enable_signal.then(signals::start),
-        }
+        let signals = enable_signal.then(|| signals::start(server.enable_restart()));
+        Self { server, signals }
     }
 
     #[inline(never)]
@@ -131,6 +125,16 @@ impl ServerFutureInner {
                 let cmd = match sig {
                     Signal::Int | Signal::Quit => Command::ForceStop,
                     Signal::Term => Command::GracefulStop,
+                    // new process takes over listeners and current one drains in-flight connections.
+                    Signal::Hup | Signal::Usr2 if self.server.enable_restart() => {
+                        if self.server.restart() {
+                            Command::GracefulStop
+                        } else {
+                            // keep server running when restart failed and wait for next signal.
+                            return self.poll_cmd(cx);
+                        }
+                    }
+                    Signal::Usr2 => return self.poll_cmd(cx),
                     // Remove signal listening and keep Server running when
                     // terminal closed which xitca-server process belong.
                     Signal::Hup => {
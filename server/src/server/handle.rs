@@ -1,13 +1,32 @@
-use tokio::sync::mpsc::UnboundedSender;
+use core::net::SocketAddr;
 
-use super::Command;
+use std::{io, sync::Arc};
+
+use tokio::{runtime, sync::mpsc::UnboundedSender};
+use xitca_io::net::Listener;
+
+use crate::{net::IntoListener, worker::Connections};
+
+use super::{registry::Registry, Command, Server};
 
 #[derive(Clone)]
 pub struct ServerHandle {
-    pub(super) tx: UnboundedSender<Command>,
+    tx: UnboundedSender<Command>,
+    connections: Arc<Connections>,
+    registry: Arc<Registry>,
+    rt: runtime::Handle,
 }
 
 impl ServerHandle {
+    pub(super) fn new(server: &Server) -> Self {
+        Self {
+            tx: server.tx_cmd.clone(),
+            connections: server.connections.clone(),
+            registry: server.registry.clone(),
+            rt: server.rt_handle.clone(),
+        }
+    }
+
     /// Stop xitca-server with graceful flag.
     pub fn stop(&self, graceful: bool) {
         let cmd = if graceful {
@@ -18,4 +37,198 @@ impl ServerHandle {
 
         let _ = self.tx.send(cmd);
     }
+
+    /// Number of active connections of all workers.
+    ///
+    /// Connections are counted from being accepted until their service call is finished.
+    pub fn active_connections(&self) -> usize {
+        self.connections.active()
+    }
+
+    /// Snapshot of connection and request statistics of server, it's listeners and workers.
+    ///
+    /// Server has no knowledge of requests of upper level protocol(for example http/1 keep-alive and
+    /// http/2 multiplexing). In-flight requests are counted by service through [InFlight] and they are
+    /// always zero for service not doing so. xitca-http counts every request from it's head being
+    /// decoded until it's response is sent.
+    ///
+    /// [InFlight]: xitca_io::net::InFlight
+    pub fn stats(&self) -> ServerStats {
+        let mut listeners = self
+            .registry
+            .groups()
+            .flat_map(|group| {
+                let paused = group.is_paused();
+                group.listeners().into_iter().map(move |entry| ListenerStats {
+                    id: entry.id,
+                    name: group.name().to_owned(),
+                    local_addr: local_addr(&entry.listener),
+                    paused,
+                    active_connections: entry.counter.active(),
+                    accepted_connections: entry.counter.accepted(),
+                    in_flight_requests: entry.counter.in_flight(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        listeners.sort_by_key(|listener| listener.id);
+
+        let workers = self
+            .connections
+            .workers()
+            .into_iter()
+            .map(|counter| WorkerStats {
+                active_connections: counter.active(),
+                accepted_connections: counter.accepted(),
+                in_flight_requests: counter.in_flight(),
+            })
+            .collect::<Vec<_>>();
+
+        ServerStats {
+            active_connections: self.connections.active(),
+            in_flight_requests: workers.iter().map(|worker| worker.in_flight_requests).sum(),
+            listeners,
+            workers,
+        }
+    }
+
+    /// Stop accepting new connections on listeners with given name. Active connections are not
+    /// affected and pending connections are queued in listener's backlog.
+    ///
+    /// Return false when no service is registered with the name.
+    pub fn pause(&self, name: &str) -> bool {
+        self.set_paused(name, true)
+    }
+
+    /// Resume accepting new connections on listeners paused by [ServerHandle::pause].
+    ///
+    /// Return false when no service is registered with the name.
+    pub fn resume(&self, name: &str) -> bool {
+        self.set_paused(name, false)
+    }
+
+    fn set_paused(&self, name: &str, paused: bool) -> bool {
+        self.registry
+            .group(name)
+            .map(|group| group.set_paused(paused))
+            .is_some()
+    }
+
+    /// Add listener to a running server. Connections accepted by it are handled by the service
+    /// registered with given name. Return id of listener that can be used to remove it.
+    ///
+    /// # Errors:
+    /// When no service is registered with the name an [io::ErrorKind::NotFound] error is returned.
+    ///
+    /// # Examples:
+    /// ```rust
+    /// # use xitca_io::net::TcpStream;
+    /// # use xitca_server::Builder;
+    /// # use xitca_service::fn_service;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut server = Builder::new()
+    ///     .bind("test", "127.0.0.1:0", fn_service(|_io: TcpStream| async { Ok::<_, ()>(())}))
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// let handle = server.handle().unwrap();
+    ///
+    /// // listen on another address with the service named "test".
+    /// let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    /// let id = handle.add_listener("test", listener).unwrap();
+    /// assert_eq!(handle.stats().listeners.len(), 2);
+    ///
+    /// assert!(handle.remove_listener(id));
+    /// assert_eq!(handle.stats().listeners.len(), 1);
+    /// # handle.stop(false);
+    /// # server.await.unwrap();
+    /// # }
+    /// ```
+    pub fn add_listener<L>(&self, name: &str, listener: L) -> io::Result<usize>
+    where
+        L: IntoListener,
+    {
+        if self.registry.group(name).is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "No service registered with name",
+            ));
+        }
+
+        // listener is registered to server's runtime same as listeners added through Builder.
+        let listener = {
+            let _guard = self.rt.enter();
+            listener.into_listener()?
+        };
+
+        self.registry
+            .add(name, listener)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+
+    /// Remove listener with given id. Listener is closed after all workers stopped accepting from
+    /// it and connections already accepted by it are not affected.
+    ///
+    /// Return false when listener is not found.
+    pub fn remove_listener(&self, id: usize) -> bool {
+        self.registry.remove(id)
+    }
+}
+
+/// Connection and request statistics of server. See [ServerHandle::stats].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ServerStats {
+    /// number of active connections of all workers.
+    pub active_connections: usize,
+    /// number of in-flight requests of all workers.
+    pub in_flight_requests: usize,
+    /// statistics of every listener ordered by their id.
+    pub listeners: Vec<ListenerStats>,
+    /// statistics of every started worker.
+    pub workers: Vec<WorkerStats>,
+}
+
+/// Connection and request statistics of a listener.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ListenerStats {
+    /// id of listener. See [ServerHandle::remove_listener].
+    pub id: usize,
+    /// name of service the listener registered with.
+    pub name: String,
+    /// local address of listener. `None` for unix domain socket listener.
+    pub local_addr: Option<SocketAddr>,
+    /// if listener is paused by [ServerHandle::pause].
+    pub paused: bool,
+    /// number of active connections accepted by listener.
+    pub active_connections: usize,
+    /// total number of connections accepted by listener.
+    pub accepted_connections: usize,
+    /// number of in-flight requests on connections accepted by listener.
+    pub in_flight_requests: usize,
+}
+
+/// Connection and request statistics of a worker.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct WorkerStats {
+    /// number of active connections handled by worker.
+    pub active_connections: usize,
+    /// total number of connections accepted by worker.
+    pub accepted_connections: usize,
+    /// number of in-flight requests on connections handled by worker.
+    pub in_flight_requests: usize,
+}
+
+fn local_addr(listener: &Listener) -> Option<SocketAddr> {
+    match *listener {
+        Listener::Tcp(ref l) => l.local_addr().ok(),
+        #[cfg(feature = "quic")]
+        Listener::Udp(ref l) => l.endpoint().local_addr().ok(),
+        // unix listener and udp listener when quic is enabled by other crate depending on xitca-io.
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
 }
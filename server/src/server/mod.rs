@@ -1,10 +1,17 @@
 mod future;
 mod handle;
+mod registry;
 mod service;
 
-pub use self::{future::ServerFuture, handle::ServerHandle};
+pub use self::{
+    future::ServerFuture,
+    handle::{ListenerStats, ServerHandle, ServerStats, WorkerStats},
+};
 
-pub(crate) use self::service::{IntoServiceObj, ServiceObj};
+pub(crate) use self::{
+    registry::{Group, ListenerEntry, Registry},
+    service::{IntoServiceObj, ServiceObj},
+};
 
 use std::{
     io, mem,
@@ -20,10 +27,18 @@ use tokio::{
     sync::mpsc::{UnboundedReceiver, UnboundedSender},
 };
 
-use crate::{builder::Builder, worker};
+use crate::{
+    builder::Builder,
+    worker::{self, Connections, WorkerConnections},
+};
 
 pub struct Server {
     is_graceful_shutdown: Arc<AtomicBool>,
+    connections: Arc<Connections>,
+    registry: Arc<Registry>,
+    rt_handle: tokio::runtime::Handle,
+    #[cfg(unix)]
+    enable_restart: bool,
     tx_cmd: UnboundedSender<Command>,
     rx_cmd: UnboundedReceiver<Command>,
     rt: Option<Runtime>,
@@ -38,6 +53,7 @@ impl Server {
             factories,
             shutdown_timeout,
             on_worker_start,
+            limits,
             ..
         } = builder;
 
@@ -46,32 +62,34 @@ impl Server {
         let fut = async {
             listeners
                 .into_iter()
-                .flat_map(|(name, listeners)| {
-                    listeners
-                        .into_iter()
-                        .map(move |l| l().map(|l| (name.to_owned(), Arc::new(l))))
-                })
+                .flat_map(|(name, listeners)| listeners.into_iter().map(move |l| l().map(|l| (name.to_owned(), l))))
                 .collect::<Result<Vec<_>, io::Error>>()
         };
 
         let listeners = rt.block_on(fut)?;
 
+        let registry = Registry::new(factories.keys(), listeners);
+
         let is_graceful_shutdown = Arc::new(AtomicBool::new(false));
 
+        let connections = Connections::new(limits);
+
         let on_start_fut = on_worker_start();
 
         let fut = async {
             on_start_fut.await;
 
+            let worker_connections = WorkerConnections::new(connections);
+
             let mut handles = Vec::new();
             let mut services = Vec::new();
 
             for (name, factory) in factories.iter() {
                 let (h, s) = factory
-                    .call((name, &listeners))
+                    .call((name, &registry, &worker_connections))
                     .await
                     .map_err(|_| io::Error::from(io::ErrorKind::Other))?;
-                handles.extend(h);
+                handles.push(h);
                 services.push(s);
             }
 
@@ -95,6 +113,8 @@ impl Server {
             factories,
             shutdown_timeout,
             on_worker_start,
+            limits,
+            enable_restart,
             ..
         } = builder;
 
@@ -108,11 +128,7 @@ impl Server {
         let fut = async {
             listeners
                 .into_iter()
-                .flat_map(|(name, listeners)| {
-                    listeners
-                        .into_iter()
-                        .map(move |l| l().map(|l| (name.to_owned(), Arc::new(l))))
-                })
+                .flat_map(|(name, listeners)| listeners.into_iter().map(move |l| l().map(|l| (name.to_owned(), l))))
                 .collect::<Result<Vec<_>, io::Error>>()
         };
 
@@ -120,13 +136,24 @@ impl Server {
         // *. Server::new is most likely already inside a tokio runtime.
         let listeners = thread::scope(|s| s.spawn(|| rt.block_on(fut)).join()).unwrap()?;
 
+        let registry = Arc::new(Registry::new(factories.keys(), listeners));
+        let registry2 = registry.clone();
+
         let is_graceful_shutdown = Arc::new(AtomicBool::new(false));
         let is_graceful_shutdown2 = is_graceful_shutdown.clone();
 
+        let connections = Connections::new(limits);
+        let connections2 = connections.clone();
+
+        #[cfg(not(unix))]
+        let _ = enable_restart;
+
         let worker_handles = thread::Builder::new()
             .name(String::from("xitca-server-worker-shared-scope"))
             .spawn(move || {
                 let is_graceful_shutdown = is_graceful_shutdown2;
+                let connections = connections2;
+                let registry = registry2;
 
                 // TODO: wait for startup error(including panic) and return as io::Error on call site.
                 // currently the error only show when shared scope thread is joined with handle.
@@ -137,13 +164,15 @@ impl Server {
                         let task = || async {
                             on_worker_start().await;
 
+                            let worker_connections = WorkerConnections::new(connections.clone());
+
                             let mut handles = Vec::new();
                             let mut services = Vec::new();
 
                             for (name, factory) in factories.iter() {
-                                match factory.call((name, &listeners)).await {
+                                match factory.call((name, &registry, &worker_connections)).await {
                                     Ok((h, s)) => {
-                                        handles.extend(h);
+                                        handles.push(h);
                                         services.push(s);
                                     }
                                     Err(_) => return,
@@ -182,6 +211,11 @@ impl Server {
 
         Ok(Self {
             is_graceful_shutdown,
+            connections,
+            registry,
+            rt_handle: rt.handle().clone(),
+            #[cfg(unix)]
+            enable_restart,
             tx_cmd,
             rx_cmd,
             rt: Some(rt),
@@ -189,9 +223,34 @@ impl Server {
         })
     }
 
+    /// re-execute current binary and pass listeners to it. return false when restart is not enabled.
+    pub(crate) fn restart(&self) -> bool {
+        #[cfg(unix)]
+        if self.enable_restart {
+            return crate::net::fd::restart(&self.registry)
+                .map_err(|e| tracing::error!("Failed to restart server: {e}"))
+                .is_ok();
+        }
+
+        false
+    }
+
+    pub(crate) fn enable_restart(&self) -> bool {
+        #[cfg(unix)]
+        {
+            self.enable_restart
+        }
+
+        #[cfg(not(unix))]
+        {
+            false
+        }
+    }
+
     pub(crate) fn stop(&mut self, graceful: bool) {
         if let Some(rt) = self.rt.take() {
             self.is_graceful_shutdown.store(graceful, Ordering::SeqCst);
+            self.registry.close();
             rt.shutdown_background();
             mem::take(&mut self.worker_join_handles).into_iter().for_each(|handle| {
                 let _ = handle.join().unwrap();
@@ -0,0 +1,146 @@
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::{futures::Notified, Notify};
+use xitca_io::net::Listener;
+
+use crate::worker::Counter;
+
+/// registry of listeners grouped by their service name. shared by server handle and all workers.
+pub struct Registry {
+    groups: HashMap<String, Arc<Group>>,
+    next_id: AtomicUsize,
+}
+
+impl Registry {
+    pub(crate) fn new<'a>(names: impl IntoIterator<Item = &'a String>, listeners: Vec<(String, Listener)>) -> Self {
+        let groups = names
+            .into_iter()
+            .map(|name| {
+                let group = Group {
+                    name: name.clone(),
+                    listeners: Mutex::new(Vec::new()),
+                    paused: AtomicBool::new(false),
+                    closed: AtomicBool::new(false),
+                    notify: Notify::new(),
+                };
+                (name.clone(), Arc::new(group))
+            })
+            .collect();
+
+        let registry = Self {
+            groups,
+            next_id: AtomicUsize::new(0),
+        };
+
+        for (name, listener) in listeners {
+            registry.add(&name, listener);
+        }
+
+        registry
+    }
+
+    pub(crate) fn group(&self, name: &str) -> Option<&Arc<Group>> {
+        self.groups.get(name)
+    }
+
+    pub(crate) fn groups(&self) -> impl Iterator<Item = &Arc<Group>> {
+        self.groups.values()
+    }
+
+    /// add listener to group with given name. return id of listener or `None` when there is no
+    /// group with the name.
+    pub(crate) fn add(&self, name: &str, listener: Listener) -> Option<usize> {
+        let group = self.group(name)?;
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        group.listeners.lock().unwrap().push(Arc::new(ListenerEntry {
+            id,
+            listener: Arc::new(listener),
+            counter: Arc::default(),
+        }));
+        group.notify.notify_waiters();
+        Some(id)
+    }
+
+    /// remove listener with given id. return false when listener is not found.
+    pub(crate) fn remove(&self, id: usize) -> bool {
+        self.groups().any(|group| {
+            let mut listeners = group.listeners.lock().unwrap();
+            let len = listeners.len();
+            listeners.retain(|entry| entry.id != id);
+            let removed = listeners.len() != len;
+            drop(listeners);
+            if removed {
+                group.notify.notify_waiters();
+            }
+            removed
+        })
+    }
+
+    /// close all groups and notify workers to stop accepting connections.
+    pub(crate) fn close(&self) {
+        for group in self.groups() {
+            group.closed.store(true, Ordering::SeqCst);
+            group.notify.notify_waiters();
+        }
+    }
+}
+
+/// listeners share the same service name.
+pub(crate) struct Group {
+    name: String,
+    listeners: Mutex<Vec<Arc<ListenerEntry>>>,
+    paused: AtomicBool,
+    closed: AtomicBool,
+    notify: Notify,
+}
+
+impl Group {
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn listeners(&self) -> Vec<Arc<ListenerEntry>> {
+        self.listeners.lock().unwrap().clone()
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    /// a future resolves on next state change of group. it's registered for notification on
+    /// creation so state change between creation and await is not missed.
+    pub(crate) fn changed(&self) -> Notified<'_> {
+        self.notify.notified()
+    }
+
+    /// wait until given condition of group is met.
+    pub(crate) async fn wait(&self, func: impl Fn(&Self) -> bool) {
+        loop {
+            let changed = self.changed();
+            if func(self) {
+                return;
+            }
+            changed.await;
+        }
+    }
+}
+
+pub(crate) struct ListenerEntry {
+    pub(crate) id: usize,
+    pub(crate) listener: Arc<Listener>,
+    pub(crate) counter: Arc<Counter>,
+}
@@ -1,15 +1,17 @@
-use std::{marker::PhantomData, rc::Rc, sync::Arc};
+use std::{marker::PhantomData, rc::Rc};
 
 use tokio::task::JoinHandle;
-use xitca_io::net::{Listener, Stream};
+use xitca_io::net::Stream;
 use xitca_service::{ready::ReadyService, Service};
 
-use crate::worker::{self, ServiceAny};
+use crate::worker::{self, ServiceAny, WorkerConnections};
+
+use super::registry::Registry;
 
 pub type ServiceObj = Box<
     dyn for<'a> xitca_service::object::ServiceObject<
-            (&'a str, &'a [(String, Arc<Listener>)]),
-            Response = (Vec<JoinHandle<()>>, ServiceAny),
+            (&'a str, &'a Registry, &'a Rc<WorkerConnections>),
+            Response = (JoinHandle<()>, ServiceAny),
             Error = (),
         > + Send
         + Sync,
@@ -20,28 +22,25 @@ struct Container<F, Req> {
     _t: PhantomData<fn(Req)>,
 }
 
-impl<'a, F, Req> Service<(&'a str, &'a [(String, Arc<Listener>)])> for Container<F, Req>
+impl<'a, F, Req> Service<(&'a str, &'a Registry, &'a Rc<WorkerConnections>)> for Container<F, Req>
 where
     F: IntoServiceObj<Req>,
     Req: TryFrom<Stream> + 'static,
 {
-    type Response = (Vec<JoinHandle<()>>, ServiceAny);
+    type Response = (JoinHandle<()>, ServiceAny);
     type Error = ();
 
     async fn call(
         &self,
-        (name, listeners): (&'a str, &'a [(String, Arc<Listener>)]),
+        (name, registry, connections): (&'a str, &'a Registry, &'a Rc<WorkerConnections>),
     ) -> Result<Self::Response, Self::Error> {
         let service = self.inner.call(()).await.map_err(|_| ())?;
         let service = Rc::new(service);
 
-        let handles = listeners
-            .iter()
-            .filter(|(n, _)| n == name)
-            .map(|(_, listener)| worker::start(listener, &service))
-            .collect::<Vec<_>>();
+        let group = registry.group(name).ok_or(())?;
+        let handle = worker::serve(group, &service, connections);
 
-        Ok((handles, service as _))
+        Ok((handle, service as _))
     }
 }
 
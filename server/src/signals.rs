@@ -12,11 +12,13 @@ pub(crate) enum Signal {
     Term,
     /// SIGQUIT
     Quit,
+    /// SIGUSR2
+    Usr2,
 }
 
 pub(crate) type SignalFuture = Pin<Box<dyn Future<Output = Signal> + Send>>;
 
-pub(crate) fn start() -> SignalFuture {
+pub(crate) fn start(enable_restart: bool) -> SignalFuture {
     #[cfg(unix)]
     {
         use std::{future::poll_fn, task::Poll};
@@ -28,8 +30,11 @@ pub(crate) fn start() -> SignalFuture {
             (unix::SignalKind::hangup(), Signal::Hup),
             (unix::SignalKind::terminate(), Signal::Term),
             (unix::SignalKind::quit(), Signal::Quit),
+            (unix::SignalKind::user_defined2(), Signal::Usr2),
         ]
         .iter()
+        // SIGUSR2 terminates process by default. only listen to it when it's used for restarting.
+        .filter(|(_, sig)| enable_restart || *sig != Signal::Usr2)
         .filter_map(|(kind, sig)| {
             unix::signal(*kind)
                 .map(|tokio_sig| (*sig, tokio_sig))
@@ -49,6 +54,9 @@ pub(crate) fn start() -> SignalFuture {
         }))
     }
 
+    #[cfg(not(unix))]
+    let _ = enable_restart;
+
     #[cfg(not(any(unix, target_family = "wasm")))]
     {
         Box::pin(async {
@@ -0,0 +1,385 @@
+use core::{
+    net::IpAddr,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use tokio::{sync::Notify, time::Instant};
+use xitca_io::net::{InFlight, Stream};
+
+/// Behavior of server when connection limit is reached.
+///
+/// See [Builder::max_connections](crate::Builder::max_connections) for detail.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LimitPolicy {
+    /// stop accepting new connections until active connections drop below limit. pending
+    /// connections are queued in listener's backlog.
+    ///
+    /// Workers can race for the last capacity and the losers have already accepted a connection.
+    /// Such connection waits for capacity for at most 1 second and is closed when no capacity
+    /// becomes available in time. The listener does not accept more connections while waiting.
+    #[default]
+    Pause,
+    /// keep accepting new connections and close them immediately.
+    Close,
+}
+
+/// connection limits of server. `usize::MAX` means no limit.
+#[derive(Clone, Copy)]
+pub(crate) struct Limits {
+    pub(crate) max: usize,
+    pub(crate) max_per_worker: usize,
+    pub(crate) max_per_ip: usize,
+    pub(crate) policy: LimitPolicy,
+    // max duration accepted connection waits for capacity with LimitPolicy::Pause.
+    pub(crate) pause_timeout: Duration,
+}
+
+impl Limits {
+    pub(crate) const fn new() -> Self {
+        Self {
+            max: usize::MAX,
+            max_per_worker: usize::MAX,
+            max_per_ip: usize::MAX,
+            policy: LimitPolicy::Pause,
+            pause_timeout: Duration::from_secs(1),
+        }
+    }
+}
+
+/// counter of active and accepted connections and in-flight requests.
+#[derive(Default)]
+pub(crate) struct Counter {
+    active: AtomicUsize,
+    accepted: AtomicUsize,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl Counter {
+    /// number of active connections.
+    pub(crate) fn active(&self) -> usize {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// total number of accepted connections.
+    pub(crate) fn accepted(&self) -> usize {
+        self.accepted.load(Ordering::Relaxed)
+    }
+
+    /// number of in-flight requests counted by protocol implementation through [InFlight].
+    pub(crate) fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    fn incr(&self) {
+        self.active.fetch_add(1, Ordering::Relaxed);
+        self.accepted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn decr(&self) {
+        self.active.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// connection counter shared by all workers of server.
+pub(crate) struct Connections {
+    limits: Limits,
+    active: AtomicUsize,
+    per_ip: Mutex<HashMap<IpAddr, usize>>,
+    notify: Notify,
+    workers: Mutex<Vec<Arc<Counter>>>,
+}
+
+impl Connections {
+    pub(crate) fn new(limits: Limits) -> Arc<Self> {
+        Arc::new(Self {
+            limits,
+            active: AtomicUsize::new(0),
+            per_ip: Mutex::new(HashMap::new()),
+            notify: Notify::new(),
+            workers: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// number of active connections of all workers.
+    pub(crate) fn active(&self) -> usize {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// connection counters of every started worker.
+    pub(crate) fn workers(&self) -> Vec<Arc<Counter>> {
+        self.workers.lock().unwrap().clone()
+    }
+}
+
+/// connection counter of a single worker.
+pub struct WorkerConnections {
+    shared: Arc<Connections>,
+    counter: Arc<Counter>,
+}
+
+enum Exceeded {
+    Capacity,
+    Ip,
+}
+
+impl WorkerConnections {
+    pub(crate) fn new(shared: Arc<Connections>) -> Rc<Self> {
+        let counter = Arc::new(Counter::default());
+        shared.workers.lock().unwrap().push(counter.clone());
+        Rc::new(Self { shared, counter })
+    }
+
+    fn has_capacity(&self) -> bool {
+        let limits = &self.shared.limits;
+        self.counter.active() < limits.max_per_worker && self.shared.active() < limits.max
+    }
+
+    /// wait until there is capacity for accepting new connection. resolve immediately when policy
+    /// is [LimitPolicy::Close].
+    pub(crate) async fn ready(&self) {
+        if self.shared.limits.policy == LimitPolicy::Close {
+            return;
+        }
+
+        loop {
+            // Notified future is registered on creation so no wake up can be missed between
+            // capacity check and await.
+            let notified = self.shared.notify.notified();
+            if self.has_capacity() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// acquire a guard for accepted connection. `None` is returned when connection must be closed.
+    ///
+    /// listener counter is increased together with worker counter when guard is acquired.
+    pub(crate) async fn acquire(self: &Rc<Self>, stream: &Stream, listener: &Arc<Counter>) -> Option<ConnectionGuard> {
+        let ip = match *stream {
+            Stream::Tcp(_, addr) => Some(addr.ip()),
+            #[cfg(feature = "quic")]
+            Stream::Udp(_, addr) => Some(addr.ip()),
+            // unix stream and udp stream when quic is enabled by other crate depending on xitca-io.
+            #[allow(unreachable_patterns)]
+            _ => None,
+        };
+
+        self.acquire_ip(ip, listener).await
+    }
+
+    async fn acquire_ip(self: &Rc<Self>, ip: Option<IpAddr>, listener: &Arc<Counter>) -> Option<ConnectionGuard> {
+        let mut deadline = None;
+
+        loop {
+            let notified = self.shared.notify.notified();
+            match self.try_acquire(ip, listener) {
+                Ok(guard) => return Some(guard),
+                Err(Exceeded::Ip) => return None,
+                Err(Exceeded::Capacity) if self.shared.limits.policy == LimitPolicy::Close => return None,
+                // another worker can take the last slot between ready and acquire. wait for it to be
+                // released with a bounded time as the accepted connection is held meanwhile.
+                Err(Exceeded::Capacity) => {
+                    let deadline = *deadline.get_or_insert_with(|| Instant::now() + self.shared.limits.pause_timeout);
+                    tokio::time::timeout_at(deadline, notified).await.ok()?;
+                }
+            }
+        }
+    }
+
+    fn try_acquire(self: &Rc<Self>, ip: Option<IpAddr>, listener: &Arc<Counter>) -> Result<ConnectionGuard, Exceeded> {
+        let limits = &self.shared.limits;
+
+        if self.counter.active() >= limits.max_per_worker {
+            return Err(Exceeded::Capacity);
+        }
+
+        self.shared
+            .active
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                (n < limits.max).then_some(n + 1)
+            })
+            .map_err(|_| Exceeded::Capacity)?;
+
+        // per ip counting is skipped when there is no limit on it.
+        let ip = ip.filter(|_| limits.max_per_ip != usize::MAX);
+
+        if let Some(ip) = ip {
+            let mut per_ip = self.shared.per_ip.lock().unwrap();
+            let count = per_ip.entry(ip).or_default();
+            if *count >= limits.max_per_ip {
+                drop(per_ip);
+                self.shared.active.fetch_sub(1, Ordering::Relaxed);
+                return Err(Exceeded::Ip);
+            }
+            *count += 1;
+        }
+
+        self.counter.incr();
+        listener.incr();
+
+        Ok(ConnectionGuard {
+            worker: self.clone(),
+            listener: listener.clone(),
+            ip,
+        })
+    }
+}
+
+/// guard of an active connection. connection counters are decreased when it's dropped.
+pub(crate) struct ConnectionGuard {
+    worker: Rc<WorkerConnections>,
+    listener: Arc<Counter>,
+    ip: Option<IpAddr>,
+}
+
+impl ConnectionGuard {
+    /// in-flight request counter of connection shared by it's listener and worker.
+    pub(crate) fn in_flight(&self) -> InFlight {
+        InFlight::new([self.listener.in_flight.clone(), self.worker.counter.in_flight.clone()])
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        let worker = &self.worker;
+
+        worker.counter.decr();
+        self.listener.decr();
+        worker.shared.active.fetch_sub(1, Ordering::Relaxed);
+
+        if let Some(ip) = self.ip {
+            let mut per_ip = worker.shared.per_ip.lock().unwrap();
+            if let Some(count) = per_ip.get_mut(&ip) {
+                *count -= 1;
+                if *count == 0 {
+                    per_ip.remove(&ip);
+                }
+            }
+        }
+
+        if worker.shared.limits.policy == LimitPolicy::Pause {
+            worker.shared.notify.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn worker(limits: Limits) -> Rc<WorkerConnections> {
+        WorkerConnections::new(Connections::new(limits))
+    }
+
+    const IP: Option<IpAddr> = Some(IpAddr::V4(core::net::Ipv4Addr::LOCALHOST));
+
+    #[test]
+    fn limit() {
+        let shared = Connections::new(Limits {
+            max: 3,
+            max_per_worker: 2,
+            ..Limits::new()
+        });
+
+        let listener = Arc::new(Counter::default());
+
+        let worker1 = WorkerConnections::new(shared.clone());
+        let worker2 = WorkerConnections::new(shared.clone());
+
+        let g1 = worker1.try_acquire(None, &listener).ok().unwrap();
+        let g2 = worker1.try_acquire(None, &listener).ok().unwrap();
+        // per worker limit.
+        assert!(worker1.try_acquire(None, &listener).is_err());
+        assert!(!worker1.has_capacity());
+
+        let _g3 = worker2.try_acquire(None, &listener).ok().unwrap();
+        // global limit.
+        assert!(worker2.try_acquire(None, &listener).is_err());
+        assert_eq!(shared.active(), 3);
+
+        drop(g1);
+        assert_eq!(shared.active(), 2);
+        assert!(worker2.has_capacity());
+        let _g4 = worker2.try_acquire(None, &listener).ok().unwrap();
+
+        drop(g2);
+        assert_eq!(worker1.counter.active(), 0);
+        assert_eq!(worker1.counter.accepted(), 2);
+        assert_eq!(listener.active(), 2);
+        assert_eq!(listener.accepted(), 4);
+        assert_eq!(shared.workers().len(), 2);
+    }
+
+    #[test]
+    fn limit_per_ip() {
+        let worker = worker(Limits {
+            max_per_ip: 1,
+            ..Limits::new()
+        });
+        let listener = Arc::new(Counter::default());
+
+        let guard = worker.try_acquire(IP, &listener).ok().unwrap();
+        assert!(matches!(worker.try_acquire(IP, &listener), Err(Exceeded::Ip)));
+        // connection without ip is not counted.
+        let _guard = worker.try_acquire(None, &listener).ok().unwrap();
+        assert_eq!(worker.shared.active(), 2);
+
+        drop(guard);
+        assert!(worker.shared.per_ip.lock().unwrap().is_empty());
+        let _guard = worker.try_acquire(IP, &listener).ok().unwrap();
+    }
+
+    #[tokio::test]
+    async fn acquire_pause_timeout() {
+        let worker = worker(Limits {
+            max: 1,
+            pause_timeout: Duration::from_millis(50),
+            ..Limits::new()
+        });
+        let listener = Arc::new(Counter::default());
+
+        let guard = worker.try_acquire(None, &listener).ok().unwrap();
+
+        // accepted connection is closed when no capacity becomes available in time.
+        assert!(worker.acquire_ip(None, &listener).await.is_none());
+
+        // capacity released while waiting is taken.
+        let (guard2, _) = tokio::join!(worker.acquire_ip(None, &listener), async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            drop(guard);
+        });
+        assert!(guard2.is_some());
+        assert_eq!(listener.accepted(), 2);
+    }
+
+    #[tokio::test]
+    async fn in_flight() {
+        let worker = worker(Limits::new());
+        let listener = Arc::new(Counter::default());
+
+        let guard = worker.try_acquire(None, &listener).ok().unwrap();
+
+        guard
+            .in_flight()
+            .scope(async {
+                let _req1 = InFlight::guard().unwrap();
+                let req2 = InFlight::guard().unwrap();
+                assert_eq!(listener.in_flight(), 2);
+                assert_eq!(worker.counter.in_flight(), 2);
+                drop(req2);
+                assert_eq!(listener.in_flight(), 1);
+            })
+            .await;
+
+        assert_eq!(listener.in_flight(), 0);
+        assert_eq!(worker.counter.in_flight(), 0);
+    }
+}
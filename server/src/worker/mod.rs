@@ -1,3 +1,4 @@
+mod limit;
 mod shutdown;
 
 use core::{any::Any, sync::atomic::AtomicBool, time::Duration};
@@ -6,34 +7,124 @@ use std::{io, rc::Rc, sync::Arc, thread};
 
 use tokio::{task::JoinHandle, time::sleep};
 use tracing::{error, info};
-use xitca_io::net::{Listener, Stream};
+use xitca_io::net::Stream;
 use xitca_service::{ready::ReadyService, Service};
+use xitca_unsafe_collection::futures::{Select, SelectOutput};
+
+use crate::server::{Group, ListenerEntry};
+
+pub use self::limit::LimitPolicy;
+
+pub(crate) use self::limit::{Connections, Counter, Limits, WorkerConnections};
 
 use self::shutdown::ShutdownHandle;
 
 // erase Rc<S: ReadyService<_>> type and only use it for counting the reference counter of Rc.
 pub(crate) type ServiceAny = Rc<dyn Any>;
 
-pub(crate) fn start<S, Req>(listener: &Arc<Listener>, service: &Rc<S>) -> JoinHandle<()>
+/// start accepting connections from listeners of given group and keep them in sync with group
+/// until it's closed.
+pub(crate) fn serve<S, Req>(group: &Arc<Group>, service: &Rc<S>, connections: &Rc<WorkerConnections>) -> JoinHandle<()>
+where
+    S: ReadyService + Service<Req> + 'static,
+    S::Ready: 'static,
+    Req: TryFrom<Stream> + 'static,
+{
+    let group = group.clone();
+    let service = service.clone();
+    let connections = connections.clone();
+
+    tokio::task::spawn_local(async move {
+        let mut handles = Vec::<(usize, JoinHandle<()>)>::new();
+
+        loop {
+            let changed = group.changed();
+
+            if group.is_closed() {
+                break;
+            }
+
+            let listeners = group.listeners();
+
+            // listener removed from group. connections already accepted by it are not affected.
+            handles.retain(|(id, handle)| {
+                let keep = listeners.iter().any(|entry| entry.id == *id);
+                if !keep {
+                    handle.abort();
+                }
+                keep
+            });
+
+            for entry in listeners {
+                if !handles.iter().any(|(id, _)| *id == entry.id) {
+                    handles.push((entry.id, start(&entry, &group, &service, &connections)));
+                }
+            }
+
+            changed.await;
+        }
+
+        for (_, handle) in handles {
+            let _ = handle.await;
+        }
+    })
+}
+
+fn start<S, Req>(
+    entry: &ListenerEntry,
+    group: &Arc<Group>,
+    service: &Rc<S>,
+    connections: &Rc<WorkerConnections>,
+) -> JoinHandle<()>
 where
     S: ReadyService + Service<Req> + 'static,
     S::Ready: 'static,
     Req: TryFrom<Stream> + 'static,
 {
-    let listener = listener.clone();
+    let listener = entry.listener.clone();
+    let counter = entry.counter.clone();
+    let group = group.clone();
     let service = service.clone();
+    let connections = connections.clone();
 
     tokio::task::spawn_local(async move {
         loop {
             let ready = service.ready().await;
 
-            match listener.accept().await {
-                Ok(stream) => {
+            let accept = async {
+                loop {
+                    // connection limit is reached. stop accepting and leave pending connections in backlog.
+                    connections.ready().await;
+
+                    group.wait(|group| !group.is_paused()).await;
+
+                    // listener can be paused when waiting for connection. pause is prioritized so no
+                    // connection is accepted after it.
+                    match group.wait(Group::is_paused).select(listener.accept()).await {
+                        SelectOutput::A(_) => continue,
+                        SelectOutput::B(Ok(stream)) => match connections.acquire(&stream, &counter).await {
+                            Some(guard) => return Ok((stream, guard)),
+                            None => continue,
+                        },
+                        SelectOutput::B(Err(e)) => return Err(e),
+                    }
+                }
+            };
+
+            let res = match accept.select(group.wait(Group::is_closed)).await {
+                SelectOutput::A(res) => res,
+                SelectOutput::B(_) => return,
+            };
+
+            match res {
+                Ok((stream, guard)) => {
                     if let Ok(req) = TryFrom::try_from(stream) {
                         let service = service.clone();
                         tokio::task::spawn_local(async move {
-                            let _ = service.call(req).await;
+                            // requests of upper level protocol are counted by service through InFlight.
+                            let _ = guard.in_flight().scope(service.call(req)).await;
                             drop(ready);
+                            drop(guard);
                         });
                     }
                 }
@@ -95,7 +95,7 @@ where
     )
 }
 
-/// A specialized http/3 server
+/// A specialized http/3 server on top of [test_quic_server]
 pub fn test_h3_server<T, B, E>(service: T) -> Result<TestServerHandle, Error>
 where
     T: Service + Send + Sync + 'static,
@@ -104,6 +104,18 @@ where
     T::Error: error::Error + 'static,
     B: Stream<Item = Result<Bytes, E>> + 'static,
     E: fmt::Debug + 'static,
+{
+    test_quic_server::<_, (xitca_io::net::QuicStream, SocketAddr)>(service.enclosed(HttpServiceBuilder::h3()))
+}
+
+/// A general quic test server for any given service type that accept the connection from
+/// xitca-server. The server uses self signed certificate from examples and negotiate h3
+/// protocol with alpn.
+pub fn test_quic_server<T, Req>(service: T) -> Result<TestServerHandle, Error>
+where
+    T: Service + Send + Sync + 'static,
+    T::Response: ReadyService + Service<Req>,
+    Req: TryFrom<NetStream> + 'static,
 {
     let addr = std::net::UdpSocket::bind("127.0.0.1:0")?.local_addr()?;
 
@@ -131,7 +143,7 @@ where
         .worker_threads(1)
         .server_threads(1)
         .disable_signal()
-        .listen("test_server", listener, service.enclosed(HttpServiceBuilder::h3()))
+        .listen::<_, _, _, Req>("test_server", listener, service)
         .build();
 
     Ok(TestServerHandle { addr, handle })
@@ -1,22 +1,26 @@
-use futures_util::StreamExt;
+use futures_util::{stream, Stream, StreamExt};
 use std::{
+    collections::VecDeque,
     io::{Read, Write},
-    net::TcpStream,
-    time::Duration,
+    net::{SocketAddr, TcpStream},
+    sync::{Arc, Mutex},
+    task::Poll,
+    time::{Duration, Instant},
 };
 
 use xitca_client::Client;
 use xitca_http::{
-    body::{BoxBody, ResponseBody},
+    body::{BoxBody, Frame, ResponseBody},
     bytes::{Bytes, BytesMut},
     h1,
     http::{
         header::{self, HeaderValue, CONNECTION},
-        Method, Request, RequestExt, Response, Version,
+        Method, Request, RequestExt, Response, StatusCode, Version,
     },
+    HttpServiceBuilder,
 };
-use xitca_service::fn_service;
-use xitca_test::{test_h1_server, Error};
+use xitca_service::{fn_service, ServiceExt};
+use xitca_test::{test_h1_server, test_server, Error};
 
 #[tokio::test]
 async fn h1_get() -> Result<(), Error> {
@@ -120,6 +124,99 @@ async fn h1_post() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn h1_expect_continue() -> Result<(), Error> {
+    let mut handle = test_h1_server(fn_service(handle))?;
+
+    let server_url = format!("http://{}/expect", handle.ip_port_string());
+
+    let c = Client::new();
+
+    let mut res = c
+        .post(&server_url)
+        .version(Version::HTTP_11)
+        .expect_continue()
+        .text("Hello,World!")
+        .send()
+        .await?;
+    assert_eq!(res.status().as_u16(), 200);
+    assert!(!res.can_close_connection());
+    let body = res.string().await?;
+    assert_eq!(body, "Hello,World!");
+
+    // server reject request by it's head. body is not sent and connection is closed.
+    let server_url = format!("http://{}/unauthorized", handle.ip_port_string());
+    let mut res = c
+        .post(&server_url)
+        .version(Version::HTTP_11)
+        .expect_continue()
+        .text("Hello,World!")
+        .send()
+        .await?;
+    assert_eq!(res.status().as_u16(), 401);
+    assert!(res.can_close_connection());
+
+    handle.try_handle()?.stop(false);
+
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn h1_informational() -> Result<(), Error> {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let server_url = format!("http://{}/", listener.local_addr()?);
+
+    // raw server writes interim and final response head in one write. request body is not read
+    // before responding.
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut buf = Vec::new();
+            let mut chunk = [0; 1024];
+            while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = stream.read(&mut chunk).unwrap();
+                buf.extend_from_slice(&chunk[..n]);
+            }
+            stream
+                .write_all(
+                    b"HTTP/1.1 103 Early Hints\r\nlink: </style.css>; rel=preload\r\n\r\n\
+                      HTTP/1.1 200 OK\r\nconnection: close\r\ncontent-length: 2\r\n\r\nok",
+                )
+                .unwrap();
+            // keep connection open until client closes it.
+            while stream.read(&mut chunk).map(|n| n > 0).unwrap_or(false) {}
+        }
+    });
+
+    let c = Client::new();
+
+    for expect in [false, true] {
+        let hints = Arc::new(Mutex::new(Vec::new()));
+        let hints2 = hints.clone();
+
+        let mut req = c
+            .post(&server_url)
+            .version(Version::HTTP_11)
+            .on_informational(move |res| hints2.lock().unwrap().push(res.status()));
+        if expect {
+            req = req.expect_continue();
+        }
+
+        let now = Instant::now();
+        let res = req.text("Hello,World!").send().await?;
+        // final response must be decoded from the same read as interim response without waiting for
+        // more bytes from server or expect continue timeout.
+        assert!(now.elapsed() < Duration::from_secs(1));
+        assert_eq!(res.status().as_u16(), 200);
+        assert_eq!(res.string().await?, "ok");
+        assert_eq!(*hints.lock().unwrap(), [StatusCode::EARLY_HINTS]);
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn h1_drop_body_read() -> Result<(), Error> {
     let mut handle = test_h1_server(fn_service(handle))?;
@@ -228,6 +325,65 @@ async fn h1_request_too_large() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn h1_trailers() -> Result<(), Error> {
+    let service = fn_service(echo_trailers).enclosed(HttpServiceBuilder::h1());
+    let mut handle = test_server::<_, (xitca_io::net::TcpStream, SocketAddr)>(service)?;
+
+    let mut stream = TcpStream::connect(handle.addr())?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+    // host is not allowed in trailers and must be dropped from request trailers.
+    stream.write_all(
+        b"POST / HTTP/1.1\r\ntransfer-encoding: chunked\r\n\r\n3\r\nfoo\r\n0\r\nchecksum: 996\r\nhost: evil\r\n\r\n",
+    )?;
+
+    let mut buf = Vec::new();
+    let mut chunk = [0; 128];
+    while !buf.ends_with(b"0\r\nchecksum: 996\r\n\r\n") {
+        let n = stream.read(&mut chunk)?;
+        assert_ne!(
+            n,
+            0,
+            "connection closed before response trailers: {:?}",
+            String::from_utf8_lossy(&buf)
+        );
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    assert!(buf.starts_with(b"HTTP/1.1 200 OK\r\n"));
+    assert!(buf.ends_with(b"\r\n\r\n3\r\nfoo\r\n0\r\nchecksum: 996\r\n\r\n"));
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+// echo request body and request trailers as response trailers.
+async fn echo_trailers(
+    req: Request<RequestExt<h1::RequestBody>>,
+) -> Result<Response<impl Stream<Item = Result<Frame, Error>>>, Error> {
+    let (parts, ext) = req.into_parts();
+    let (_, mut body) = ext.replace_body(());
+
+    let mut buf = BytesMut::new();
+    while let Some(bytes) = body.next().await {
+        buf.extend_from_slice(&bytes?);
+    }
+
+    assert!(parts.headers.get("checksum").is_none());
+    let mut trailers = body.take_trailers().unwrap();
+    // content-length is not allowed in trailers and must be dropped from response trailers.
+    trailers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("3"));
+
+    // a stream body without size hint is encoded with transfer-encoding: chunked.
+    let mut frames = VecDeque::from([Frame::Data(buf.freeze()), Frame::Trailers(trailers)]);
+    let body = stream::poll_fn(move |_| Poll::Ready(frames.pop_front().map(Ok)));
+
+    Ok(Response::new(body))
+}
+
 #[tokio::test]
 async fn h1_keepalive() -> Result<(), Error> {
     let mut handle = test_h1_server(fn_service(handle))?;
@@ -285,6 +441,21 @@ async fn handle(req: Request<RequestExt<h1::RequestBody>>) -> Result<Response<Re
 
             Ok(res)
         }
+        // collect request body before sending response head.
+        (&Method::POST, "/expect") => {
+            let mut body = req.into_body();
+            let mut buf = BytesMut::new();
+            while let Some(bytes) = body.next().await {
+                buf.extend_from_slice(&bytes?);
+            }
+            Ok(Response::new(buf.freeze().into()))
+        }
+        // reject request without reading request body.
+        (&Method::POST, "/unauthorized") => {
+            let mut res = Response::new(Bytes::new().into());
+            *res.status_mut() = StatusCode::UNAUTHORIZED;
+            Ok(res)
+        }
         // drop request body. server should close connection afterwards.
         (&Method::POST, "/drop_body") => Ok(Response::new(Bytes::new().into())),
         // partial read request body. server should close connection afterwards.
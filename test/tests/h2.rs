@@ -1,15 +1,27 @@
-use std::time::{Duration, Instant};
+use std::{
+    collections::VecDeque,
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    task::Poll,
+    time::{Duration, Instant},
+};
 
-use futures_util::StreamExt;
+use futures_util::{stream, StreamExt};
 use xitca_client::Client;
 use xitca_http::{
-    body::ResponseBody,
+    body::{Frame, ResponseBody},
     bytes::{Bytes, BytesMut},
+    config::HttpServiceConfig,
     h2,
-    http::{header, Method, Request, RequestExt, Response, Version},
+    http::{
+        header::{self, HeaderMap, HeaderValue},
+        Method, Request, RequestExt, Response, Version,
+    },
+    HttpServiceBuilder, RequestBody,
 };
-use xitca_service::fn_service;
-use xitca_test::{test_h2_server, Error};
+use xitca_io::net::Stream;
+use xitca_service::{fn_service, ServiceExt};
+use xitca_test::{test_h2_server, test_server, Error};
 
 #[tokio::test]
 async fn h2_get() -> Result<(), Error> {
@@ -34,6 +46,208 @@ async fn h2_get() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn h2c_prior_knowledge() -> Result<(), Error> {
+    let service = fn_service(|req: Request<RequestExt<RequestBody>>| async move {
+        let version = format!("{:?}", req.version());
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::from(version).into()))
+    })
+    .enclosed(HttpServiceBuilder::new().config(HttpServiceConfig::new().peek_protocol()));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let server_url = format!("http://{}/", handle.ip_port_string());
+
+    let c = Client::new();
+
+    // plain text listener serves both http/1 and http/2 prior knowledge.
+    for version in [Version::HTTP_2, Version::HTTP_11, Version::HTTP_2] {
+        let res = c.get(&server_url).version(version).send().await?;
+        assert_eq!(res.status().as_u16(), 200);
+        let body = res.string().await?;
+        assert_eq!(format!("{version:?}"), body);
+    }
+
+    handle.try_handle()?.stop(false);
+
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn h2c_upgrade() -> Result<(), Error> {
+    let service = fn_service(|req: Request<RequestExt<RequestBody>>| async move {
+        let version = format!("{:?}", req.version());
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::from(version).into()))
+    })
+    .enclosed(HttpServiceBuilder::new().config(HttpServiceConfig::new().h2c_upgrade()));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let mut stream = TcpStream::connect(handle.addr())?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+    stream.write_all(
+        b"GET / HTTP/1.1\r\n\
+        host: localhost\r\n\
+        connection: upgrade, http2-settings\r\n\
+        upgrade: h2c\r\n\
+        http2-settings: AAMAAABkAAQAoAAAAAIAAAAA\r\n\
+        \r\n",
+    )?;
+
+    let mut buf = Vec::new();
+    let mut chunk = [0; 1024];
+
+    let head_len = loop {
+        let n = stream.read(&mut chunk)?;
+        assert_ne!(n, 0, "connection closed before upgrade");
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(idx) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break idx + 4;
+        }
+    };
+    assert!(buf.starts_with(b"HTTP/1.1 101 Switching Protocols\r\n"));
+    buf.drain(..head_len);
+
+    // connection preface and an empty SETTINGS frame.
+    stream.write_all(b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n\0\0\0\x04\0\0\0\0\0")?;
+
+    // upgrade request is responded on stream 1 with http/2.
+    let mut headers = false;
+    loop {
+        while buf.len() >= 9 {
+            let len = u32::from_be_bytes([0, buf[0], buf[1], buf[2]]) as usize;
+            if buf.len() < 9 + len {
+                break;
+            }
+            let ty = buf[3];
+            let stream_id = u32::from_be_bytes([buf[5], buf[6], buf[7], buf[8]]) & 0x7fff_ffff;
+            let payload = buf[9..9 + len].to_vec();
+            buf.drain(..9 + len);
+
+            match ty {
+                // HEADERS
+                0x1 => {
+                    assert_eq!(stream_id, 1);
+                    headers = true;
+                }
+                // DATA
+                0x0 => {
+                    assert_eq!(stream_id, 1);
+                    assert!(headers);
+                    assert_eq!(payload, b"HTTP/2.0");
+
+                    handle.try_handle()?.stop(false);
+                    handle.await?;
+
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
+        let n = stream.read(&mut chunk)?;
+        assert_ne!(n, 0, "connection closed before response");
+        buf.extend_from_slice(&chunk[..n]);
+    }
+}
+
+#[tokio::test]
+async fn h2c_upgrade_malformed_settings() -> Result<(), Error> {
+    let service = fn_service(|req: Request<RequestExt<RequestBody>>| async move {
+        let version = format!("{:?}", req.version());
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::from(version).into()))
+    })
+    .enclosed(HttpServiceBuilder::new().config(HttpServiceConfig::new().h2c_upgrade()));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let mut stream = TcpStream::connect(handle.addr())?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+    // HTTP2-Settings must be base64url encoded SETTINGS payload.
+    stream.write_all(
+        b"GET / HTTP/1.1\r\n\
+        host: localhost\r\n\
+        connection: upgrade, http2-settings\r\n\
+        upgrade: h2c\r\n\
+        http2-settings: not+base64url\r\n\
+        \r\n",
+    )?;
+
+    let mut buf = Vec::new();
+    stream.read_to_end(&mut buf)?;
+    assert!(buf.starts_with(b"HTTP/1.1 400 Bad Request\r\n"));
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn h2_settings() -> Result<(), Error> {
+    let config = HttpServiceConfig::new()
+        .peek_protocol()
+        .h2_max_concurrent_streams(7)
+        .h2_initial_window_size(1 << 20)
+        .h2_max_frame_size(1 << 15);
+
+    let service = fn_service(|_: Request<RequestExt<RequestBody>>| async move {
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::new().into()))
+    })
+    .enclosed(HttpServiceBuilder::new().config(config));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let mut stream = TcpStream::connect(handle.addr())?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+    // connection preface and an empty SETTINGS frame.
+    stream.write_all(b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n\0\0\0\x04\0\0\0\0\0")?;
+
+    let mut buf = Vec::new();
+    let mut chunk = [0; 1024];
+
+    // server SETTINGS frame is the first frame it sends.
+    let payload = loop {
+        let n = stream.read(&mut chunk)?;
+        assert_ne!(n, 0, "connection closed before settings");
+        buf.extend_from_slice(&chunk[..n]);
+        if buf.len() >= 9 {
+            let len = u32::from_be_bytes([0, buf[0], buf[1], buf[2]]) as usize;
+            if buf.len() >= 9 + len {
+                assert_eq!(buf[3], 0x4);
+                break buf[9..9 + len].to_vec();
+            }
+        }
+    };
+
+    let settings = payload
+        .chunks(6)
+        .map(|s| {
+            (
+                u16::from_be_bytes([s[0], s[1]]),
+                u32::from_be_bytes([s[2], s[3], s[4], s[5]]),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // SETTINGS_MAX_CONCURRENT_STREAMS
+    assert!(settings.contains(&(0x3, 7)));
+    // SETTINGS_INITIAL_WINDOW_SIZE
+    assert!(settings.contains(&(0x4, 1 << 20)));
+    // SETTINGS_MAX_FRAME_SIZE
+    assert!(settings.contains(&(0x5, 1 << 15)));
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn h2_no_host_header() -> Result<(), Error> {
     let mut handle = test_h2_server(fn_service(handle))?;
@@ -173,6 +387,100 @@ async fn h2_keepalive() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn h2_trailers() -> Result<(), Error> {
+    let service = fn_service(echo_trailers).enclosed(HttpServiceBuilder::h2());
+    let mut handle = test_server::<_, (xitca_io::net::TcpStream, SocketAddr)>(service)?;
+
+    let stream = tokio::net::TcpStream::connect(handle.addr()).await?;
+    let (mut client, conn) = ::h2::client::handshake(stream).await?;
+    tokio::spawn(conn);
+
+    let req = Request::post(format!("http://{}/", handle.ip_port_string())).body(())?;
+    let (res, mut tx) = client.send_request(req, false)?;
+
+    let mut trailers = HeaderMap::new();
+    trailers.insert("checksum", HeaderValue::from_static("996"));
+    // authorization is not allowed in trailers and must be dropped from request trailers.
+    trailers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer 996"));
+
+    tx.send_data(Bytes::from_static(b"foo"), false)?;
+    tx.send_trailers(trailers)?;
+
+    let mut body = res.await?.into_body();
+
+    let mut buf = BytesMut::new();
+    while let Some(bytes) = body.data().await {
+        let bytes = bytes?;
+        body.flow_control().release_capacity(bytes.len())?;
+        buf.extend_from_slice(&bytes);
+    }
+    assert_eq!(buf, b"foo"[..]);
+
+    let trailers = body.trailers().await?.unwrap();
+    assert_eq!(trailers.len(), 1);
+    assert_eq!(trailers.get("checksum").unwrap(), "996");
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn h2_client_goaway() -> Result<(), Error> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let server_url = format!("http://{}/", listener.local_addr()?);
+
+    // real h2 server that starts graceful shutdown of every connection after receiving its first
+    // request and responds with the index of connection.
+    tokio::spawn(async move {
+        for idx in 0usize.. {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let mut conn = ::h2::server::handshake(stream).await.unwrap();
+                let (_, mut tx) = conn.accept().await.unwrap().unwrap();
+                conn.graceful_shutdown();
+                let mut stream = tx.send_response(Response::new(()), false).unwrap();
+                stream.send_data(Bytes::from(idx.to_string()), true).unwrap();
+                while conn.accept().await.is_some() {}
+            });
+        }
+    });
+
+    let c = Client::new();
+
+    for idx in 0..3 {
+        let res = c.get(&server_url).version(Version::HTTP_2).send().await?;
+        assert_eq!(res.status().as_u16(), 200);
+        // connection received GOAWAY must not be reused for new request.
+        assert_eq!(res.string().await?, idx.to_string());
+    }
+
+    Ok(())
+}
+
+// echo request body and request trailers as response trailers.
+async fn echo_trailers(
+    req: Request<RequestExt<h2::RequestBody>>,
+) -> Result<Response<impl futures_util::Stream<Item = Result<Frame, Error>>>, Error> {
+    let (_, mut body) = req.into_body().replace_body(());
+
+    let mut buf = BytesMut::new();
+    while let Some(bytes) = body.next().await {
+        buf.extend_from_slice(&bytes?);
+    }
+
+    let mut trailers = body.take_trailers().unwrap();
+    // content-length is not allowed in trailers and must be dropped from response trailers.
+    trailers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("3"));
+
+    let mut frames = VecDeque::from([Frame::Data(buf.freeze()), Frame::Trailers(trailers)]);
+    let body = stream::poll_fn(move |_| Poll::Ready(frames.pop_front().map(Ok)));
+
+    Ok(Response::new(body))
+}
+
 async fn handle(req: Request<RequestExt<h2::RequestBody>>) -> Result<Response<ResponseBody>, Error> {
     // Some yield for testing h2 dispatcher's concurrent future handling.
     tokio::task::yield_now().await;
@@ -1,13 +1,29 @@
-use futures_util::StreamExt;
+use std::{collections::VecDeque, future::poll_fn, net::SocketAddr, sync::Arc, task::Poll};
+
+use futures_util::{stream, Stream, StreamExt};
+use h3_quinn::quinn::{
+    self,
+    rustls::{
+        self,
+        client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+        pki_types::{CertificateDer, ServerName, UnixTime},
+        DigitallySignedStruct, SignatureScheme,
+    },
+};
 use xitca_client::Client;
 use xitca_http::{
-    body::ResponseBody,
-    bytes::{Bytes, BytesMut},
+    body::{Frame, ResponseBody},
+    bytes::{Buf, Bytes, BytesMut},
     h3,
-    http::{header, Method, Request, RequestExt, Response, Version},
+    http::{
+        header::{self, HeaderMap, HeaderValue},
+        Method, Request, RequestExt, Response, Version,
+    },
+    HttpServiceBuilder,
 };
-use xitca_service::fn_service;
-use xitca_test::{test_h3_server, Error};
+use xitca_io::net::QuicStream;
+use xitca_service::{fn_service, ServiceExt};
+use xitca_test::{test_h3_server, test_quic_server, Error};
 
 #[tokio::test]
 async fn h3_get() -> Result<(), Error> {
@@ -82,6 +98,118 @@ async fn h3_post() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn h3_trailers() -> Result<(), Error> {
+    let service = fn_service(echo_trailers).enclosed(HttpServiceBuilder::h3());
+    let mut handle = test_quic_server::<_, (QuicStream, SocketAddr)>(service)?;
+
+    let mut config = rustls::ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(SkipServerVerification))
+        .with_no_client_auth();
+    config.alpn_protocols = vec![b"h3".to_vec()];
+    let config = quinn::crypto::rustls::QuicClientConfig::try_from(config)?;
+
+    let mut endpoint = quinn::Endpoint::client("127.0.0.1:0".parse()?)?;
+    endpoint.set_default_client_config(quinn::ClientConfig::new(Arc::new(config)));
+
+    let conn = endpoint.connect(handle.addr(), "localhost")?.await?;
+    let (mut driver, mut client) = ::h3::client::new(h3_quinn::Connection::new(conn)).await?;
+    tokio::spawn(async move { poll_fn(|cx| driver.poll_close(cx)).await });
+
+    let req = Request::post(format!("https://localhost:{}/", handle.addr().port())).body(())?;
+    let mut stream = client.send_request(req).await?;
+
+    let mut trailers = HeaderMap::new();
+    trailers.insert("checksum", HeaderValue::from_static("996"));
+    // authorization is not allowed in trailers and must be dropped from request trailers.
+    trailers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer 996"));
+
+    stream.send_data(Bytes::from_static(b"foo")).await?;
+    stream.send_trailers(trailers).await?;
+    stream.finish().await?;
+
+    stream.recv_response().await?;
+
+    let mut buf = BytesMut::new();
+    while let Some(bytes) = stream.recv_data().await? {
+        buf.extend_from_slice(bytes.chunk());
+    }
+    assert_eq!(buf, b"foo"[..]);
+
+    let trailers = stream.recv_trailers().await?.unwrap();
+    assert_eq!(trailers.len(), 1);
+    assert_eq!(trailers.get("checksum").unwrap(), "996");
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+// echo request body and request trailers as response trailers.
+async fn echo_trailers(
+    req: Request<RequestExt<h3::RequestBody>>,
+) -> Result<Response<impl Stream<Item = Result<Frame, Error>>>, Error> {
+    let (_, mut body) = req.into_body().replace_body(());
+
+    let mut buf = BytesMut::new();
+    while let Some(bytes) = body.next().await {
+        buf.extend_from_slice(&bytes?);
+    }
+
+    let mut trailers = body.take_trailers().unwrap();
+    // content-length is not allowed in trailers and must be dropped from response trailers.
+    trailers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("3"));
+
+    let mut frames = VecDeque::from([Frame::Data(buf.freeze()), Frame::Trailers(trailers)]);
+    let body = stream::poll_fn(move |_| Poll::Ready(frames.pop_front().map(Ok)));
+
+    Ok(Response::new(body))
+}
+
+// test server uses certificate signed by an unknown ca.
+#[derive(Debug)]
+struct SkipServerVerification;
+
+impl ServerCertVerifier for SkipServerVerification {
+    fn verify_server_cert(
+        &self,
+        _: &CertificateDer<'_>,
+        _: &[CertificateDer<'_>],
+        _: &ServerName<'_>,
+        _: &[u8],
+        _: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        _: &[u8],
+        _: &CertificateDer<'_>,
+        _: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        Ok(HandshakeSignatureValid::assertion())
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        _: &[u8],
+        _: &CertificateDer<'_>,
+        _: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        Ok(HandshakeSignatureValid::assertion())
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        rustls::crypto::ring::default_provider()
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
 async fn handle(req: Request<RequestExt<h3::RequestBody>>) -> Result<Response<ResponseBody>, Error> {
     // Some yield for testing h3 dispatcher's concurrent future handling.
     tokio::task::yield_now().await;
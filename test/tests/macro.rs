@@ -159,3 +159,117 @@ async fn web_handler() {
         .at_typed(test3)
         .finish();
 }
+
+async fn group_middleware<S, C, Err>(s: &S, ctx: WebContext<'_, C>) -> Result<WebResponse, Err>
+where
+    S: for<'r> Service<WebContext<'r, C>, Response = WebResponse, Error = Err>,
+{
+    s.call(ctx).await.map(|mut res| {
+        let len = res.headers().get_all("x-group").iter().count();
+        res.headers_mut()
+            .append("x-group", xitca_web::http::HeaderValue::from(len));
+        res
+    })
+}
+
+#[xitca_web::codegen::route_group("/api", enclosed_fn = group_middleware)]
+mod api {
+    use xitca_web::codegen::route;
+
+    #[route("/users", method = [get, post])]
+    pub(super) async fn users() -> &'static str {
+        "users"
+    }
+
+    pub(super) mod nest {
+        use xitca_web::codegen::route;
+
+        #[route("/nest", method = get)]
+        pub(crate) async fn nest() -> &'static str {
+            "nest"
+        }
+    }
+
+    #[xitca_web::codegen::route_group("/v2", enclosed_fn = super::group_middleware)]
+    pub(super) mod v2 {
+        use xitca_web::codegen::route;
+
+        #[route("/users", method = get)]
+        pub(crate) async fn users() -> &'static str {
+            "users"
+        }
+    }
+}
+
+#[tokio::test]
+async fn web_route_group() {
+    use xitca_web::http::{Method, Request};
+
+    let service = xitca_web::App::new()
+        .at_typed(api::users)
+        .at_typed(api::nest::nest)
+        .at_typed(api::v2::users)
+        .finish()
+        .call(())
+        .await
+        .unwrap();
+
+    let call = |method: Method, uri: &'static str| {
+        let req = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Default::default())
+            .unwrap();
+        Service::call(&service, req)
+    };
+
+    for method in [Method::GET, Method::POST] {
+        let res = call(method, "/api/users").await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get_all("x-group").iter().count(), 1);
+    }
+
+    let res = call(Method::PUT, "/api/users").await.unwrap();
+    assert_eq!(res.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+    let res = call(Method::GET, "/api/nest").await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get_all("x-group").iter().count(), 1);
+
+    // nested group is enclosed by both groups.
+    let res = call(Method::GET, "/api/v2/users").await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get_all("x-group").iter().count(), 2);
+
+    let res = call(Method::GET, "/users").await.unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn web_app_routes() {
+    use xitca_web::http::{Method, Request};
+
+    let app = xitca_web::App::new()
+        .at_typed(api::users)
+        .at_typed(api::nest::nest)
+        .at_typed(api::v2::users);
+
+    let routes = app.routes().iter().map(|r| (r.path(), r.methods())).collect::<Vec<_>>();
+    assert_eq!(
+        routes,
+        [
+            ("/api/users", &[Method::GET, Method::POST][..]),
+            ("/api/nest", &[Method::GET][..]),
+            ("/api/v2/users", &[Method::GET][..]),
+        ]
+    );
+
+    let paths = routes.iter().map(|(path, _)| path.to_string()).collect::<Vec<_>>();
+
+    // listed routes are served.
+    let service = app.finish().call(()).await.unwrap();
+    for path in paths {
+        let req = Request::builder().uri(path).body(Default::default()).unwrap();
+        assert_eq!(service.call(req).await.unwrap().status(), StatusCode::OK);
+    }
+}
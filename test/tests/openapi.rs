@@ -0,0 +1,171 @@
+use xitca_service::Service;
+use xitca_web::{
+    codegen::route,
+    error::Error,
+    handler::{json::Json, params::Params, path::PathRef, query::Query},
+    http::{header::CONTENT_TYPE, Method, Request, StatusCode},
+    openapi::{Info, JsonSchema, Operation, OperationOutput, ParameterIn, SchemaGenerator},
+    test::collect_string_body,
+    App, WebContext,
+};
+
+#[derive(serde::Deserialize, serde::Serialize, JsonSchema)]
+#[schemars(crate = "xitca_web::openapi::schemars")]
+struct User {
+    id: u64,
+    name: String,
+}
+
+#[derive(serde::Deserialize, JsonSchema)]
+#[schemars(crate = "xitca_web::openapi::schemars")]
+struct Page {
+    page: u32,
+    size: Option<u32>,
+}
+
+#[derive(serde::Deserialize, JsonSchema)]
+#[schemars(crate = "xitca_web::openapi::schemars")]
+struct UserPath {
+    id: u64,
+}
+
+#[route("/users", method = get)]
+async fn users(Query(page): Query<Page>) -> Json<Vec<User>> {
+    let _ = (page.page, page.size);
+    Json(Vec::new())
+}
+
+#[route("/users/:id", method = [put, patch])]
+async fn user_update(Params(path): Params<UserPath>, Json(user): Json<User>) -> Result<Json<User>, Error> {
+    assert_eq!(path.id, user.id);
+    Ok(Json(user))
+}
+
+#[route("/files/*path", method = get)]
+async fn file(_: &WebContext<'_>) -> &'static str {
+    "file"
+}
+
+#[route("/health", method = get)]
+async fn health() -> &'static str {
+    "ok"
+}
+
+#[route("/posts/*path", method = get)]
+async fn post(PathRef(path): PathRef<'_>) -> String {
+    path.to_owned()
+}
+
+#[tokio::test]
+async fn openapi() {
+    let app = App::new()
+        .at_openapi_typed(users)
+        .at_openapi_typed(user_update)
+        .at_openapi_typed(file)
+        // typed route inserted without openapi is not described.
+        .at_typed(health);
+
+    let doc = app.openapi(Info::new("users", "1.0.0"));
+
+    assert_eq!(doc.openapi, "3.1.0");
+    assert_eq!(doc.paths.len(), 3);
+
+    let op = &doc.paths["/users"]["get"];
+    assert_eq!(op.operation_id.as_deref(), Some("users"));
+    assert_eq!(op.parameters.len(), 2);
+    let page = op.parameters.iter().find(|p| p.name == "page").unwrap();
+    assert_eq!(page.location, ParameterIn::Query);
+    assert!(page.required);
+    assert!(!op.parameters.iter().find(|p| p.name == "size").unwrap().required);
+    assert!(op.request_body.is_none());
+    assert!(op.responses["200"].content.contains_key("application/json"));
+
+    let item = &doc.paths["/users/{id}"];
+    assert_eq!(item.len(), 2);
+    let op = &item["put"];
+    assert_eq!(op.operation_id.as_deref(), Some("user_update_put"));
+    assert_eq!(item["patch"].operation_id.as_deref(), Some("user_update_patch"));
+    assert_eq!(op.parameters.len(), 1);
+    assert_eq!(op.parameters[0].location, ParameterIn::Path);
+    assert_eq!(op.parameters[0].schema.get("type").unwrap(), "integer");
+    assert!(op
+        .request_body
+        .as_ref()
+        .unwrap()
+        .content
+        .contains_key("application/json"));
+
+    // handler argument without description is ignored. catch all is described by path template.
+    let op = &doc.paths["/files/{path}"]["get"];
+    assert_eq!(op.parameters[0].name, "path");
+    assert!(op.responses["200"].content.contains_key("text/plain; charset=utf-8"));
+
+    assert!(doc.components.schemas.contains_key("User"));
+
+    let service = app
+        .at_openapi("/openapi.json", Info::new("users", "1.0.0"))
+        .finish()
+        .call(())
+        .await
+        .unwrap();
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/openapi.json")
+        .body(Default::default())
+        .unwrap();
+    let res = service.call(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+
+    let body = collect_string_body(res.into_body()).await.unwrap();
+    assert!(body.contains(r#""openapi":"3.1.0""#));
+    assert!(body.contains(r##""$ref":"#/components/schemas/User""##));
+    assert!(!body.contains("/health"));
+
+    let req = Request::builder().uri("/health").body(Default::default()).unwrap();
+    let res = service.call(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn openapi_routes() {
+    // document is generated from route table when App is built. routes of nested App and routes
+    // inserted after at_openapi are described.
+    let app = App::new()
+        .at_openapi("/openapi.json", Info::new("users", "1.0.0"))
+        .at("/v1", App::new().at_openapi_typed(users).at_openapi_typed(post))
+        .at_openapi_typed(file);
+
+    let doc = app.openapi(Info::new("users", "1.0.0"));
+    assert_eq!(doc.paths.len(), 3);
+    assert!(doc.paths["/v1/users"].contains_key("get"));
+    assert!(doc.paths["/files/{path}"].contains_key("get"));
+
+    // request path extractor is described by path template.
+    let op = &doc.paths["/v1/posts/{path}"]["get"];
+    assert_eq!(op.parameters.len(), 1);
+    assert_eq!(op.parameters[0].name, "path");
+    assert!(op.request_body.is_none());
+
+    let service = app.finish().call(()).await.unwrap();
+
+    let req = Request::builder()
+        .uri("/openapi.json")
+        .body(Default::default())
+        .unwrap();
+    let res = service.call(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = collect_string_body(res.into_body()).await.unwrap();
+    assert!(body.contains(r#""/v1/users""#));
+    assert!(body.contains(r#""/v1/posts/{path}""#));
+    assert!(body.contains(r#""/files/{path}""#));
+}
+
+#[test]
+fn openapi_json_limit() {
+    let mut op = Operation::default();
+    <Json<User, 64>>::operation_output(&mut op, &mut SchemaGenerator::default());
+    assert!(op.responses["200"].content.contains_key("application/json"));
+}
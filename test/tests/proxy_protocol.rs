@@ -0,0 +1,146 @@
+use std::{
+    io::{Read, Write},
+    net::SocketAddr,
+};
+
+use xitca_http::{
+    body::ResponseBody,
+    bytes::Bytes,
+    config::HttpServiceConfig,
+    h1, h2,
+    http::{Request, RequestExt, Response},
+    HttpServiceBuilder, RequestBody,
+};
+use xitca_io::net::{Stream, TcpStream};
+use xitca_service::{fn_service, ServiceExt};
+use xitca_test::{test_server, Error};
+
+#[tokio::test]
+async fn proxy_protocol() -> Result<(), Error> {
+    let service = fn_service(proxy_info::<RequestBody>)
+        .enclosed(HttpServiceBuilder::new().config(HttpServiceConfig::new().proxy_protocol()));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let send = |header: &[u8]| send(handle.addr(), header);
+
+    let res = send(b"PROXY TCP4 192.168.0.1 192.168.0.11 56324 443\r\n").unwrap();
+    assert!(res.ends_with("v1 192.168.0.1:56324 "), "{res}");
+
+    let res = send(&v2_header()).unwrap();
+    assert!(res.ends_with("v2 10.0.0.1:8080 example.com"), "{res}");
+
+    // connection without PROXY protocol header is closed(or reset) without response.
+    match send(b"") {
+        Ok(res) => assert!(res.is_empty(), "{res}"),
+        Err(e) => assert_eq!(e.kind(), std::io::ErrorKind::ConnectionReset),
+    }
+
+    handle.try_handle()?.stop(false);
+
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn proxy_protocol_h1() -> Result<(), Error> {
+    let service = fn_service(proxy_info::<h1::RequestBody>)
+        .enclosed(HttpServiceBuilder::h1().config(HttpServiceConfig::new().proxy_protocol()));
+
+    let mut handle = test_server::<_, (TcpStream, SocketAddr)>(service)?;
+
+    let res = send(handle.addr(), b"PROXY TCP6 ::1 ::2 56324 443\r\n").unwrap();
+    assert!(res.ends_with("v1 [::1]:56324 "), "{res}");
+
+    let res = send(handle.addr(), &v2_header()).unwrap();
+    assert!(res.ends_with("v2 10.0.0.1:8080 example.com"), "{res}");
+
+    match send(handle.addr(), b"") {
+        Ok(res) => assert!(res.is_empty(), "{res}"),
+        Err(e) => assert_eq!(e.kind(), std::io::ErrorKind::ConnectionReset),
+    }
+
+    handle.try_handle()?.stop(false);
+
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn proxy_protocol_h2() -> Result<(), Error> {
+    let service = fn_service(proxy_info::<h2::RequestBody>)
+        .enclosed(HttpServiceBuilder::h2().config(HttpServiceConfig::new().proxy_protocol()));
+
+    let mut handle = test_server::<_, (TcpStream, SocketAddr)>(service)?;
+
+    for (header, expected) in [
+        (
+            &b"PROXY TCP4 192.168.0.1 192.168.0.11 56324 443\r\n"[..],
+            "v1 192.168.0.1:56324 ",
+        ),
+        (&v2_header()[..], "v2 10.0.0.1:8080 example.com"),
+    ] {
+        let mut stream = std::net::TcpStream::connect(handle.addr())?;
+        stream.write_all(header)?;
+        stream.set_nonblocking(true)?;
+        let stream = tokio::net::TcpStream::from_std(stream)?;
+
+        let (mut client, conn) = ::h2::client::handshake(stream).await?;
+        tokio::spawn(conn);
+
+        let req = Request::get(format!("http://{}/", handle.ip_port_string())).body(())?;
+        let (res, _) = client.send_request(req, true)?;
+        let mut body = res.await?.into_body();
+
+        let mut buf = Vec::new();
+        while let Some(bytes) = body.data().await {
+            let bytes = bytes?;
+            body.flow_control().release_capacity(bytes.len())?;
+            buf.extend_from_slice(&bytes);
+        }
+        assert_eq!(buf, expected.as_bytes());
+    }
+
+    handle.try_handle()?.stop(false);
+
+    handle.await?;
+
+    Ok(())
+}
+
+async fn proxy_info<B>(req: Request<RequestExt<B>>) -> Result<Response<ResponseBody>, Error> {
+    let ext = req.body();
+    let header = ext.proxy_header().unwrap();
+    // PP2_TYPE_AUTHORITY
+    let authority = header
+        .tlv(0x02)
+        .map(|v| String::from_utf8_lossy(v).into_owned())
+        .unwrap_or_default();
+    let body = format!("v{} {} {}", header.version(), ext.socket_addr(), authority);
+    Ok(Response::new(Bytes::from(body).into()))
+}
+
+// send PROXY protocol header followed by a Http/1 request and read the whole response.
+fn send(addr: SocketAddr, header: &[u8]) -> std::io::Result<String> {
+    const REQ: &[u8] = b"GET / HTTP/1.1\r\nconnection: close\r\n\r\n";
+
+    let mut stream = std::net::TcpStream::connect(addr)?;
+    stream.write_all(&[header, REQ].concat())?;
+    let mut res = String::new();
+    stream.read_to_string(&mut res)?;
+    Ok(res)
+}
+
+// v2 header of PROXY command over TCP4 with a PP2_TYPE_AUTHORITY TLV.
+fn v2_header() -> Vec<u8> {
+    let mut v2 = b"\r\n\r\n\0\r\nQUIT\n\x21\x11".to_vec();
+    v2.extend_from_slice(&(12u16 + 3 + 11).to_be_bytes());
+    v2.extend_from_slice(&[10, 0, 0, 1, 10, 0, 0, 2]);
+    v2.extend_from_slice(&8080u16.to_be_bytes());
+    v2.extend_from_slice(&443u16.to_be_bytes());
+    v2.extend_from_slice(&[0x02, 0, 11]);
+    v2.extend_from_slice(b"example.com");
+    v2
+}
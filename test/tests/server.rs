@@ -0,0 +1,153 @@
+use std::{
+    io::{self, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+    time::Duration,
+};
+
+use tokio::sync::Notify;
+
+use xitca_http::{
+    body::ResponseBody,
+    h1,
+    http::{Request, RequestExt, Response},
+};
+use xitca_service::fn_service;
+use xitca_test::{test_h1_server, Error};
+
+const REQ: &[u8] = b"GET / HTTP/1.1\r\nconnection: close\r\n\r\n";
+
+fn send(stream: &mut TcpStream) -> io::Result<String> {
+    stream.write_all(REQ)?;
+    let mut res = String::new();
+    stream.read_to_string(&mut res)?;
+    Ok(res)
+}
+
+#[tokio::test]
+async fn server_handle() -> Result<(), Error> {
+    let mut handle = test_h1_server(fn_service(|_: Request<RequestExt<h1::RequestBody>>| async {
+        Ok::<Response<ResponseBody>, Error>(Response::new(ResponseBody::empty()))
+    }))?;
+
+    let addr = handle.addr();
+    let server = handle.try_handle()?;
+
+    let res = send(&mut TcpStream::connect(addr)?)?;
+    assert!(res.starts_with("HTTP/1.1 200 OK"), "{res}");
+
+    let stats = server.stats();
+    assert_eq!(stats.listeners.len(), 1);
+    assert_eq!(stats.listeners[0].name, "test_server");
+    assert_eq!(stats.listeners[0].local_addr, Some(addr));
+    assert_eq!(stats.listeners[0].accepted_connections, 1);
+    assert_eq!(stats.workers.len(), 1);
+    assert_eq!(stats.workers[0].accepted_connections, 1);
+
+    assert!(!server.pause("unknown"));
+    assert!(server.pause("test_server"));
+    assert!(server.stats().listeners[0].paused);
+
+    // connection is queued in backlog and not accepted while paused.
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(Duration::from_millis(500)))?;
+    stream.write_all(REQ)?;
+    let err = stream.read(&mut [0; 1]).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+    ));
+
+    assert!(server.resume("test_server"));
+    stream.set_read_timeout(None)?;
+    let mut res = String::new();
+    stream.read_to_string(&mut res)?;
+    assert!(res.starts_with("HTTP/1.1 200 OK"), "{res}");
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr2 = listener.local_addr()?;
+    assert!(server
+        .add_listener("unknown", TcpListener::bind("127.0.0.1:0")?)
+        .is_err());
+    let id = server.add_listener("test_server", listener)?;
+
+    let res = send(&mut TcpStream::connect(addr2)?)?;
+    assert!(res.starts_with("HTTP/1.1 200 OK"), "{res}");
+
+    let stats = server.stats();
+    assert_eq!(stats.listeners.len(), 2);
+    assert_eq!(stats.listeners[1].id, id);
+    assert_eq!(stats.listeners[1].accepted_connections, 1);
+    assert_eq!(stats.workers[0].accepted_connections, 3);
+
+    assert!(server.remove_listener(id));
+    assert!(!server.remove_listener(id));
+    assert_eq!(server.stats().listeners.len(), 1);
+
+    // removed listener is closed after worker stopped accepting from it.
+    let mut refused = false;
+    for _ in 0..50 {
+        if TcpStream::connect(addr2).is_err() {
+            refused = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(refused);
+
+    server.stop(false);
+
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn server_in_flight() -> Result<(), Error> {
+    let release = Arc::new(Notify::new());
+
+    let release2 = release.clone();
+    let mut handle = test_h1_server(fn_service(move |_: Request<RequestExt<h1::RequestBody>>| {
+        let release = release2.clone();
+        async move {
+            release.notified().await;
+            Ok::<Response<ResponseBody>, Error>(Response::new(ResponseBody::empty()))
+        }
+    }))?;
+
+    let server = handle.try_handle()?;
+
+    let mut stream = TcpStream::connect(handle.addr())?;
+    stream.write_all(REQ)?;
+
+    let mut in_flight = false;
+    for _ in 0..50 {
+        if server.stats().in_flight_requests == 1 {
+            in_flight = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(in_flight);
+
+    let stats = server.stats();
+    assert_eq!(stats.listeners[0].in_flight_requests, 1);
+    assert_eq!(stats.workers[0].in_flight_requests, 1);
+
+    release.notify_one();
+
+    let mut res = String::new();
+    stream.read_to_string(&mut res)?;
+    assert!(res.starts_with("HTTP/1.1 200 OK"), "{res}");
+
+    let stats = server.stats();
+    assert_eq!(stats.in_flight_requests, 0);
+    assert_eq!(stats.listeners[0].in_flight_requests, 0);
+    assert_eq!(stats.workers[0].in_flight_requests, 0);
+
+    server.stop(false);
+
+    handle.await?;
+
+    Ok(())
+}
@@ -0,0 +1,443 @@
+use std::{
+    fs,
+    io::{Read, Write},
+    net::TcpStream,
+    sync::Arc,
+};
+
+use xitca_client::{Client, TlsConfig};
+use xitca_http::{
+    body::ResponseBody,
+    bytes::Bytes,
+    config::HttpServiceConfig,
+    http::{Request, RequestExt, Response, Version},
+    HttpServiceBuilder, RequestBody,
+};
+use xitca_io::net::Stream;
+use xitca_service::{fn_service, ServiceExt};
+use xitca_test::{test_server, Error};
+use xitca_tls::{
+    openssl::{
+        self,
+        ssl::{SslAcceptor, SslFiletype, SslMethod, SslVerifyMode},
+    },
+    rustls::{
+        crypto::ring::default_provider,
+        pki_types::{CertificateDer, PrivateKeyDer},
+        server::WebPkiClientVerifier,
+        sign::CertifiedKey,
+        CertResolver, ClientConfig, ClientConnection, RootCertStore, ServerConfig, StreamOwned,
+    },
+};
+
+// sha256 fingerprint of examples/cert/cert.pem
+const CERT_SHA256: [u8; 32] = [
+    0x4c, 0x8e, 0x45, 0xb3, 0x0e, 0x6b, 0xe3, 0x62, 0x2d, 0x11, 0xa5, 0xf3, 0x16, 0xbc, 0x92, 0xfd, 0x0f, 0x55, 0xdc,
+    0xc5, 0x5d, 0x98, 0x30, 0x0e, 0x7c, 0x02, 0x7f, 0x91, 0xfb, 0xf9, 0xfe, 0xb3,
+];
+
+fn cert_and_key() -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), Error> {
+    let key = fs::read("../examples/cert/key.pem")?;
+    let cert = fs::read("../examples/cert/cert.pem")?;
+
+    let key = rustls_pemfile::pkcs8_private_keys(&mut &*key).next().unwrap()?;
+    let cert = rustls_pemfile::certs(&mut &*cert).collect::<Result<_, _>>()?;
+
+    Ok((cert, PrivateKeyDer::from(key)))
+}
+
+// certificates signed by private certificate authority of test/cert/ca.pem
+fn signed_cert_and_key(name: &str) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), Error> {
+    let key = fs::read(format!("cert/{name}.key.pem"))?;
+    let cert = fs::read(format!("cert/{name}.pem"))?;
+
+    let key = rustls_pemfile::private_key(&mut &*key)?.unwrap();
+    let cert = rustls_pemfile::certs(&mut &*cert).collect::<Result<_, _>>()?;
+
+    Ok((cert, key))
+}
+
+fn ca_root_store() -> Result<RootCertStore, Error> {
+    let mut roots = RootCertStore::empty();
+    let ca = fs::read("cert/ca.pem")?;
+    for cert in rustls_pemfile::certs(&mut &*ca) {
+        roots.add(cert?)?;
+    }
+    Ok(roots)
+}
+
+fn client(version: Version) -> Client {
+    // max http version decides client's alpn protocols.
+    Client::builder()
+        .set_max_http_version(version)
+        .rustls_with_config(
+            TlsConfig::new()
+                .server_name("localhost")
+                .disable_built_in_roots()
+                .pin_sha256(CERT_SHA256),
+        )
+        .finish()
+}
+
+#[tokio::test]
+async fn tls_info() -> Result<(), Error> {
+    let (cert, key) = cert_and_key()?;
+
+    let mut config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(cert, key)?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    let service = fn_service(|req: Request<RequestExt<RequestBody>>| async move {
+        let info = req.body().tls_info().unwrap();
+        let body = format!(
+            "{}|{}|{}|{}",
+            info.server_name().unwrap(),
+            String::from_utf8_lossy(info.alpn_protocol().unwrap()),
+            info.protocol_version().is_some() && info.cipher_suite().is_some(),
+            info.peer_certificate().is_none(),
+        );
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::from(body).into()))
+    })
+    .enclosed(HttpServiceBuilder::new().rustls(Arc::new(config)));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let server_url = format!("https://{}/", handle.ip_port_string());
+
+    for (version, alpn) in [(Version::HTTP_2, "h2"), (Version::HTTP_11, "http/1.1")] {
+        let c = client(version);
+
+        let res = c.get(&server_url).version(version).send().await?;
+        assert_eq!(res.status().as_u16(), 200);
+        let body = res.string().await?;
+        assert_eq!(body, format!("localhost|{alpn}|true|true"));
+    }
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_cert_resolver() -> Result<(), Error> {
+    let (cert, key) = cert_and_key()?;
+    let key = default_provider().key_provider.load_private_key(key)?;
+    let key = CertifiedKey::new(cert, key);
+
+    let resolver = CertResolver::new();
+
+    let mut config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_cert_resolver(Arc::new(resolver.clone()));
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    let service = fn_service(|_: Request<RequestExt<RequestBody>>| async move {
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::new().into()))
+    })
+    .enclosed(HttpServiceBuilder::new().rustls(Arc::new(config)));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let server_url = format!("https://{}/", handle.ip_port_string());
+
+    // new client for every request so tls handshake always happens.
+    let send = || async { client(Version::HTTP_11).get(&server_url).send().await };
+
+    // no certificate for server name.
+    assert!(send().await.is_err());
+
+    // certificate is added after server started.
+    resolver.insert("*.localhost", key.clone());
+    assert!(send().await.is_err());
+    resolver.insert("LocalHost", key.clone());
+    assert_eq!(send().await?.status().as_u16(), 200);
+
+    // certificate is removed after server started.
+    resolver.remove("localhost");
+    assert!(send().await.is_err());
+
+    // default certificate is used when nothing matches.
+    resolver.set_default(Some(key));
+    assert_eq!(send().await?.status().as_u16(), 200);
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_cert_resolver_openssl() -> Result<(), Error> {
+    let cert = fs::read("../examples/cert/cert.pem")?;
+    let key = fs::read("../examples/cert/key.pem")?;
+    let key = || openssl::CertifiedKey::from_pem(&cert, &key);
+
+    let resolver = openssl::CertResolver::new();
+
+    let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
+    resolver.install(&mut builder);
+
+    let service = fn_service(|_: Request<RequestExt<RequestBody>>| async move {
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::new().into()))
+    })
+    .enclosed(HttpServiceBuilder::new().openssl(builder.build()));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let server_url = format!("https://{}/", handle.ip_port_string());
+
+    // new client for every request so tls handshake always happens.
+    let send = || async { client(Version::HTTP_11).get(&server_url).send().await };
+
+    // no certificate for server name.
+    assert!(send().await.is_err());
+
+    // certificate is added after server started.
+    resolver.insert("*.localhost", key()?);
+    assert!(send().await.is_err());
+    resolver.insert("LocalHost", key()?);
+    assert_eq!(send().await?.status().as_u16(), 200);
+
+    // certificate is removed after server started.
+    resolver.remove("localhost");
+    assert!(send().await.is_err());
+
+    // default certificate is used when nothing matches.
+    resolver.set_default(Some(key()?));
+    assert_eq!(send().await?.status().as_u16(), 200);
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_client_config() -> Result<(), Error> {
+    let (cert, key) = signed_cert_and_key("server")?;
+    let fingerprint = xitca_tls::openssl::sha::sha256(&cert[0]);
+
+    let mut config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(cert, key)?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    let service = fn_service(|req: Request<RequestExt<RequestBody>>| async move {
+        let info = req.body().tls_info().unwrap();
+        let body = format!(
+            "{}|{}",
+            info.server_name().unwrap_or(""),
+            String::from_utf8_lossy(info.alpn_protocol().unwrap()),
+        );
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::from(body).into()))
+    })
+    .enclosed(HttpServiceBuilder::new().rustls(Arc::new(config)));
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    // request is sent to ip address and server name of certificate is only known through override.
+    let server_url = format!("https://{}/", handle.ip_port_string());
+    let ca = fs::read("cert/ca.pem")?;
+
+    let trusted = || {
+        TlsConfig::new()
+            .add_root_certificates_pem(ca.clone())
+            .disable_built_in_roots()
+            .server_name("localhost")
+    };
+
+    for openssl in [false, true] {
+        let send = |config: TlsConfig, version: Version| {
+            let builder = Client::builder().set_max_http_version(version);
+            let builder = if openssl {
+                builder.openssl_with_config(config)
+            } else {
+                builder.rustls_with_config(config)
+            };
+            let client = builder.finish();
+            let url = server_url.clone();
+            async move { client.get(&url).version(version).send().await?.string().await }
+        };
+
+        // server name override is sent as SNI and alpn protocols follow max http version.
+        assert_eq!(send(trusted(), Version::HTTP_2).await?, "localhost|h2");
+        assert_eq!(send(trusted(), Version::HTTP_11).await?, "localhost|http/1.1");
+
+        // server certificate is pinned on top of chain verification.
+        let config = trusted().pin_sha256(fingerprint);
+        assert_eq!(send(config, Version::HTTP_11).await?, "localhost|http/1.1");
+        assert!(send(trusted().pin_sha256([0; 32]), Version::HTTP_11).await.is_err());
+
+        // private certificate authority is not trusted by built in roots.
+        let config = TlsConfig::new().server_name("localhost");
+        assert!(send(config, Version::HTTP_11).await.is_err());
+
+        // certificate is not valid for server name.
+        let config = trusted().server_name("example.com");
+        assert!(send(config, Version::HTTP_11).await.is_err());
+
+        // pinning is the only verification when no root is trusted.
+        let config = TlsConfig::new()
+            .disable_built_in_roots()
+            .server_name("localhost")
+            .pin_sha256(fingerprint);
+        assert_eq!(send(config, Version::HTTP_11).await?, "localhost|http/1.1");
+    }
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_ignore_h2c_upgrade() -> Result<(), Error> {
+    let (cert, key) = signed_cert_and_key("server")?;
+
+    let mut config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(cert, key)?;
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    let service = fn_service(|req: Request<RequestExt<RequestBody>>| async move {
+        let version = format!("{:?}", req.version());
+        Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::from(version).into()))
+    })
+    .enclosed(
+        HttpServiceBuilder::new()
+            .config(HttpServiceConfig::new().h2c_upgrade())
+            .rustls(Arc::new(config)),
+    );
+
+    let mut handle = test_server::<_, Stream>(service)?;
+
+    let config = ClientConfig::builder()
+        .with_root_certificates(ca_root_store()?)
+        .with_no_client_auth();
+    let conn = ClientConnection::new(Arc::new(config), "localhost".try_into()?)?;
+
+    let stream = TcpStream::connect(handle.addr())?;
+    stream.set_read_timeout(Some(std::time::Duration::from_secs(5)))?;
+    let mut stream = StreamOwned::new(conn, stream);
+
+    // h2c upgrade is forbidden over tls and request is served as http/1.
+    stream.write_all(
+        b"GET / HTTP/1.1\r\n\
+        host: localhost\r\n\
+        connection: close, upgrade, http2-settings\r\n\
+        upgrade: h2c\r\n\
+        http2-settings: AAMAAABkAAQAoAAAAAIAAAAA\r\n\
+        \r\n",
+    )?;
+
+    let mut buf = Vec::new();
+    let mut chunk = [0; 1024];
+    while !buf.ends_with(b"HTTP/1.1") {
+        let n = stream.read(&mut chunk)?;
+        assert_ne!(n, 0, "connection closed before response body");
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    assert!(buf.starts_with(b"HTTP/1.1 200 OK\r\n"));
+
+    handle.try_handle()?.stop(false);
+    handle.await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tls_client_certificate() -> Result<(), Error> {
+    // handler echos the certificate client presented in tls handshake.
+    let service = || {
+        fn_service(|req: Request<RequestExt<RequestBody>>| async move {
+            let cert = req.body().tls_info().unwrap().peer_certificate().unwrap_or_default();
+            Ok::<Response<ResponseBody>, Error>(Response::new(Bytes::copy_from_slice(cert).into()))
+        })
+    };
+
+    let (cert, key) = signed_cert_and_key("server")?;
+    let verifier = WebPkiClientVerifier::builder(Arc::new(ca_root_store()?)).build()?;
+    let mut config = ServerConfig::builder()
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(cert, key)?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    let config = Arc::new(config);
+
+    // client certificates are only collected when enabled.
+    let http_config = HttpServiceConfig::new().tls_peer_certificates();
+    let rustls = test_server::<_, Stream>(
+        service().enclosed(HttpServiceBuilder::new().config(http_config).rustls(config.clone())),
+    )?;
+    let mut disabled = test_server::<_, Stream>(service().enclosed(HttpServiceBuilder::new().rustls(config)))?;
+
+    let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
+    builder.set_certificate_chain_file("cert/server.pem")?;
+    builder.set_private_key_file("cert/server.key.pem", SslFiletype::PEM)?;
+    builder.set_ca_file("cert/ca.pem")?;
+    builder.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+    let openssl = test_server::<_, Stream>(
+        service().enclosed(HttpServiceBuilder::new().config(http_config).openssl(builder.build())),
+    )?;
+
+    let ca = fs::read("cert/ca.pem")?;
+    let client_cert = fs::read("cert/client.pem")?;
+    let client_key = fs::read("cert/client.key.pem")?;
+    let client_der = rustls_pemfile::certs(&mut &*client_cert).next().unwrap()?;
+
+    let handles = [rustls, openssl];
+
+    for handle in handles.iter() {
+        let server_url = format!("https://{}/", handle.ip_port_string());
+
+        for openssl in [false, true] {
+            let send = |identity: bool| {
+                let mut config = TlsConfig::new()
+                    .add_root_certificates_pem(ca.clone())
+                    .disable_built_in_roots()
+                    .server_name("localhost");
+                if identity {
+                    config = config.identity_pem(client_cert.clone(), client_key.clone());
+                }
+                let builder = Client::builder().set_max_http_version(Version::HTTP_11);
+                let builder = if openssl {
+                    builder.openssl_with_config(config)
+                } else {
+                    builder.rustls_with_config(config)
+                };
+                let client = builder.finish();
+                let url = server_url.clone();
+                async move { client.get(&url).send().await?.body().await }
+            };
+
+            // handler sees the end entity certificate client presented.
+            assert_eq!(send(true).await?, client_der.as_ref());
+
+            // server requires client certificate.
+            assert!(send(false).await.is_err());
+        }
+    }
+
+    let config = TlsConfig::new()
+        .add_root_certificates_pem(ca)
+        .disable_built_in_roots()
+        .server_name("localhost")
+        .identity_pem(client_cert, client_key);
+    let client = Client::builder()
+        .set_max_http_version(Version::HTTP_11)
+        .rustls_with_config(config)
+        .finish();
+    let url = format!("https://{}/", disabled.ip_port_string());
+    assert!(client.get(&url).send().await?.body().await?.is_empty());
+
+    for mut handle in handles {
+        handle.try_handle()?.stop(false);
+        handle.await?;
+    }
+
+    disabled.try_handle()?.stop(false);
+    disabled.await?;
+
+    Ok(())
+}
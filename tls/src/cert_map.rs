@@ -0,0 +1,66 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+// certificates keyed by server name and shared between resolver handle and tls acceptors.
+// lookup happens on every tls handshake so updates are visible to new connections immediately
+// while established connections keep using the certificate they were accepted with.
+pub(crate) struct CertMap<K> {
+    inner: Arc<RwLock<Inner<K>>>,
+}
+
+struct Inner<K> {
+    names: HashMap<String, Arc<K>>,
+    default: Option<Arc<K>>,
+}
+
+impl<K> Clone for CertMap<K> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K> CertMap<K> {
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Inner {
+                names: HashMap::new(),
+                default: None,
+            })),
+        }
+    }
+
+    pub(crate) fn insert(&self, name: &str, key: K) -> Option<Arc<K>> {
+        let name = name.to_ascii_lowercase();
+        self.inner.write().unwrap().names.insert(name, Arc::new(key))
+    }
+
+    pub(crate) fn remove(&self, name: &str) -> Option<Arc<K>> {
+        let name = name.to_ascii_lowercase();
+        self.inner.write().unwrap().names.remove(&name)
+    }
+
+    pub(crate) fn set_default(&self, key: Option<K>) -> Option<Arc<K>> {
+        let key = key.map(Arc::new);
+        core::mem::replace(&mut self.inner.write().unwrap().default, key)
+    }
+
+    // exact match first and then wildcard match of the left most label. e.g. `*.example.com` matches
+    // `www.example.com` but not `example.com` nor `a.b.example.com`. default certificate is used when
+    // nothing matches or client did not send server name.
+    pub(crate) fn get(&self, name: Option<&str>) -> Option<Arc<K>> {
+        let inner = self.inner.read().unwrap();
+        name.and_then(|name| {
+            let name = name.to_ascii_lowercase();
+            inner.names.get(&name).or_else(|| {
+                let (_, parent) = name.split_once('.')?;
+                inner.names.get(&format!("*.{parent}"))
+            })
+        })
+        .or(inner.default.as_ref())
+        .cloned()
+    }
+}
@@ -1,3 +1,11 @@
+#[cfg(any(
+    feature = "openssl",
+    feature = "rustls",
+    feature = "rustls-ring-crypto",
+    feature = "rustls-no-crypto"
+))]
+mod cert_map;
+
 #[cfg(feature = "openssl")]
 pub mod openssl;
 #[cfg(any(feature = "rustls", feature = "rustls-ring-crypto", feature = "rustls-no-crypto"))]
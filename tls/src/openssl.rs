@@ -5,13 +5,20 @@ use core::{
     task::{Context, Poll},
 };
 
-use std::io;
+use std::{io, sync::Arc};
 
 pub use openssl::*;
 
-use openssl::ssl::{ErrorCode, ShutdownResult, Ssl, SslRef, SslStream};
+use openssl::{
+    error::ErrorStack,
+    pkey::{PKey, Private},
+    ssl::{ErrorCode, NameType, ShutdownResult, SniError, Ssl, SslAcceptorBuilder, SslAlert, SslRef, SslStream},
+    x509::X509,
+};
 use xitca_io::io::{AsyncIo, Interest, Ready};
 
+use crate::cert_map::CertMap;
+
 /// A stream managed by `openssl` crate for tls read/write.
 pub struct TlsStream<Io> {
     io: SslStream<Io>,
@@ -138,3 +145,106 @@ impl fmt::Display for Error {
 }
 
 impl std::error::Error for Error {}
+
+/// Certificate chain and it's private key used by [CertResolver].
+pub struct CertifiedKey {
+    cert: X509,
+    chain: Vec<X509>,
+    key: PKey<Private>,
+}
+
+impl CertifiedKey {
+    /// Construct from end entity certificate, intermediate certificates and private key.
+    pub fn new(cert: X509, chain: Vec<X509>, key: PKey<Private>) -> Self {
+        Self { cert, chain, key }
+    }
+
+    /// Construct from certificate chain and private key in PEM format. The first certificate of
+    /// `cert_chain` must be the end entity certificate.
+    pub fn from_pem(cert_chain: &[u8], key: &[u8]) -> Result<Self, ErrorStack> {
+        let mut chain = X509::stack_from_pem(cert_chain)?.into_iter();
+        let cert = chain.next().ok_or_else(ErrorStack::get)?;
+        let key = PKey::private_key_from_pem(key)?;
+        Ok(Self::new(cert, chain.collect(), key))
+    }
+
+    fn apply(&self, ssl: &mut SslRef) -> Result<(), ErrorStack> {
+        ssl.set_certificate(&self.cert)?;
+        ssl.set_private_key(&self.key)?;
+        for cert in self.chain.iter() {
+            ssl.add_chain_cert(cert.clone())?;
+        }
+        Ok(())
+    }
+}
+
+/// Server certificate resolver select certificate by server name(SNI) client sent in tls handshake.
+///
+/// Resolver is a cheap to clone handle and all clones share the same certificates. Certificates can
+/// be inserted, replaced or removed at any time and it would take effect on the next tls handshake
+/// without restarting server. Connections already established are not affected.
+///
+/// # Examples
+/// ```rust
+/// use xitca_tls::openssl::{
+///     ssl::{SslAcceptor, SslMethod},
+///     CertResolver, CertifiedKey,
+/// };
+///
+/// # fn config(cert: &[u8], key: &[u8]) {
+/// let resolver = CertResolver::new();
+/// resolver.insert("example.com", CertifiedKey::from_pem(cert, key).unwrap());
+///
+/// let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+/// resolver.install(&mut builder);
+///
+/// // rotate certificate of server name after server started.
+/// resolver.insert("example.com", CertifiedKey::from_pem(cert, key).unwrap());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CertResolver {
+    map: CertMap<CertifiedKey>,
+}
+
+impl Default for CertResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CertResolver {
+    pub fn new() -> Self {
+        Self { map: CertMap::new() }
+    }
+
+    /// Insert certificate for given server name and return the previous one if any.
+    ///
+    /// Server name is case insensitive and it can start with `*.` for matching one level of sub domain.
+    pub fn insert(&self, name: &str, key: CertifiedKey) -> Option<Arc<CertifiedKey>> {
+        self.map.insert(name, key)
+    }
+
+    /// Remove certificate for given server name and return it if any.
+    pub fn remove(&self, name: &str) -> Option<Arc<CertifiedKey>> {
+        self.map.remove(name)
+    }
+
+    /// Set certificate used when client did not send server name or no certificate matches it.
+    /// Pass `None` to reject these handshakes.
+    pub fn set_default(&self, key: Option<CertifiedKey>) -> Option<Arc<CertifiedKey>> {
+        self.map.set_default(key)
+    }
+
+    /// Install resolver to acceptor builder. Certificate set on builder would be overridden by resolver.
+    pub fn install(&self, builder: &mut SslAcceptorBuilder) {
+        let map = self.map.clone();
+        builder.set_servername_callback(move |ssl, alert| {
+            let key = map.get(ssl.servername(NameType::HOST_NAME)).ok_or_else(|| {
+                *alert = SslAlert::UNRECOGNIZED_NAME;
+                SniError::ALERT_FATAL
+            })?;
+            key.apply(ssl).map_err(|_| SniError::ALERT_FATAL)
+        });
+    }
+}
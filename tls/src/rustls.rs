@@ -1,16 +1,24 @@
 use core::{
+    fmt,
     future::Future,
     ops::DerefMut,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use std::io;
+use std::{io, sync::Arc};
 
 pub use rustls_crate::*;
 
+use rustls_crate::{
+    server::{ClientHello, ResolvesServerCert},
+    sign::CertifiedKey,
+};
+
 use xitca_io::io::{AsyncIo, Interest, Ready};
 
+use crate::cert_map::CertMap;
+
 /// A stream managed by `rustls` crate for tls read/write.
 pub struct TlsStream<C, Io> {
     conn: C,
@@ -178,3 +186,76 @@ where
         }
     }
 }
+
+/// Server certificate resolver select certificate by server name(SNI) client sent in tls handshake.
+///
+/// Resolver is a cheap to clone handle and all clones share the same certificates. Certificates can
+/// be inserted, replaced or removed at any time and it would take effect on the next tls handshake
+/// without restarting server. Connections already established are not affected.
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use xitca_tls::rustls::{sign::CertifiedKey, CertResolver, ServerConfig};
+///
+/// # fn config(key: CertifiedKey, new_key: CertifiedKey) {
+/// let resolver = CertResolver::new();
+/// resolver.insert("example.com", key.clone());
+/// // wildcard name matches one level of sub domain.
+/// resolver.insert("*.example.com", key);
+///
+/// let config = ServerConfig::builder()
+///     .with_no_client_auth()
+///     .with_cert_resolver(Arc::new(resolver.clone()));
+///
+/// // rotate certificate of server name after server started.
+/// resolver.insert("example.com", new_key);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CertResolver {
+    map: CertMap<CertifiedKey>,
+}
+
+impl Default for CertResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CertResolver {
+    pub fn new() -> Self {
+        Self { map: CertMap::new() }
+    }
+
+    /// Insert certificate for given server name and return the previous one if any.
+    ///
+    /// Server name is case insensitive and it can start with `*.` for matching one level of sub domain.
+    pub fn insert(&self, name: &str, key: CertifiedKey) -> Option<Arc<CertifiedKey>> {
+        self.map.insert(name, key)
+    }
+
+    /// Remove certificate for given server name and return it if any.
+    pub fn remove(&self, name: &str) -> Option<Arc<CertifiedKey>> {
+        self.map.remove(name)
+    }
+
+    /// Set certificate used when client did not send server name or no certificate matches it.
+    /// Pass `None` to reject these handshakes.
+    pub fn set_default(&self, key: Option<CertifiedKey>) -> Option<Arc<CertifiedKey>> {
+        self.map.set_default(key)
+    }
+}
+
+impl fmt::Debug for CertResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CertResolver").finish_non_exhaustive()
+    }
+}
+
+impl ResolvesServerCert for CertResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        self.map.get(client_hello.server_name())
+    }
+}
@@ -1,6 +1,9 @@
+mod normalize;
 mod object;
 mod router;
 
+pub use self::normalize::{NormalizePath, TrailingSlash};
+
 use core::{
     convert::Infallible,
     fmt,
@@ -13,7 +16,7 @@ use std::error;
 use futures_core::stream::Stream;
 use xitca_http::util::{
     middleware::context::ContextBuilder,
-    service::router::{IntoObject, PathGen, RouteGen, RouteObject, TypedRoute},
+    service::router::{IntoObject, PathGen, RouteGen, RouteInfo, RouteNames, RouteObject, TypedRoute},
 };
 
 use crate::{
@@ -26,6 +29,18 @@ use crate::{
     service::{ready::ReadyService, AsyncFn, EnclosedBuilder, EnclosedFnBuilder, MapBuilder, Service, ServiceExt},
 };
 
+#[cfg(feature = "openapi")]
+use {
+    crate::{
+        handler::json::Json,
+        openapi::{
+            __private::{Document, TypedOperation},
+            Info, OpenApi,
+        },
+    },
+    xitca_http::util::service::router::RouterMapErr,
+};
+
 use self::{object::WebObject, router::AppRouter};
 
 /// composed application type with router, stateful context and default middlewares.
@@ -128,6 +143,7 @@ impl<Obj, CF> App<AppRouter<Obj>, CF> {
     /// assert_eq!(res.status(), StatusCode::OK);
     ///
     /// // any change on uri path would result in no match of route.
+    /// // see App::trailing_slash and App::normalize_path for matching path loosely.
     /// let req = Request::builder().uri("/users/").body(Default::default())?;
     /// let res = app_service.call(req).now_or_panic()?;
     /// assert_eq!(res.status(), StatusCode::NOT_FOUND);
@@ -186,6 +202,61 @@ impl<Obj, CF> App<AppRouter<Obj>, CF> {
     /// # }
     /// ```
     ///
+    /// ## Parameter Constraints
+    /// Named parameters can be written as `{name:constraint}` where the value must satisfy the constraint.
+    /// Built in constraints are integer types(`u8`, `i64`, `usize` etc). With `router-regex` feature enabled
+    /// any other constraint is a regular expression matching the whole value. Path not satisfying the
+    /// constraint falls through to other routes:
+    /// ```rust
+    /// # fn main() {
+    /// #   #[cfg(feature = "params")]
+    /// #   _main();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "params")]
+    /// # fn _main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use xitca_unsafe_collection::futures::NowOrPanic;
+    /// # use xitca_web::{
+    /// #   handler::{handler_service, params::Params},
+    /// #   http::{Request, StatusCode},
+    /// #   route::get,
+    /// #   service::Service,
+    /// #   App
+    /// # };
+    /// // same position of path can be registered with multiple constraints.
+    /// let app = App::new()
+    ///     .at("/users/{id:u64}", get(handler_service(by_id)))
+    ///     .at("/users/:name", get(handler_service(by_name)))
+    ///     .at("/posts/{id:u8}", get(handler_service(by_id)));
+    ///
+    /// async fn by_id(Params(id): Params<u64>) -> StatusCode {
+    ///     assert_eq!(id, 996);
+    ///     StatusCode::OK
+    /// }
+    ///
+    /// async fn by_name(Params(name): Params<String>) -> StatusCode {
+    ///     assert_eq!(name, "alice");
+    ///     StatusCode::ACCEPTED
+    /// }
+    ///
+    /// let app_service = app.finish().call(()).now_or_panic().unwrap();
+    ///
+    /// let req = Request::builder().uri("/users/996").body(Default::default())?;
+    /// let res = app_service.call(req).now_or_panic()?;
+    /// assert_eq!(res.status(), StatusCode::OK);
+    ///
+    /// let req = Request::builder().uri("/users/alice").body(Default::default())?;
+    /// let res = app_service.call(req).now_or_panic()?;
+    /// assert_eq!(res.status(), StatusCode::ACCEPTED);
+    ///
+    /// // 996 is out of range of u8 and there is no other matching route.
+    /// let req = Request::builder().uri("/posts/996").body(Default::default())?;
+    /// let res = app_service.call(req).now_or_panic()?;
+    /// assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// ## Catch-all Parameters
     /// Catch-all parameters start with `*` and match everything after the `/`.
     /// They must always be at the **end** of the route:
@@ -302,14 +373,279 @@ impl<Obj, CF> App<AppRouter<Obj>, CF> {
         self
     }
 
+    /// insert routed service with given name and string literal as route path to application.
+    /// the name can be used for generating path of the route with [UrlFor] extractor. routing
+    /// rules are the same as [App::at].
+    ///
+    /// Names of nested App are visible to the whole application and their paths include the
+    /// prefix of nesting.
+    ///
+    /// # Panic
+    /// When multiple routes registered with the same name.
+    ///
+    /// # Example
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use xitca_unsafe_collection::futures::NowOrPanic;
+    /// # use xitca_web::{
+    /// #   error::Error,
+    /// #   handler::{handler_service, redirect::Redirect, url_for::UrlFor},
+    /// #   http::{header::LOCATION, Request, StatusCode},
+    /// #   route::get,
+    /// #   service::Service,
+    /// #   App
+    /// # };
+    /// let app = App::new()
+    ///     .at("/", get(handler_service(index)))
+    ///     .at("/v2", App::new().at_named("user", "/users/{id:u64}", get(handler_service(user))));
+    ///
+    /// // redirect to named route.
+    /// async fn index(url: UrlFor<'_>) -> Result<Redirect, Error> {
+    ///     let path = url.url_for_with_query("user", [("id", "996")], [("tab", "posts")])?;
+    ///     Ok(Redirect::see_other(path))
+    /// }
+    ///
+    /// async fn user() -> StatusCode {
+    ///     StatusCode::OK
+    /// }
+    ///
+    /// let app_service = app.finish().call(()).now_or_panic().unwrap();
+    ///
+    /// let req = Request::builder().uri("/").body(Default::default())?;
+    /// let res = app_service.call(req).now_or_panic()?;
+    /// assert_eq!(res.status(), StatusCode::SEE_OTHER);
+    /// assert_eq!(res.headers().get(LOCATION).unwrap(), "/v2/users/996?tab=posts");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [UrlFor]: crate::handler::url_for::UrlFor
+    pub fn at_named<F, C, B>(mut self, name: &'static str, path: &'static str, builder: F) -> Self
+    where
+        F: RouteGen + Service + Send + Sync,
+        F::Response: for<'r> Service<WebContext<'r, C, B>>,
+        for<'r> WebContext<'r, C, B>: IntoObject<F::Route<F>, (), Object = Obj>,
+    {
+        self.router = self.router.insert_named(name, path, builder);
+        self
+    }
+
+    /// insert application serving requests to given host. requests are routed by their host before
+    /// path and request with host not matching any inserted one falls back to routes registered with
+    /// [App::at]. host is read from Http/2 `:authority` pseudo header or Http/1 `host` header and
+    /// matched case-insensitively with port removed.
+    ///
+    /// host pattern supports exact host(`api.example.com`), parameter matching a single label
+    /// (`{tenant}.example.com`) and leading catch-all matching one or more labels(`*.example.com`
+    /// or `*sub.example.com` capturing them as `sub` parameter). captured host parameters are
+    /// available together with path parameters.
+    ///
+    /// # Panic
+    /// When the same host inserted multiple times.
+    ///
+    /// # Example
+    /// ```rust
+    /// # fn main() {
+    /// #   #[cfg(feature = "params")]
+    /// #   _main();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "params")]
+    /// # fn _main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use xitca_unsafe_collection::futures::NowOrPanic;
+    /// # use xitca_web::{
+    /// #   handler::{handler_service, params::ParamsRef},
+    /// #   http::{header::HOST, Request, StatusCode},
+    /// #   route::get,
+    /// #   service::Service,
+    /// #   App, NestApp
+    /// # };
+    /// // application for api.example.com
+    /// fn api() -> NestApp<()> {
+    ///     App::new().at("/", get(handler_service(|| async { "api" })))
+    /// }
+    ///
+    /// // application for sub domains of tenant.example.com
+    /// fn tenant() -> NestApp<()> {
+    ///     App::new().at("/", get(handler_service(handler)))
+    /// }
+    ///
+    /// // host parameter is extracted the same way as path parameter.
+    /// async fn handler(params: ParamsRef<'_>) -> String {
+    ///     params.get("tenant").unwrap().to_string()
+    /// }
+    ///
+    /// let app = App::new()
+    ///     .host("api.example.com", api())
+    ///     .host("{tenant}.tenant.example.com", tenant())
+    ///     // fallback for all other hosts.
+    ///     .at("/", get(handler_service(|| async { "default" })));
+    ///
+    /// let app_service = app.finish().call(()).now_or_panic().unwrap();
+    ///
+    /// let req = Request::builder().uri("/").header(HOST, "acme.tenant.example.com").body(Default::default())?;
+    /// let res = app_service.call(req).now_or_panic()?;
+    /// assert_eq!(res.status(), StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn host(mut self, host: &'static str, app: App<AppRouter<Obj>>) -> Self {
+        self.router = self.router.insert_host(host, app.router);
+        self
+    }
+
+    /// set policy of matching request path with trailing slash. default to [TrailingSlash::Strict].
+    ///
+    /// alternative path with trailing slash added or removed is looked up from paths of all routes
+    /// including routes of nested App and host App. policy set on nested App and host App has no
+    /// effect.
+    ///
+    /// # Example
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use xitca_unsafe_collection::futures::NowOrPanic;
+    /// # use xitca_web::{
+    /// #   handler::handler_service,
+    /// #   http::{header::LOCATION, Request, StatusCode},
+    /// #   route::{get, TrailingSlash},
+    /// #   service::Service,
+    /// #   App
+    /// # };
+    /// let app = App::new()
+    ///     .at("/users", get(handler_service(|| async { "users" })))
+    ///     .trailing_slash(TrailingSlash::Redirect);
+    ///
+    /// let app_service = app.finish().call(()).now_or_panic().unwrap();
+    ///
+    /// // request is redirected to path registered as route.
+    /// let req = Request::builder().uri("/users/?page=1").body(Default::default())?;
+    /// let res = app_service.call(req).now_or_panic()?;
+    /// assert_eq!(res.status(), StatusCode::PERMANENT_REDIRECT);
+    /// assert_eq!(res.headers().get(LOCATION).unwrap(), "/users?page=1");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn trailing_slash(mut self, policy: TrailingSlash) -> Self {
+        self.router = self.router.trailing_slash(policy);
+        self
+    }
+
+    /// normalize request path with given rules before routing. handlers and middlewares observe
+    /// the normalized path from request uri. normalization set on nested App and host App has no
+    /// effect.
+    ///
+    /// # Example
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use xitca_unsafe_collection::futures::NowOrPanic;
+    /// # use xitca_web::{
+    /// #   handler::{handler_service, path::PathRef},
+    /// #   http::{Request, StatusCode},
+    /// #   route::{get, NormalizePath},
+    /// #   service::Service,
+    /// #   App
+    /// # };
+    /// async fn handler(PathRef(path): PathRef<'_>) -> StatusCode {
+    ///     assert_eq!(path, "/users/~996");
+    ///     StatusCode::OK
+    /// }
+    ///
+    /// let app = App::new()
+    ///     .at("/users/~996", get(handler_service(handler)))
+    ///     .normalize_path(NormalizePath::new());
+    ///
+    /// let app_service = app.finish().call(()).now_or_panic().unwrap();
+    ///
+    /// let req = Request::builder().uri("//api/..//users/./%7E996").body(Default::default())?;
+    /// let res = app_service.call(req).now_or_panic()?;
+    /// assert_eq!(res.status(), StatusCode::OK);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn normalize_path(mut self, normalize: NormalizePath) -> Self {
+        self.router = self.router.normalize_path(normalize);
+        self
+    }
+
+    /// routes registered to application in insertion order. routes of nested App are listed with
+    /// full path including the prefix of nesting and routes of host App with their host pattern.
+    /// path generated by service (e.g. `/*p` appended by `ServeDir` of `file` feature) is included.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use xitca_web::{handler::handler_service, http::Method, route::get, App, NestApp};
+    /// fn nest() -> NestApp<()> {
+    ///     App::new().at("/users/:id", get(handler_service(|| async { "" })).post(handler_service(|| async { "" })))
+    /// }
+    ///
+    /// let app = App::new()
+    ///     .at_named("index", "/", handler_service(|| async { "" }))
+    ///     .at("/v2", nest())
+    ///     .host("api.example.com", nest());
+    ///
+    /// let routes = app.routes();
+    /// assert_eq!(routes.len(), 3);
+    ///
+    /// assert_eq!(routes[0].path(), "/");
+    /// assert_eq!(routes[0].name(), Some("index"));
+    /// assert!(routes[0].methods().is_empty());
+    /// assert_eq!(routes[0].host(), None);
+    ///
+    /// assert_eq!(routes[1].path(), "/v2/users/:id");
+    /// assert_eq!(routes[1].methods(), &[Method::GET, Method::POST]);
+    /// assert_eq!(routes[1].name(), None);
+    ///
+    /// assert_eq!(routes[2].path(), "/users/:id");
+    /// assert_eq!(routes[2].host(), Some("api.example.com"));
+    /// ```
+    pub fn routes(&self) -> &[RouteInfo] {
+        self.router.routes()
+    }
+
     /// insert typed route service with given path to application.
     pub fn at_typed<T, C>(mut self, typed: T) -> Self
     where
         T: TypedRoute<C, Route = Obj>,
+        Obj: PathGen,
     {
         self.router = self.router.insert_typed(typed);
         self
     }
+
+    /// insert typed route service with given path to application like [App::at_typed].
+    /// the route is described in document generated by [App::openapi].
+    #[cfg(feature = "openapi")]
+    pub fn at_openapi_typed<T, C>(mut self, typed: T) -> Self
+    where
+        T: TypedRoute<C, Route = Obj> + TypedOperation,
+        Obj: PathGen,
+    {
+        self.router = self.router.insert_openapi_typed(typed);
+        self
+    }
+
+    /// generate OpenAPI document from route table of [App::routes].
+    ///
+    /// routes inserted with [App::at_openapi_typed] are described with their full path including
+    /// prefix of nested App. other routes are not described. See [openapi](crate::openapi) module
+    /// for detail.
+    #[cfg(feature = "openapi")]
+    pub fn openapi(&self, info: Info) -> OpenApi {
+        self.router.openapi(info)
+    }
+
+    /// insert route serving OpenAPI document generated by [App::openapi] as json with given
+    /// path. document is generated when App is built and routes inserted after this method are
+    /// described.
+    #[cfg(feature = "openapi")]
+    pub fn at_openapi<C, B>(mut self, path: &'static str, info: Info) -> Self
+    where
+        for<'r> WebContext<'r, C, B>: IntoObject<RouterMapErr<Json<Document>>, (), Object = Obj>,
+    {
+        let document = Document::default();
+        self.router = self.router.insert_openapi_document(info, document.clone());
+        self.at(path, Json(document))
+    }
 }
 
 impl<R, CF> App<R, CF> {
@@ -528,6 +864,14 @@ where
     fn path_gen(&mut self, prefix: &str) -> String {
         self.router.path_gen(prefix)
     }
+
+    fn name_gen(&mut self) -> RouteNames {
+        self.router.name_gen()
+    }
+
+    fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+        self.router.routes_gen()
+    }
 }
 
 impl<R, F> RouteGen for App<R, F>
@@ -608,9 +952,13 @@ mod test {
     use crate::{
         handler::{
             extension::ExtensionRef, extension::ExtensionsRef, handler_service, path::PathRef, state::StateRef,
-            uri::UriRef,
+            uri::UriRef, url_for::UrlFor,
+        },
+        http::{
+            const_header_value::TEXT_UTF8,
+            header::{ALLOW, CONTENT_LENGTH, CONTENT_TYPE, LOCATION},
+            request, Method,
         },
-        http::{const_header_value::TEXT_UTF8, header::CONTENT_TYPE, request, Method},
         middleware::UncheckedReady,
         route::get,
     };
@@ -790,4 +1138,203 @@ mod test {
 
         assert_eq!(res.status().as_u16(), 200);
     }
+
+    #[test]
+    fn app_host() {
+        async fn api(PathRef(path): PathRef<'_>, url: UrlFor<'_>) -> Result<&'static str, Error> {
+            assert_eq!(path, "/users");
+            assert_eq!(url.url_for("users", [("", "")])?, "/users");
+            Ok("api")
+        }
+
+        async fn tenant(req: &WebContext<'_, String>) -> String {
+            assert_eq!(req.state(), "state");
+            req.req().body().params().get("tenant").unwrap().to_string()
+        }
+
+        let service = App::new()
+            .with_state(String::from("state"))
+            .host(
+                "api.example.com",
+                App::new().at_named("users", "/users", get(handler_service(api))),
+            )
+            .host("{tenant}.example.com", App::new().at("/", get(handler_service(tenant))))
+            .at("/", get(handler_service(|| async { "default" })))
+            .finish()
+            .call(())
+            .now_or_panic()
+            .ok()
+            .unwrap();
+
+        let call = |uri: &str, host: &str| {
+            let req = request::Builder::default()
+                .uri(uri)
+                .header(crate::http::header::HOST, host)
+                .body(Default::default())
+                .unwrap();
+            service.call(req).now_or_panic().unwrap().status().as_u16()
+        };
+
+        assert_eq!(call("/users", "api.example.com"), 200);
+        assert_eq!(call("/", "api.example.com"), 404);
+        assert_eq!(call("/", "acme.example.com"), 200);
+        assert_eq!(call("/", "example.com"), 200);
+        assert_eq!(call("/users", "example.com"), 404);
+    }
+
+    #[test]
+    fn app_auto_method() {
+        let service = App::new()
+            .at(
+                "/",
+                get(handler_service(|| async { "hello" })).auto_head().auto_options(),
+            )
+            .finish()
+            .call(())
+            .now_or_panic()
+            .ok()
+            .unwrap();
+
+        let call = |method| {
+            let req = request::Builder::default()
+                .method(method)
+                .body(Default::default())
+                .unwrap();
+            service.call(req).now_or_panic().unwrap()
+        };
+
+        let res = call(Method::HEAD);
+        assert_eq!(res.status().as_u16(), 200);
+        assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "5");
+        assert_eq!(res.body().size_hint(), crate::body::NONE_BODY_HINT);
+
+        let res = call(Method::OPTIONS);
+        assert_eq!(res.status().as_u16(), 204);
+        assert_eq!(res.headers().get(ALLOW).unwrap(), "GET,HEAD,OPTIONS");
+
+        let res = call(Method::DELETE);
+        assert_eq!(res.status().as_u16(), 405);
+        assert_eq!(res.headers().get(ALLOW).unwrap(), "GET,HEAD,OPTIONS");
+    }
+
+    #[test]
+    fn app_path_policy() {
+        async fn handler(PathRef(path): PathRef<'_>) -> String {
+            path.to_owned()
+        }
+
+        let nest = || {
+            App::new()
+                .at("/users/", get(handler_service(handler)))
+                .trailing_slash(TrailingSlash::Strict)
+        };
+
+        let app = || {
+            App::new()
+                .at("/", get(handler_service(handler)))
+                .at("/users", get(handler_service(handler)))
+                .at("/v2", nest())
+                .host(
+                    "api.example.com",
+                    App::new().at("/items", get(handler_service(handler))),
+                )
+        };
+
+        let call = |app: NestApp<()>, uri| {
+            let service = app.finish().call(()).now_or_panic().ok().unwrap();
+            let req = request::Builder::default().uri(uri).body(Default::default()).unwrap();
+            let res = service.call(req).now_or_panic().unwrap();
+            let location = res.headers().get(LOCATION).map(|v| v.to_str().unwrap().to_owned());
+            (res.status().as_u16(), location)
+        };
+
+        assert_eq!(call(app(), "/users/").0, 404);
+
+        let redirect = || app().trailing_slash(TrailingSlash::Redirect);
+        assert_eq!(call(redirect(), "/users").0, 200);
+        assert_eq!(call(redirect(), "/users/?a=b"), (308, Some("/users?a=b".to_owned())));
+        assert_eq!(call(redirect(), "/v2/users"), (308, Some("/v2/users/".to_owned())));
+        assert_eq!(call(redirect(), "/"), (200, None));
+        assert_eq!(call(redirect(), "/foo/").0, 404);
+        assert_eq!(
+            call(redirect(), "http://api.example.com/items/"),
+            (308, Some("/items".to_owned()))
+        );
+        // alternative path is looked up with routes of the host request is routed with.
+        assert_eq!(call(redirect(), "http://api.example.com/users/"), (404, None));
+        assert_eq!(call(redirect(), "/items/"), (404, None));
+
+        let both = || app().trailing_slash(TrailingSlash::MatchBoth);
+        assert_eq!(call(both(), "/users/").0, 200);
+        assert_eq!(call(both(), "/v2/users").0, 200);
+        assert_eq!(call(both(), "//users/").0, 404);
+
+        let normalize = || both().normalize_path(NormalizePath::new());
+        assert_eq!(call(normalize(), "//users/").0, 200);
+        assert_eq!(call(normalize(), "/v2/../v2/%75sers").0, 200);
+        assert_eq!(call(normalize(), "/users%2F").0, 404);
+    }
+
+    #[test]
+    fn app_routes() {
+        let nest = App::new()
+            .at(
+                "/users/:id",
+                get(handler_service(|| async { "" })).enclosed_fn(middleware),
+            )
+            .with_state(996usize);
+
+        let app: NestApp<()> = App::new()
+            .at_named("index", "/", get(handler_service(|| async { "" })))
+            .at("/v2", nest)
+            .host("api.example.com", App::new().at("/", handler_service(|| async { "" })));
+
+        #[cfg(feature = "file")]
+        let app = app.at("/static", crate::service::file::ServeDir::new("assets"));
+
+        let routes = app
+            .routes()
+            .iter()
+            .map(|r| (r.host(), r.path(), r.methods(), r.name()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(routes[0], (None, "/", &[Method::GET][..], Some("index")));
+        assert_eq!(routes[1], (None, "/v2/users/:id", &[Method::GET][..], None));
+        assert_eq!(routes[2], (Some("api.example.com"), "/", &[][..], None));
+
+        #[cfg(feature = "file")]
+        assert_eq!(routes[3], (None, "/static/*p", &[Method::GET, Method::HEAD][..], None));
+    }
+
+    #[test]
+    fn app_named_route() {
+        async fn handler(url: UrlFor<'_>) -> Result<String, Error> {
+            assert_eq!(url.url_for("root", [("id", "1")])?, "/root/1");
+            assert_eq!(url.url_for("nest", [("p", "a/b c")])?, "/scope/nest/a/b%20c");
+            url.url_for("nest", [("id", "1")]).map_err(Into::into)
+        }
+
+        let service = App::new()
+            .with_state(String::from("state"))
+            .at_named("root", "/root/:id", get(handler_service(handler)))
+            .at(
+                "/scope",
+                App::new()
+                    .with_state(996usize)
+                    .at_named("nest", "/nest/*p", get(handler_service(handler)))
+                    .enclosed_fn(middleware),
+            )
+            .finish()
+            .call(())
+            .now_or_panic()
+            .ok()
+            .unwrap();
+
+        for uri in ["/root/1", "/scope/nest/1"] {
+            let req = request::Builder::default().uri(uri).body(Default::default()).unwrap();
+            let res = service.call(req).now_or_panic().unwrap();
+            // missing parameter of named route is an internal error.
+            assert_eq!(res.status().as_u16(), 500);
+        }
+    }
 }
@@ -0,0 +1,336 @@
+//! request path normalization and trailing slash policy of [App](super::App).
+
+use xitca_http::util::service::router::RouteInfo;
+
+use crate::{
+    error::TrailingSlashRedirect,
+    http::{
+        uri::{PathAndQuery, Uri},
+        HeaderValue,
+    },
+};
+
+/// policy of matching request path with trailing slash against routes. See [App::trailing_slash].
+///
+/// [App::trailing_slash]: super::App::trailing_slash
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TrailingSlash {
+    /// request path must match route exactly. `/users/` does not match `/users`.
+    #[default]
+    Strict,
+    /// when request path does not match any route but it does with trailing slash added or removed,
+    /// respond with `308 Permanent Redirect` to the path registered as route.
+    Redirect,
+    /// when request path does not match any route but it does with trailing slash added or removed,
+    /// route request with the path registered as route.
+    MatchBoth,
+}
+
+/// rules of request path normalization applied before routing. See [App::normalize_path].
+///
+/// [App::normalize_path]: super::App::normalize_path
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NormalizePath {
+    merge_slashes: bool,
+    resolve_dots: bool,
+    decode_unreserved: bool,
+}
+
+impl Default for NormalizePath {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NormalizePath {
+    /// construct normalization with all rules enabled.
+    pub const fn new() -> Self {
+        Self {
+            merge_slashes: true,
+            resolve_dots: true,
+            decode_unreserved: true,
+        }
+    }
+
+    /// collapse consecutive slashes into one. `/a//b` becomes `/a/b`.
+    ///
+    /// Default to true.
+    pub const fn merge_slashes(mut self, value: bool) -> Self {
+        self.merge_slashes = value;
+        self
+    }
+
+    /// resolve `.` and `..` segments. `/a/./b/../c` becomes `/a/c`. `..` can not go above root.
+    ///
+    /// Default to true.
+    pub const fn resolve_dots(mut self, value: bool) -> Self {
+        self.resolve_dots = value;
+        self
+    }
+
+    /// decode percent-encoded unreserved characters (`A-Z a-z 0-9 - . _ ~`) and upper case hex
+    /// digits of the rest. `/%7Euser/a%2fb` becomes `/~user/a%2Fb`. reserved characters stay
+    /// encoded so decoding never changes segments of path. decoding happens before resolving dot
+    /// segments so `%2E%2E` is resolved as `..`. when [NormalizePath::resolve_dots] is disabled
+    /// `.` stays encoded so decoding never produces dot segments.
+    ///
+    /// Default to true.
+    pub const fn decode_unreserved(mut self, value: bool) -> Self {
+        self.decode_unreserved = value;
+        self
+    }
+
+    // return normalized path when it's different from input.
+    fn normalize(&self, path: &str) -> Option<String> {
+        if !path.starts_with('/') || !self.needs_normalize(path) {
+            return None;
+        }
+
+        let decoded = if self.decode_unreserved && path.contains('%') {
+            decode_unreserved(path, self.resolve_dots)
+        } else {
+            path.to_owned()
+        };
+
+        let mut segments = Vec::new();
+        let mut trailing = false;
+        let mut iter = decoded[1..].split('/').peekable();
+
+        while let Some(segment) = iter.next() {
+            let last = iter.peek().is_none();
+            match segment {
+                "" if self.merge_slashes => {
+                    trailing = last;
+                    continue;
+                }
+                "." if self.resolve_dots => {}
+                ".." if self.resolve_dots => {
+                    segments.pop();
+                }
+                segment => {
+                    segments.push(segment);
+                    trailing = false;
+                    continue;
+                }
+            }
+            trailing = last;
+        }
+
+        let mut normalized = String::with_capacity(decoded.len());
+        for segment in segments.iter() {
+            normalized.push('/');
+            normalized.push_str(segment);
+        }
+        if normalized.is_empty() || trailing {
+            normalized.push('/');
+        }
+
+        (normalized != path).then_some(normalized)
+    }
+
+    // scan path for anything normalization could change without allocation.
+    fn needs_normalize(&self, path: &str) -> bool {
+        (self.decode_unreserved && path.contains('%'))
+            || (self.merge_slashes && path.contains("//"))
+            || (self.resolve_dots && path.split('/').any(|segment| segment == "." || segment == ".."))
+    }
+}
+
+// decode percent-encoded unreserved characters. `.` is only decoded when dot segments are resolved
+// afterwards.
+fn decode_unreserved(path: &str, dots: bool) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = String::with_capacity(path.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| Some((from_hex(hex[0])? << 4) | from_hex(hex[1])?));
+
+        match hex {
+            Some(b) if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'~') || (dots && b == b'.') => {
+                decoded.push(b as char);
+            }
+            Some(b) => {
+                decoded.push('%');
+                decoded.push_str(&format!("{b:02X}"));
+            }
+            None => {
+                // path is valid utf-8 and multi-byte characters are copied as is.
+                let len = path[i..].chars().next().map_or(1, char::len_utf8);
+                decoded.push_str(&path[i..i + len]);
+                i += len;
+                continue;
+            }
+        }
+
+        i += 3;
+    }
+
+    decoded
+}
+
+fn from_hex(b: u8) -> Option<u8> {
+    (b as char).to_digit(16).map(|d| d as u8)
+}
+
+// path with trailing slash added or removed. root path has no alternative.
+fn toggle_trailing_slash(path: &str) -> Option<String> {
+    match path.strip_suffix('/') {
+        Some("") => None,
+        Some(path) => Some(path.to_owned()),
+        None => Some(format!("{path}/")),
+    }
+}
+
+// path policy of App constructed with all registered routes.
+pub(super) struct PathPolicy {
+    trailing_slash: TrailingSlash,
+    normalize: Option<NormalizePath>,
+    // paths of routes without host. used for looking up alternative path of trailing slash policy.
+    paths: xitca_router::Router<()>,
+    // paths of routes of each host pattern.
+    hosts: Vec<(&'static str, xitca_router::Router<()>)>,
+}
+
+impl PathPolicy {
+    // return None when policy has nothing to apply.
+    pub(super) fn new(
+        trailing_slash: TrailingSlash,
+        normalize: Option<NormalizePath>,
+        routes: &[RouteInfo],
+    ) -> Option<Self> {
+        if trailing_slash == TrailingSlash::Strict && normalize.is_none() {
+            return None;
+        }
+
+        let mut paths = xitca_router::Router::new();
+        let mut hosts = Vec::<(_, xitca_router::Router<()>)>::new();
+        if trailing_slash != TrailingSlash::Strict {
+            for route in routes {
+                let paths = match route.host() {
+                    Some(host) => match hosts.iter().position(|(h, _)| *h == host) {
+                        Some(idx) => &mut hosts[idx].1,
+                        None => {
+                            hosts.push((host, xitca_router::Router::new()));
+                            &mut hosts.last_mut().unwrap().1
+                        }
+                    },
+                    None => &mut paths,
+                };
+                // routes are validated by router already and conflict of nested catch-all path is
+                // good enough for look up.
+                let _ = paths.insert(route.path().to_owned(), ());
+            }
+        }
+
+        Some(Self {
+            trailing_slash,
+            normalize,
+            paths,
+            hosts,
+        })
+    }
+
+    // return normalized path when uri has to be rewritten or redirect error when path has to be
+    // redirected. host is the pattern request is routed with and only called when trailing slash
+    // policy has to look up alternative path.
+    pub(super) fn apply(
+        &self,
+        uri: &Uri,
+        host: impl FnOnce() -> Option<&'static str>,
+    ) -> Result<Option<String>, TrailingSlashRedirect> {
+        let mut path = self.normalize.and_then(|normalize| normalize.normalize(uri.path()));
+
+        if self.trailing_slash != TrailingSlash::Strict {
+            let paths = self.paths(host());
+            let current = path.as_deref().unwrap_or(uri.path());
+            if paths.at(current).is_err() {
+                if let Some(alt) = toggle_trailing_slash(current).filter(|alt| paths.at(alt).is_ok()) {
+                    if self.trailing_slash == TrailingSlash::Redirect {
+                        let location = match uri.query() {
+                            Some(query) => format!("{alt}?{query}"),
+                            None => alt,
+                        };
+                        // location is built from valid uri path and query.
+                        let location = HeaderValue::try_from(location).unwrap();
+                        return Err(TrailingSlashRedirect::new(location));
+                    }
+                    path = Some(alt);
+                }
+            }
+        }
+
+        Ok(path)
+    }
+
+    // paths of routes request is routed with. like router, request matching a host pattern is
+    // never routed with routes without host.
+    fn paths(&self, host: Option<&str>) -> &xitca_router::Router<()> {
+        host.and_then(|host| self.hosts.iter().find(|(h, _)| *h == host))
+            .map_or(&self.paths, |(_, paths)| paths)
+    }
+}
+
+pub(super) fn set_path(uri: &mut Uri, path: String) {
+    let path_and_query = match uri.query() {
+        Some(query) => format!("{path}?{query}"),
+        None => path,
+    };
+
+    let mut parts = core::mem::take(uri).into_parts();
+    if let Ok(path_and_query) = PathAndQuery::try_from(path_and_query) {
+        parts.path_and_query = Some(path_and_query);
+    }
+    // parts are from valid uri and path is only normalized.
+    *uri = Uri::from_parts(parts).unwrap();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        let n = NormalizePath::new();
+        assert_eq!(n.normalize("/users"), None);
+        assert_eq!(n.normalize("/users/"), None);
+        assert_eq!(n.normalize("/"), None);
+        assert_eq!(n.normalize("*"), None);
+        assert_eq!(n.normalize("//users///996/").as_deref(), Some("/users/996/"));
+        assert_eq!(n.normalize("/a/./b/../c").as_deref(), Some("/a/c"));
+        assert_eq!(n.normalize("/a/b/..").as_deref(), Some("/a/"));
+        assert_eq!(n.normalize("/a/b/.").as_deref(), Some("/a/b/"));
+        assert_eq!(n.normalize("/../../a").as_deref(), Some("/a"));
+        assert_eq!(n.normalize("/..").as_deref(), Some("/"));
+        assert_eq!(n.normalize("/%7Euser/a%2fb").as_deref(), Some("/~user/a%2Fb"));
+        assert_eq!(n.normalize("/a/%2E%2E/b").as_deref(), Some("/b"));
+        assert_eq!(n.normalize("/100%/%zz").as_deref(), None);
+        assert_eq!(n.normalize("/caf%C3%A9").as_deref(), None);
+
+        let n = NormalizePath::new().merge_slashes(false).decode_unreserved(false);
+        assert_eq!(n.normalize("//a//./b/").as_deref(), Some("//a//b/"));
+        assert_eq!(n.normalize("/a//./b/").as_deref(), Some("/a//b/"));
+        assert_eq!(n.normalize("/%7E/.").as_deref(), Some("/%7E/"));
+
+        let n = NormalizePath::new().resolve_dots(false);
+        assert_eq!(n.normalize("/a//../b").as_deref(), Some("/a/../b"));
+        assert_eq!(n.normalize("/a/%2E%2E/b"), None);
+        assert_eq!(n.normalize("/a/%2e%2e/%62").as_deref(), Some("/a/%2E%2E/b"));
+
+        let n = NormalizePath::new();
+        assert!(!n.needs_normalize("/users/996/"));
+        assert!(!n.needs_normalize("/a.b/..c/"));
+        assert!(n.needs_normalize("/a/../b"));
+        assert!(!n.resolve_dots(false).needs_normalize("/a/../b"));
+    }
+
+    #[test]
+    fn toggle() {
+        assert_eq!(toggle_trailing_slash("/"), None);
+        assert_eq!(toggle_trailing_slash("/users").as_deref(), Some("/users/"));
+        assert_eq!(toggle_trailing_slash("/users/").as_deref(), Some("/users"));
+    }
+}
@@ -1,9 +1,9 @@
 use core::marker::PhantomData;
 
-use xitca_http::util::service::router::{IntoObject, PathGen, RouteGen, RouteObject};
+use xitca_http::util::service::router::{IntoObject, PathGen, RouteGen, RouteInfo, RouteNames, RouteObject};
 use xitca_service::{object::ServiceObject, Service};
 
-use crate::context::WebContext;
+use crate::{context::WebContext, http::Method};
 
 pub type WebObject<C, B, Res, Err> = Box<dyn for<'r> ServiceObject<WebContext<'r, C, B>, Response = Res, Error = Err>>;
 
@@ -26,6 +26,18 @@ where
             fn path_gen(&mut self, prefix: &str) -> String {
                 self.0.path_gen(prefix)
             }
+
+            fn name_gen(&mut self) -> RouteNames {
+                self.0.name_gen()
+            }
+
+            fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+                self.0.routes_gen()
+            }
+
+            fn methods_gen(&self) -> Vec<Method> {
+                self.0.methods_gen()
+            }
         }
 
         impl<I, C, B> RouteGen for Builder<I, C, B>
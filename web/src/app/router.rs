@@ -1,4 +1,12 @@
-use xitca_http::util::service::router::{IntoObject, PathGen, RouteGen, Router, RouterError, RouterMapErr, TypedRoute};
+use xitca_http::util::service::router::{
+    self, IntoObject, PathGen, RouteGen, RouteInfo, RouteNames, Router, RouterError, RouterMapErr, TypedRoute,
+};
+
+#[cfg(feature = "openapi")]
+use crate::openapi::{
+    __private::{Describe, Document, TypedOperation},
+    Info, OpenApi,
+};
 
 use crate::{
     error::Error,
@@ -6,12 +14,27 @@ use crate::{
     WebContext,
 };
 
+use super::normalize::{set_path, NormalizePath, PathPolicy, TrailingSlash};
+
 /// application wrap around [Router] and transform it's error type into [Error]
-pub struct AppRouter<Obj>(Router<Obj>);
+pub struct AppRouter<Obj> {
+    router: Router<Obj>,
+    trailing_slash: TrailingSlash,
+    normalize: Option<NormalizePath>,
+    // documents served by App::at_openapi. generated from route table when router is built.
+    #[cfg(feature = "openapi")]
+    documents: Vec<(Info, Document)>,
+}
 
 impl<Obj> AppRouter<Obj> {
     pub(super) fn new() -> Self {
-        Self(Router::new())
+        Self {
+            router: Router::new(),
+            trailing_slash: TrailingSlash::Strict,
+            normalize: None,
+            #[cfg(feature = "openapi")]
+            documents: Vec::new(),
+        }
     }
 
     pub(super) fn insert<F, Arg, Req>(mut self, path: &'static str, builder: F) -> Self
@@ -20,15 +43,71 @@ impl<Obj> AppRouter<Obj> {
         F::Response: Service<Req>,
         Req: IntoObject<F::Route<F>, Arg, Object = Obj>,
     {
-        self.0 = self.0.insert(path, builder);
+        self.router = self.router.insert(path, builder);
+        self
+    }
+
+    pub(super) fn insert_named<F, Arg, Req>(mut self, name: &'static str, path: &'static str, builder: F) -> Self
+    where
+        F: Service<Arg> + RouteGen + Send + Sync,
+        F::Response: Service<Req>,
+        Req: IntoObject<F::Route<F>, Arg, Object = Obj>,
+    {
+        self.router = self.router.insert_named(name, path, builder);
+        self
+    }
+
+    pub(super) fn routes(&self) -> &[RouteInfo] {
+        self.router.routes()
+    }
+
+    pub(super) fn trailing_slash(mut self, policy: TrailingSlash) -> Self {
+        self.trailing_slash = policy;
+        self
+    }
+
+    pub(super) fn normalize_path(mut self, normalize: NormalizePath) -> Self {
+        self.normalize = Some(normalize);
+        self
+    }
+
+    pub(super) fn insert_host(mut self, host: &'static str, router: Self) -> Self {
+        self.router = self.router.insert_host(host, router.router);
         self
     }
 
     pub(super) fn insert_typed<T, M>(mut self, t: T) -> Self
     where
         T: TypedRoute<M, Route = Obj>,
+        Obj: PathGen,
+    {
+        self.router = self.router.insert_typed(t);
+        self
+    }
+
+    #[cfg(feature = "openapi")]
+    pub(super) fn insert_openapi_typed<T, M>(mut self, t: T) -> Self
+    where
+        T: TypedRoute<M, Route = Obj> + TypedOperation,
+        Obj: PathGen,
     {
-        self.0 = self.0.insert_typed(t);
+        self = self.insert_typed(t);
+        // description is attached to route table and it's moved together with the route when
+        // router is nested.
+        if let Some(info) = self.router.routes_mut().last_mut() {
+            info.extensions_mut().insert(Describe(T::operation));
+        }
+        self
+    }
+
+    #[cfg(feature = "openapi")]
+    pub(super) fn openapi(&self, info: Info) -> OpenApi {
+        OpenApi::from_routes(info, self.router.routes())
+    }
+
+    #[cfg(feature = "openapi")]
+    pub(super) fn insert_openapi_document(mut self, info: Info, document: Document) -> Self {
+        self.documents.push((info, document));
         self
     }
 }
@@ -38,7 +117,18 @@ where
     Router<Obj>: PathGen,
 {
     fn path_gen(&mut self, prefix: &str) -> String {
-        self.0.path_gen(prefix)
+        // nested router is routed with path policy of the router it's nested in.
+        self.trailing_slash = TrailingSlash::Strict;
+        self.normalize = None;
+        self.router.path_gen(prefix)
+    }
+
+    fn name_gen(&mut self) -> RouteNames {
+        self.router.name_gen()
+    }
+
+    fn routes_gen(&mut self) -> Option<Vec<RouteInfo>> {
+        self.router.routes_gen()
     }
 }
 
@@ -55,40 +145,58 @@ where
 
 impl<Arg, Obj> Service<Arg> for AppRouter<Obj>
 where
-    Router<Obj>: Service<Arg>,
+    Obj: Service<Arg>,
+    Arg: Clone,
 {
-    type Response = RouterService<<Router<Obj> as Service<Arg>>::Response>;
-    type Error = <Router<Obj> as Service<Arg>>::Error;
+    type Response = RouterService<Obj::Response>;
+    type Error = Obj::Error;
 
     async fn call(&self, arg: Arg) -> Result<Self::Response, Self::Error> {
-        self.0.call(arg).await.map(RouterService)
+        // route table is complete when router is built.
+        #[cfg(feature = "openapi")]
+        for (info, document) in self.documents.iter() {
+            document.get_or_init(|| self.openapi(info.clone()));
+        }
+
+        let service = self.router.call(arg).await?;
+        let policy = PathPolicy::new(self.trailing_slash, self.normalize, self.router.routes());
+        Ok(RouterService { service, policy })
     }
 }
 
-pub struct RouterService<S>(S);
+pub struct RouterService<S> {
+    service: router::RouterService<S>,
+    policy: Option<PathPolicy>,
+}
 
 impl<'r, S, C, B, Res, E> Service<WebContext<'r, C, B>> for RouterService<S>
 where
-    S: for<'r2> Service<WebContext<'r2, C, B>, Response = Res, Error = RouterError<E>>,
+    router::RouterService<S>: for<'r2> Service<WebContext<'r2, C, B>, Response = Res, Error = RouterError<E>>,
     E: Into<Error>,
 {
     type Response = Res;
     type Error = Error;
 
     #[inline]
-    async fn call(&self, req: WebContext<'r, C, B>) -> Result<Self::Response, Self::Error> {
-        self.0.call(req).await.map_err(Into::into)
+    async fn call(&self, mut req: WebContext<'r, C, B>) -> Result<Self::Response, Self::Error> {
+        if let Some(ref policy) = self.policy {
+            let path = policy.apply(req.req().uri(), || self.service.matched_host(&req))?;
+            if let Some(path) = path {
+                set_path(req.req_mut().uri_mut(), path);
+            }
+        }
+        self.service.call(req).await.map_err(Into::into)
     }
 }
 
 impl<S> ReadyService for RouterService<S>
 where
-    S: ReadyService,
+    router::RouterService<S>: ReadyService,
 {
-    type Ready = S::Ready;
+    type Ready = <router::RouterService<S> as ReadyService>::Ready;
 
     #[inline]
     async fn ready(&self) -> Self::Ready {
-        self.0.ready().await
+        self.service.ready().await
     }
 }
@@ -1,22 +1,30 @@
 pub use xitca_http::util::service::{
     route::MethodNotAllowed,
-    router::{MatchError, RouterError},
+    router::{MatchError, RouterError, UrlForError},
 };
 
-use core::convert::Infallible;
+use core::{convert::Infallible, fmt};
+
+use std::error;
 
 use crate::{
     body::ResponseBody,
-    http::{header::ALLOW, StatusCode, WebResponse},
+    http::{
+        header::{ALLOW, LOCATION},
+        HeaderValue, StatusCode, WebResponse,
+    },
     service::Service,
     WebContext,
 };
 
-use super::{blank_error_service, error_from_service, Error};
+use super::{blank_error_service, error_from_service, forward_blank_internal, Error};
 
 error_from_service!(MatchError);
 blank_error_service!(MatchError, StatusCode::NOT_FOUND);
 
+error_from_service!(UrlForError);
+forward_blank_internal!(UrlForError);
+
 error_from_service!(MethodNotAllowed);
 
 impl<'r, C, B> Service<WebContext<'r, C, B>> for MethodNotAllowed {
@@ -45,6 +53,45 @@ impl<'r, C, B> Service<WebContext<'r, C, B>> for MethodNotAllowed {
     }
 }
 
+/// error type of request redirected to path registered as route by trailing slash policy.
+/// it's rendered as `308 Permanent Redirect` response with `location` header.
+/// See [App::trailing_slash](crate::App::trailing_slash) for detail.
+#[derive(Debug)]
+pub struct TrailingSlashRedirect(HeaderValue);
+
+impl TrailingSlashRedirect {
+    pub(crate) fn new(location: HeaderValue) -> Self {
+        Self(location)
+    }
+
+    /// path and query request is redirected to.
+    pub fn location(&self) -> &HeaderValue {
+        &self.0
+    }
+}
+
+impl fmt::Display for TrailingSlashRedirect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "request is redirected to {:?}", self.0)
+    }
+}
+
+impl error::Error for TrailingSlashRedirect {}
+
+error_from_service!(TrailingSlashRedirect);
+
+impl<'r, C, B> Service<WebContext<'r, C, B>> for TrailingSlashRedirect {
+    type Response = WebResponse;
+    type Error = Infallible;
+
+    async fn call(&self, ctx: WebContext<'r, C, B>) -> Result<Self::Response, Self::Error> {
+        let mut res = ctx.into_response(ResponseBody::empty());
+        res.headers_mut().insert(LOCATION, self.0.clone());
+        *res.status_mut() = StatusCode::PERMANENT_REDIRECT;
+        Ok(res)
+    }
+}
+
 impl<E> From<RouterError<E>> for Error
 where
     E: Into<Self>,
@@ -4,7 +4,7 @@ use crate::{
     body::ResponseBody,
     context::WebContext,
     error::{Error, ErrorStatus},
-    http::{Method, RequestExt, StatusCode, WebRequest, WebResponse},
+    http::{Method, RequestExt, StatusCode, TlsInfo, WebRequest, WebResponse},
 };
 
 use super::{FromRequest, Responder};
@@ -109,6 +109,21 @@ impl<'a, 'r, C, B> FromRequest<'a, WebContext<'r, C, B>> for SocketAddr {
     }
 }
 
+/// extract tls connection information of request. request from plain text connection is rejected
+/// with [`StatusCode::BAD_REQUEST`]. use `Option<&TlsInfo>` when plain text connection is expected.
+impl<'a, 'r, C, B> FromRequest<'a, WebContext<'r, C, B>> for &'a TlsInfo {
+    type Type<'b> = &'b TlsInfo;
+    type Error = Error;
+
+    #[inline]
+    async fn from_request(ctx: &'a WebContext<'r, C, B>) -> Result<Self, Self::Error> {
+        ctx.req()
+            .body()
+            .tls_info()
+            .ok_or_else(|| Error::from(ErrorStatus::bad_request()))
+    }
+}
+
 impl<'a, 'r, C, B> FromRequest<'a, WebContext<'r, C, B>> for &'a Method {
     type Type<'b> = &'b Method;
     type Error = Error;
@@ -225,6 +240,10 @@ mod test {
         <&WebContext<'_>>::from_request(&req).now_or_panic().unwrap();
 
         <()>::from_request(&req).now_or_panic().unwrap();
+
+        // plain text request has no tls info.
+        assert!(<&TlsInfo>::from_request(&req).now_or_panic().is_err());
+        assert!(Option::<&TlsInfo>::from_request(&req).now_or_panic().unwrap().is_none());
     }
 
     #[test]
@@ -7,6 +7,7 @@ pub mod redirect;
 pub mod state;
 pub mod text;
 pub mod uri;
+pub mod url_for;
 
 #[cfg(feature = "params")]
 pub mod params;
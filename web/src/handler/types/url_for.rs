@@ -0,0 +1,47 @@
+//! type extractor for generating path of named route.
+
+use core::ops::Deref;
+
+use xitca_http::util::service::router::RouteNames;
+
+use crate::{context::WebContext, error::Error, handler::FromRequest};
+
+/// extractor for generating path of route registered with [App::at_named]. the generated path
+/// can be used for Location header of redirect and links of html template.
+///
+/// # Examples
+/// ```rust
+/// # use xitca_web::{error::Error, handler::{handler_service, url_for::UrlFor}, App};
+/// async fn handler(url: UrlFor<'_>) -> Result<String, Error> {
+///     // generate path from route name and it's parameters. missing parameter is an error.
+///     let path = url.url_for("user", [("id", "996")])?;
+///     assert_eq!(path, "/users/996");
+///     Ok(path)
+/// }
+///
+/// App::new()
+///     # .at("/nah", handler_service(|_: &xitca_web::WebContext<'_>| async { "used for infer type" }))
+///     .at_named("user", "/users/:id", handler_service(handler));
+/// ```
+///
+/// [App::at_named]: crate::App::at_named
+#[derive(Debug)]
+pub struct UrlFor<'a>(pub &'a RouteNames);
+
+impl Deref for UrlFor<'_> {
+    type Target = RouteNames;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<'a, 'r, C, B> FromRequest<'a, WebContext<'r, C, B>> for UrlFor<'a> {
+    type Type<'b> = UrlFor<'b>;
+    type Error = Error;
+
+    #[inline]
+    async fn from_request(ctx: &'a WebContext<'r, C, B>) -> Result<Self, Self::Error> {
+        Ok(UrlFor(ctx.req().body().route_names()))
+    }
+}
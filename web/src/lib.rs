@@ -265,6 +265,8 @@ pub mod body;
 pub mod error;
 pub mod handler;
 pub mod middleware;
+#[cfg(feature = "openapi")]
+pub mod openapi;
 pub mod service;
 pub mod test;
 
@@ -308,15 +310,79 @@ pub mod codegen {
 
     pub use xitca_codegen::route;
 
+    /// Routes with the same full path and method in a group and it's nested groups are rejected:
+    /// ```compile_fail
+    /// use xitca_web::codegen::{route, route_group};
+    ///
+    /// #[route_group("/api")]
+    /// mod api {
+    ///     use super::*;
+    ///
+    ///     #[route("/v1/users", method = get)]
+    ///     async fn users() -> &'static str {
+    ///         ""
+    ///     }
+    ///
+    ///     #[route_group("/v1")]
+    ///     mod v1 {
+    ///         use super::*;
+    ///
+    ///         // error: duplicate route GET /api/v1/users. it's already declared by users
+    ///         #[route("/users", method = get)]
+    ///         async fn list() -> &'static str {
+    ///             ""
+    ///         }
+    ///     }
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub use xitca_codegen::route_group;
+
     pub use xitca_codegen::error_impl;
 
     #[doc(hidden)]
     /// a hidden module for macro to access public types that are not framework user facing.
     pub mod __private {
         pub use xitca_http::util::service::router::{IntoObject, RouteObject, RouterMapErr, TypedRoute};
+
+        #[cfg(feature = "openapi")]
+        pub use crate::openapi::__private::*;
+
+        pub use crate::__codegen_operation as operation;
     }
 }
 
+// describe typed route generated by codegen::route macro. expands to nothing when openapi feature
+// is not enabled.
+#[cfg(all(feature = "codegen", feature = "openapi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __codegen_operation {
+    ($route: ident, [$($arg: ty),*], $output: ty) => {
+        impl $crate::codegen::__private::TypedOperation for $route {
+            fn operation(generator: &mut $crate::openapi::SchemaGenerator) -> $crate::openapi::Operation {
+                #[allow(unused_imports)]
+                use $crate::codegen::__private::{InputFallback, InputSpec, OutputFallback, OutputSpec};
+
+                let mut operation = $crate::openapi::Operation::default();
+                operation.operation_id = ::core::option::Option::Some(::std::string::String::from(stringify!($route)));
+                $(
+                    (&&$crate::codegen::__private::Input::<$arg>::new()).operation_input(&mut operation, generator);
+                )*
+                (&&$crate::codegen::__private::Output::<$output>::new()).operation_output(&mut operation, generator);
+                operation
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "codegen", not(feature = "openapi")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __codegen_operation {
+    ($($tt: tt)*) => {};
+}
+
 pub mod http {
     //! http types
 
@@ -354,13 +420,19 @@ pub mod route {
     //! # Ok(())
     //! # }
     //! ```
-    pub use xitca_http::util::service::route::{connect, delete, get, head, options, patch, post, put, trace, Route};
+    pub use xitca_http::util::service::route::{
+        connect, delete, get, head, options, patch, post, put, trace, AutoRoute, Route,
+    };
+
+    pub use xitca_http::util::service::router::RouteInfo;
+
+    pub use crate::app::{NormalizePath, TrailingSlash};
 }
 
 pub use app::{App, AppObject, NestApp};
 pub use body::BodyStream;
 pub use context::WebContext;
 #[cfg(feature = "__server")]
-pub use server::HttpServer;
+pub use server::{HttpServer, LimitPolicy};
 
 pub use xitca_http::bytes;
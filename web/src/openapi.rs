@@ -0,0 +1,749 @@
+//! [OpenAPI 3.1](https://spec.openapis.org/oas/v3.1.0) document generation.
+//!
+//! document is generated from route table of [App::routes](crate::App::routes). routes declared
+//! with [route](crate::codegen::route) macro and inserted with [App::at_openapi_typed](crate::App::at_openapi_typed)
+//! are described with their full path including prefix of nested App and methods listed in the
+//! table. operation of each route is collected from the macro where handler function's arguments
+//! and return type are described with [OperationInput] and [OperationOutput] traits. schema of user
+//! type is derived with [JsonSchema] trait. routes inserted with [App::at_typed](crate::App::at_typed)
+//! and other methods are served the same way but not described.
+//!
+//! # Example
+//! ```rust
+//! use xitca_web::{
+//!     codegen::route,
+//!     handler::json::Json,
+//!     openapi::{Info, JsonSchema},
+//!     App
+//! };
+//!
+//! #[derive(serde::Deserialize, serde::Serialize, JsonSchema)]
+//! #[schemars(crate = "xitca_web::openapi::schemars")]
+//! struct User {
+//!     id: u64,
+//!     name: String,
+//! }
+//!
+//! // path parameter is described by path template. request body and response are described
+//! // by handler argument and return type.
+//! #[route("/users/{id:u64}", method = put)]
+//! async fn user(Json(user): Json<User>) -> Json<User> {
+//!     Json(user)
+//! }
+//!
+//! // routes of nested App are described with prefix of nesting.
+//! let app = App::new().at("/v1", App::new().at_openapi_typed(user));
+//!
+//! let doc = app.openapi(Info::new("users", "1.0.0"));
+//! assert!(doc.paths["/v1/users/{id}"].contains_key("put"));
+//! assert!(doc.components.schemas.contains_key("User"));
+//!
+//! // serve the document as json. document is generated when App is built.
+//! app.at_openapi("/openapi.json", Info::new("users", "1.0.0")).serve();
+//! ```
+//!
+//! # Limitation
+//! - routes of nested App are moved to the App it's nested in. document of nested App is generated
+//!   from routes remaining in it's own table and it's empty when all routes are nested.
+//! - host of route inserted with [App::host](crate::App::host) is not described.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use xitca_http::util::service::router::RouteInfo;
+
+use crate::http::Method;
+
+pub use schemars::{self, JsonSchema, Schema, SchemaGenerator};
+
+const VERSION: &str = "3.1.0";
+
+/// root object of OpenAPI document.
+#[derive(Clone, Debug, Serialize)]
+pub struct OpenApi {
+    /// version of OpenAPI specification. default to `3.1.0`.
+    pub openapi: String,
+    /// metadata of API.
+    pub info: Info,
+    /// operations keyed by path in OpenAPI template form(`/users/{id}`) and lower cased method.
+    pub paths: BTreeMap<String, BTreeMap<String, Operation>>,
+    /// reusable schemas referenced by operations.
+    #[serde(skip_serializing_if = "Components::is_empty")]
+    pub components: Components,
+}
+
+impl OpenApi {
+    /// construct an empty document with given info.
+    pub fn new(info: Info) -> Self {
+        Self {
+            openapi: String::from(VERSION),
+            info,
+            paths: BTreeMap::new(),
+            components: Components::default(),
+        }
+    }
+
+    pub(crate) fn generator() -> SchemaGenerator {
+        schemars::generate::SchemaSettings::draft2020_12()
+            .with(|settings| {
+                settings.definitions_path = "/components/schemas".into();
+                settings.meta_schema = None;
+            })
+            .into_generator()
+    }
+
+    // generate document from routes with description attached to their extensions.
+    pub(crate) fn from_routes(info: Info, routes: &[RouteInfo]) -> Self {
+        let mut doc = Self::new(info);
+        let mut generator = Self::generator();
+        for route in routes {
+            if let Some(__private::Describe(operation)) = route.extensions().get() {
+                doc.insert(route.path(), route.methods(), operation(&mut generator));
+            }
+        }
+        doc.components.schemas = generator.take_definitions(true);
+        doc
+    }
+
+    // insert operation with path in router's template form.
+    pub(crate) fn insert(&mut self, path: &str, methods: &[Method], mut operation: Operation) {
+        let path = template(path, &mut operation);
+
+        if operation.responses.is_empty() {
+            operation.responses.insert(String::from("200"), Response::new("OK"));
+        }
+
+        let methods = methods
+            .iter()
+            .filter_map(|method| METHODS.iter().find(|m| method.as_str().eq_ignore_ascii_case(m)))
+            .collect::<Vec<_>>();
+
+        let item = self.paths.entry(path).or_default();
+
+        for method in methods.iter() {
+            let mut operation = operation.clone();
+            // operation id must be unique among all operations.
+            if methods.len() > 1 {
+                if let Some(ref mut id) = operation.operation_id {
+                    id.push('_');
+                    id.push_str(method);
+                }
+            }
+            item.insert(String::from(**method), operation);
+        }
+    }
+}
+
+// methods supported by OpenAPI path item.
+const METHODS: [&str; 8] = ["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+/// metadata of API.
+#[derive(Clone, Debug, Serialize)]
+pub struct Info {
+    /// title of API.
+    pub title: String,
+    /// version of API. not to be confused with version of OpenAPI specification.
+    pub version: String,
+    /// optional description of API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Info {
+    /// construct info with given title and version of API.
+    pub fn new(title: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            version: version.into(),
+            description: None,
+        }
+    }
+
+    /// set description of API.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// a single API operation on a path.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    /// unique id of operation. default to name of typed route.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+    /// path, query and header parameters of operation.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<Parameter>,
+    /// optional request body of operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<RequestBody>,
+    /// responses keyed by status code.
+    pub responses: BTreeMap<String, Response>,
+}
+
+impl Operation {
+    /// add parameters from properties of object schema of type. non object schema is ignored.
+    /// existing parameter with the same name and location is replaced.
+    pub fn parameters_from<T>(&mut self, location: ParameterIn, generator: &mut SchemaGenerator)
+    where
+        T: JsonSchema + ?Sized,
+    {
+        let schema = T::json_schema(generator);
+
+        let Some(Value::Object(properties)) = schema.get("properties") else {
+            return;
+        };
+
+        let required = match schema.get("required") {
+            Some(Value::Array(required)) => required.as_slice(),
+            _ => &[],
+        };
+
+        for (name, schema) in properties {
+            let Ok(schema) = Schema::try_from(schema.clone()) else {
+                continue;
+            };
+            let required = location == ParameterIn::Path || required.iter().any(|r| r == name);
+            self.parameter(Parameter {
+                name: name.clone(),
+                location,
+                required,
+                schema,
+            });
+        }
+    }
+
+    /// add parameter. existing parameter with the same name and location is replaced.
+    pub fn parameter(&mut self, parameter: Parameter) {
+        match self
+            .parameters
+            .iter_mut()
+            .find(|p| p.name == parameter.name && p.location == parameter.location)
+        {
+            Some(p) => *p = parameter,
+            None => self.parameters.push(parameter),
+        }
+    }
+
+    /// set request body with given content type and schema of type.
+    pub fn request_body_from<T>(&mut self, content_type: &str, generator: &mut SchemaGenerator)
+    where
+        T: JsonSchema + ?Sized,
+    {
+        let body = self.request_body.get_or_insert_with(|| RequestBody {
+            content: BTreeMap::new(),
+            required: true,
+        });
+        body.content.insert(
+            String::from(content_type),
+            MediaType {
+                schema: Some(generator.subschema_for::<T>()),
+            },
+        );
+    }
+
+    /// add response with given status code, content type and optional schema.
+    pub fn response(&mut self, status: u16, content_type: &str, schema: Option<Schema>) {
+        let res = self
+            .responses
+            .entry(status.to_string())
+            .or_insert_with(|| Response::new("OK"));
+        res.content.insert(String::from(content_type), MediaType { schema });
+    }
+}
+
+/// a single parameter of [Operation].
+#[derive(Clone, Debug, Serialize)]
+pub struct Parameter {
+    /// name of parameter.
+    pub name: String,
+    /// location of parameter.
+    #[serde(rename = "in")]
+    pub location: ParameterIn,
+    /// parameter must be present. always true for path parameter.
+    pub required: bool,
+    /// schema of parameter value.
+    pub schema: Schema,
+}
+
+/// location of [Parameter].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParameterIn {
+    /// parameter of request path.
+    Path,
+    /// parameter of request query string.
+    Query,
+    /// parameter of request header.
+    Header,
+}
+
+/// request body of [Operation].
+#[derive(Clone, Debug, Serialize)]
+pub struct RequestBody {
+    /// media types keyed by content type.
+    pub content: BTreeMap<String, MediaType>,
+    /// request body must be present.
+    pub required: bool,
+}
+
+/// content of [RequestBody] and [Response] with one media type.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MediaType {
+    /// optional schema of content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+}
+
+/// a single response of [Operation].
+#[derive(Clone, Debug, Serialize)]
+pub struct Response {
+    /// description of response.
+    pub description: String,
+    /// media types keyed by content type.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub content: BTreeMap<String, MediaType>,
+}
+
+impl Response {
+    /// construct response with given description and no content.
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            content: BTreeMap::new(),
+        }
+    }
+}
+
+/// reusable schemas referenced by operations.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Components {
+    /// schemas keyed by name of type.
+    pub schemas: Map<String, Value>,
+}
+
+impl Components {
+    fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+}
+
+/// trait for type extractor describing it's part of request in [Operation].
+pub trait OperationInput {
+    /// add parameters or request body described by type to operation.
+    fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator);
+}
+
+/// trait for responder describing it's response in [Operation].
+pub trait OperationOutput {
+    /// add responses described by type to operation.
+    fn operation_output(operation: &mut Operation, generator: &mut SchemaGenerator);
+}
+
+impl<T, E> OperationOutput for Result<T, E>
+where
+    T: OperationOutput,
+{
+    fn operation_output(operation: &mut Operation, generator: &mut SchemaGenerator) {
+        T::operation_output(operation, generator)
+    }
+}
+
+impl OperationOutput for &'static str {
+    fn operation_output(operation: &mut Operation, generator: &mut SchemaGenerator) {
+        operation.response(200, "text/plain; charset=utf-8", Some(generator.subschema_for::<str>()));
+    }
+}
+
+impl OperationOutput for String {
+    fn operation_output(operation: &mut Operation, generator: &mut SchemaGenerator) {
+        <&'static str>::operation_output(operation, generator)
+    }
+}
+
+impl<T> OperationOutput for crate::handler::text::Text<T> {
+    fn operation_output(operation: &mut Operation, generator: &mut SchemaGenerator) {
+        <&'static str>::operation_output(operation, generator)
+    }
+}
+
+impl<T> OperationOutput for crate::handler::html::Html<T> {
+    fn operation_output(operation: &mut Operation, generator: &mut SchemaGenerator) {
+        operation.response(200, "text/html; charset=utf-8", Some(generator.subschema_for::<str>()));
+    }
+}
+
+impl<T, const LIMIT: usize> OperationInput for crate::handler::json::Json<T, LIMIT>
+where
+    T: JsonSchema,
+{
+    fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator) {
+        operation.request_body_from::<T>("application/json", generator);
+    }
+}
+
+impl<T, const LIMIT: usize> OperationInput for crate::handler::json::LazyJson<T, LIMIT>
+where
+    T: JsonSchema,
+{
+    fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator) {
+        operation.request_body_from::<T>("application/json", generator);
+    }
+}
+
+impl<T, const LIMIT: usize> OperationOutput for crate::handler::json::Json<T, LIMIT>
+where
+    T: JsonSchema,
+{
+    fn operation_output(operation: &mut Operation, generator: &mut SchemaGenerator) {
+        operation.response(200, "application/json", Some(generator.subschema_for::<T>()));
+    }
+}
+
+impl OperationOutput for Value {
+    fn operation_output(operation: &mut Operation, _: &mut SchemaGenerator) {
+        operation.response(200, "application/json", None);
+    }
+}
+
+// request path is described by path template of route.
+impl OperationInput for crate::handler::path::PathRef<'_> {
+    fn operation_input(_: &mut Operation, _: &mut SchemaGenerator) {}
+}
+
+impl OperationInput for crate::handler::path::PathOwn {
+    fn operation_input(_: &mut Operation, _: &mut SchemaGenerator) {}
+}
+
+#[cfg(feature = "params")]
+mod params {
+    use crate::handler::params::{LazyParams, Params};
+
+    use super::*;
+
+    // path parameter of scalar type has no property name and it's described by path template.
+    impl<T> OperationInput for Params<T>
+    where
+        T: JsonSchema,
+    {
+        fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator) {
+            operation.parameters_from::<T>(ParameterIn::Path, generator);
+        }
+    }
+
+    impl<T> OperationInput for LazyParams<'_, T>
+    where
+        T: JsonSchema,
+    {
+        fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator) {
+            operation.parameters_from::<T>(ParameterIn::Path, generator);
+        }
+    }
+}
+
+#[cfg(feature = "urlencoded")]
+mod urlencoded {
+    use crate::handler::{
+        form::{Form, LazyForm},
+        query::{LazyQuery, Query},
+    };
+
+    use super::*;
+
+    const FORM: &str = "application/x-www-form-urlencoded";
+
+    impl<T> OperationInput for Query<T>
+    where
+        T: JsonSchema,
+    {
+        fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator) {
+            operation.parameters_from::<T>(ParameterIn::Query, generator);
+        }
+    }
+
+    impl<T> OperationInput for LazyQuery<'_, T>
+    where
+        T: JsonSchema,
+    {
+        fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator) {
+            operation.parameters_from::<T>(ParameterIn::Query, generator);
+        }
+    }
+
+    impl<T, const LIMIT: usize> OperationInput for Form<T, LIMIT>
+    where
+        T: JsonSchema,
+    {
+        fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator) {
+            operation.request_body_from::<T>(FORM, generator);
+        }
+    }
+
+    impl<T, const LIMIT: usize> OperationInput for LazyForm<T, LIMIT>
+    where
+        T: JsonSchema,
+    {
+        fn operation_input(operation: &mut Operation, generator: &mut SchemaGenerator) {
+            operation.request_body_from::<T>(FORM, generator);
+        }
+    }
+}
+
+// convert router's path template to OpenAPI template and add it's parameters to operation.
+// `/users/:id`, `/users/{id}` and `/users/{id:u64}` become `/users/{id}` and catch-all `*path`
+// becomes `{path}`. parameters already described by handler arguments are not overwritten.
+fn template(path: &str, operation: &mut Operation) -> String {
+    let mut template = String::with_capacity(path.len());
+    let mut rest = path;
+
+    let mut param = |template: &mut String, name: &str, constraint: Option<&str>| {
+        template.push('{');
+        template.push_str(name);
+        template.push('}');
+
+        if operation
+            .parameters
+            .iter()
+            .any(|p| p.name == name && p.location == ParameterIn::Path)
+        {
+            return;
+        }
+
+        operation.parameters.push(Parameter {
+            name: String::from(name),
+            location: ParameterIn::Path,
+            required: true,
+            schema: constraint_schema(constraint),
+        });
+    };
+
+    while let Some(i) = rest.find([':', '{', '*']) {
+        template.push_str(&rest[..i]);
+        rest = &rest[i..];
+
+        match rest.as_bytes()[0] {
+            b':' => {
+                let len = 1 + rest[1..]
+                    .bytes()
+                    .take_while(|c| c.is_ascii_alphanumeric() || *c == b'_')
+                    .count();
+                param(&mut template, &rest[1..len], None);
+                rest = &rest[len..];
+            }
+            b'{' => {
+                let (len, inner) = match braced_len(rest) {
+                    Some(len) => (len, &rest[1..len - 1]),
+                    None => (rest.len(), &rest[1..]),
+                };
+                match inner.split_once(':') {
+                    Some((name, constraint)) => param(&mut template, name, Some(constraint)),
+                    None => param(&mut template, inner, None),
+                }
+                rest = &rest[len..];
+            }
+            _ => {
+                match &rest[1..] {
+                    "" => template.push('*'),
+                    name => param(&mut template, name, None),
+                }
+                rest = "";
+            }
+        }
+    }
+
+    template.push_str(rest);
+    template
+}
+
+fn constraint_schema(constraint: Option<&str>) -> Schema {
+    let value = match constraint {
+        None => serde_json::json!({ "type": "string" }),
+        Some("u8" | "u16" | "u32" | "u64" | "u128" | "usize") => {
+            serde_json::json!({ "type": "integer", "minimum": 0 })
+        }
+        Some("i8" | "i16" | "i32" | "i64" | "i128" | "isize") => serde_json::json!({ "type": "integer" }),
+        Some(pattern) => serde_json::json!({ "type": "string", "pattern": format!("^(?:{pattern})$") }),
+    };
+    Schema::try_from(value).unwrap()
+}
+
+// length of braced parameter including the braces. constraint can contain nested braces.
+fn braced_len(path: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in path.bytes().enumerate() {
+        match c {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[doc(hidden)]
+/// a hidden module for macro to describe handler function of typed route.
+pub mod __private {
+    use core::{marker::PhantomData, ops::Deref};
+
+    use std::sync::{Arc, OnceLock};
+
+    use serde::{Serialize, Serializer};
+
+    use super::{OpenApi, Operation, OperationInput, OperationOutput, SchemaGenerator};
+
+    /// trait for typed route describing it's operation.
+    pub trait TypedOperation {
+        fn operation(generator: &mut SchemaGenerator) -> Operation;
+    }
+
+    /// description of route attached to extensions of it's [RouteInfo](super::RouteInfo).
+    #[derive(Clone, Copy)]
+    pub struct Describe(pub fn(&mut SchemaGenerator) -> Operation);
+
+    /// document served by [App::at_openapi](crate::App::at_openapi). it's generated when App is built.
+    #[derive(Clone, Default)]
+    pub struct Document(Arc<OnceLock<OpenApi>>);
+
+    impl Deref for Document {
+        type Target = OnceLock<OpenApi>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl Serialize for Document {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.get().serialize(serializer)
+        }
+    }
+
+    // autoref specialization for handler argument and return type. types not implementing
+    // OperationInput/OperationOutput fall back to no op.
+    pub struct Input<T: ?Sized>(PhantomData<T>);
+
+    impl<T: ?Sized> Input<T> {
+        pub const fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    pub trait InputSpec {
+        fn operation_input(&self, operation: &mut Operation, generator: &mut SchemaGenerator);
+    }
+
+    impl<T> InputSpec for &Input<T>
+    where
+        T: OperationInput + ?Sized,
+    {
+        fn operation_input(&self, operation: &mut Operation, generator: &mut SchemaGenerator) {
+            T::operation_input(operation, generator)
+        }
+    }
+
+    pub trait InputFallback {
+        fn operation_input(&self, _: &mut Operation, _: &mut SchemaGenerator) {}
+    }
+
+    impl<T: ?Sized> InputFallback for Input<T> {}
+
+    pub struct Output<T: ?Sized>(PhantomData<T>);
+
+    impl<T: ?Sized> Output<T> {
+        pub const fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    pub trait OutputSpec {
+        fn operation_output(&self, operation: &mut Operation, generator: &mut SchemaGenerator);
+    }
+
+    impl<T> OutputSpec for &Output<T>
+    where
+        T: OperationOutput + ?Sized,
+    {
+        fn operation_output(&self, operation: &mut Operation, generator: &mut SchemaGenerator) {
+            T::operation_output(operation, generator)
+        }
+    }
+
+    pub trait OutputFallback {
+        fn operation_output(&self, _: &mut Operation, _: &mut SchemaGenerator) {}
+    }
+
+    impl<T: ?Sized> OutputFallback for Output<T> {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn path_template() {
+        let mut op = Operation::default();
+        assert_eq!(template("/users/:id/posts/{post}", &mut op), "/users/{id}/posts/{post}");
+        assert_eq!(op.parameters.len(), 2);
+        assert!(op
+            .parameters
+            .iter()
+            .all(|p| p.required && p.location == ParameterIn::Path));
+
+        let mut op = Operation::default();
+        assert_eq!(
+            template("/users/{id:u64}/file.{ext}", &mut op),
+            "/users/{id}/file.{ext}"
+        );
+        assert_eq!(op.parameters[0].schema.get("type").unwrap(), "integer");
+        assert_eq!(op.parameters[1].schema.get("type").unwrap(), "string");
+
+        let mut op = Operation::default();
+        assert_eq!(template("/{name:[a-z]{2}}/*path", &mut op), "/{name}/{path}");
+        assert_eq!(op.parameters[0].schema.get("pattern").unwrap(), "^(?:[a-z]{2})$");
+        assert_eq!(op.parameters[1].name, "path");
+
+        // parameter described by handler argument is kept.
+        let mut op = Operation::default();
+        let mut generator = OpenApi::generator();
+        op.parameters.push(Parameter {
+            name: String::from("id"),
+            location: ParameterIn::Path,
+            required: true,
+            schema: generator.subschema_for::<bool>(),
+        });
+        template("/users/:id", &mut op);
+        assert_eq!(op.parameters.len(), 1);
+        assert_eq!(op.parameters[0].schema.get("type").unwrap(), "boolean");
+    }
+
+    #[test]
+    fn insert() {
+        let mut doc = OpenApi::new(Info::new("test", "0.1.0"));
+        let op = Operation {
+            operation_id: Some(String::from("users")),
+            ..Default::default()
+        };
+        doc.insert("/users", &[Method::GET, Method::POST, Method::CONNECT], op);
+
+        let item = &doc.paths["/users"];
+        assert_eq!(item.len(), 2);
+        assert_eq!(item["get"].operation_id.as_deref(), Some("users_get"));
+        assert_eq!(item["post"].operation_id.as_deref(), Some("users_post"));
+        assert!(item["get"].responses.contains_key("200"));
+
+        let value = serde_json::to_value(&doc).unwrap();
+        assert_eq!(value["openapi"], "3.1.0");
+        assert!(value.get("components").is_none());
+    }
+}
@@ -2,15 +2,16 @@ use std::{fmt, future::Future, sync::Arc, time::Duration};
 
 use futures_core::stream::Stream;
 use xitca_http::{
-    body::RequestBody,
+    body::{Frame, RequestBody},
     config::{HttpServiceConfig, DEFAULT_HEADER_LIMIT, DEFAULT_READ_BUF_LIMIT, DEFAULT_WRITE_BUF_LIMIT},
     HttpServiceBuilder,
 };
+pub use xitca_server::LimitPolicy;
+
 use xitca_server::{net::IntoListener, Builder, ServerFuture};
 use xitca_service::ServiceExt;
 
 use crate::{
-    bytes::Bytes,
     http::{Request, RequestExt, Response},
     service::{ready::ReadyService, Service},
 };
@@ -84,11 +85,51 @@ where
         self
     }
 
+    /// Enable graceful restart of server on `SIGHUP` or `SIGUSR2` signal.
+    ///
+    /// See [Builder::enable_graceful_restart] for detail.
+    pub fn enable_graceful_restart(mut self) -> Self {
+        self.builder = self.builder.enable_graceful_restart();
+        self
+    }
+
     pub fn backlog(mut self, num: u32) -> Self {
         self.builder = self.builder.backlog(num);
         self
     }
 
+    /// Set max number of concurrent connections of server.
+    ///
+    /// See [Builder::max_connections] for detail.
+    pub fn max_connections(mut self, num: usize) -> Self {
+        self.builder = self.builder.max_connections(num);
+        self
+    }
+
+    /// Set max number of concurrent connections of each worker.
+    ///
+    /// See [Builder::max_connections_per_worker] for detail.
+    pub fn max_connections_per_worker(mut self, num: usize) -> Self {
+        self.builder = self.builder.max_connections_per_worker(num);
+        self
+    }
+
+    /// Set max number of concurrent connections from the same ip address.
+    ///
+    /// See [Builder::max_connections_per_ip] for detail.
+    pub fn max_connections_per_ip(mut self, num: usize) -> Self {
+        self.builder = self.builder.max_connections_per_ip(num);
+        self
+    }
+
+    /// Set behavior of server when connection limit is reached.
+    ///
+    /// See [Builder::connection_limit_policy] for detail.
+    pub fn connection_limit_policy(mut self, policy: LimitPolicy) -> Self {
+        self.builder = self.builder.connection_limit_policy(policy);
+        self
+    }
+
     /// Disable vectored write even when IO is able to perform it.
     ///
     /// This is beneficial when dealing with small size of response body.
@@ -123,6 +164,66 @@ where
         self
     }
 
+    /// Enable PROXY protocol v1 and v2 for Tcp connections. client address sent by load balancer
+    /// is used as the socket address of request.
+    ///
+    /// See [HttpServiceConfig::proxy_protocol] for detail.
+    pub fn proxy_protocol(mut self) -> Self {
+        self.config = self.config.proxy_protocol();
+        self
+    }
+
+    /// Change max number of concurrent streams a Http/2 connection can have.
+    pub fn h2_max_concurrent_streams(mut self, max: u32) -> Self {
+        self.config = self.config.h2_max_concurrent_streams(max);
+        self
+    }
+
+    /// Change initial flow control window size of Http/2 stream.
+    pub fn h2_initial_window_size(mut self, size: u32) -> Self {
+        self.config = self.config.h2_initial_window_size(size);
+        self
+    }
+
+    /// Change initial flow control window size of Http/2 connection.
+    pub fn h2_initial_connection_window_size(mut self, size: u32) -> Self {
+        self.config = self.config.h2_initial_connection_window_size(size);
+        self
+    }
+
+    /// Change max frame size of Http/2 connection.
+    ///
+    /// # Panics
+    /// When value is not in range of 16,384 and 16,777,215.
+    pub fn h2_max_frame_size(mut self, size: u32) -> Self {
+        self.config = self.config.h2_max_frame_size(size);
+        self
+    }
+
+    /// Change max size of request header list of Http/2 connection.
+    pub fn h2_max_header_list_size(mut self, size: u32) -> Self {
+        self.config = self.config.h2_max_header_list_size(size);
+        self
+    }
+
+    /// Change max send buffer size of Http/2 stream.
+    pub fn h2_max_send_buffer_size(mut self, size: usize) -> Self {
+        self.config = self.config.h2_max_send_buffer_size(size);
+        self
+    }
+
+    /// Change interval of Http/2 ping frames sent to idle connection.
+    pub fn h2_keep_alive_interval(mut self, dur: Duration) -> Self {
+        self.config = self.config.h2_keep_alive_interval(dur);
+        self
+    }
+
+    /// Change duration of how long to wait for Http/2 pong frame before closing the connection.
+    pub fn h2_keep_alive_timeout(mut self, dur: Duration) -> Self {
+        self.config = self.config.h2_keep_alive_timeout(dur);
+        self
+    }
+
     /// Change max size for request head.
     ///
     /// Request has a bigger head than it would be reject with error.
@@ -167,7 +268,7 @@ where
     }
 
     #[cfg(not(target_family = "wasm"))]
-    pub fn bind<A, ResB, BE>(mut self, addr: A) -> std::io::Result<Self>
+    pub fn bind<A, ResB, BE, BF>(mut self, addr: A) -> std::io::Result<Self>
     where
         A: std::net::ToSocketAddrs,
         S: Service + 'static,
@@ -175,7 +276,8 @@ where
         S::Error: fmt::Debug,
         <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
 
-        ResB: Stream<Item = Result<Bytes, BE>> + 'static,
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
         BE: fmt::Debug + 'static,
     {
         let config = self.config;
@@ -184,14 +286,36 @@ where
         Ok(self)
     }
 
-    pub fn listen<ResB, BE, L>(mut self, listener: L) -> std::io::Result<Self>
+    /// Listen on sockets inherited from systemd socket activation or graceful restart of server.
+    ///
+    /// See [Builder::listen_fds] for detail.
+    #[cfg(unix)]
+    pub fn listen_fds<ResB, BE, BF>(mut self) -> std::io::Result<Self>
     where
         S: Service + 'static,
         S::Response: ReadyService + Service<Request<RequestExt<RequestBody>>, Response = Response<ResB>> + 'static,
         S::Error: fmt::Debug,
         <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
 
-        ResB: Stream<Item = Result<Bytes, BE>> + 'static,
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
+        BE: fmt::Debug + 'static,
+    {
+        let config = self.config;
+        let service = self.service.clone().enclosed(HttpServiceBuilder::with_config(config));
+        self.builder = self.builder.listen_fds("xitca-web", service)?;
+        Ok(self)
+    }
+
+    pub fn listen<ResB, BE, BF, L>(mut self, listener: L) -> std::io::Result<Self>
+    where
+        S: Service + 'static,
+        S::Response: ReadyService + Service<Request<RequestExt<RequestBody>>, Response = Response<ResB>> + 'static,
+        S::Error: fmt::Debug,
+        <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
+
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
         BE: fmt::Debug + 'static,
         L: IntoListener + 'static,
     {
@@ -202,7 +326,7 @@ where
     }
 
     #[cfg(feature = "openssl")]
-    pub fn bind_openssl<A: std::net::ToSocketAddrs, ResB, BE>(
+    pub fn bind_openssl<A: std::net::ToSocketAddrs, ResB, BE, BF>(
         mut self,
         addr: A,
         mut builder: xitca_tls::openssl::ssl::SslAcceptorBuilder,
@@ -213,7 +337,8 @@ where
         S::Error: fmt::Debug,
         <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
 
-        ResB: Stream<Item = Result<Bytes, BE>> + 'static,
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
         BE: fmt::Debug + 'static,
     {
         let config = self.config;
@@ -257,8 +382,36 @@ where
         Ok(self)
     }
 
+    /// Bind to given address with openssl and select server certificate with [CertResolver].
+    ///
+    /// Certificates can be changed through any clone of the resolver after server started.
+    ///
+    /// [CertResolver]: xitca_tls::openssl::CertResolver
+    #[cfg(feature = "openssl")]
+    pub fn bind_openssl_with_resolver<A: std::net::ToSocketAddrs, ResB, BE, BF>(
+        self,
+        addr: A,
+        resolver: xitca_tls::openssl::CertResolver,
+    ) -> std::io::Result<Self>
+    where
+        S: Service + 'static,
+        S::Response: ReadyService + Service<Request<RequestExt<RequestBody>>, Response = Response<ResB>> + 'static,
+        S::Error: fmt::Debug,
+        <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
+
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
+        BE: fmt::Debug + 'static,
+    {
+        use xitca_tls::openssl::ssl::{SslAcceptor, SslMethod};
+
+        let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
+        resolver.install(&mut builder);
+        self.bind_openssl(addr, builder)
+    }
+
     #[cfg(feature = "rustls")]
-    pub fn bind_rustls<A: std::net::ToSocketAddrs, ResB, BE>(
+    pub fn bind_rustls<A: std::net::ToSocketAddrs, ResB, BE, BF>(
         mut self,
         addr: A,
         #[cfg_attr(not(all(feature = "http1", feature = "http2")), allow(unused_mut))]
@@ -270,7 +423,8 @@ where
         S::Error: fmt::Debug,
         <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
 
-        ResB: Stream<Item = Result<Bytes, BE>> + 'static,
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
         BE: fmt::Debug + 'static,
     {
         let service_config = self.config;
@@ -293,15 +447,45 @@ where
         Ok(self)
     }
 
+    /// Bind to given address with rustls and select server certificate with [CertResolver].
+    ///
+    /// Certificates can be changed through any clone of the resolver after server started.
+    ///
+    /// Process default crypto provider of rustls must be available.
+    ///
+    /// [CertResolver]: xitca_tls::rustls::CertResolver
+    #[cfg(feature = "rustls")]
+    pub fn bind_rustls_with_resolver<A: std::net::ToSocketAddrs, ResB, BE, BF>(
+        self,
+        addr: A,
+        resolver: xitca_tls::rustls::CertResolver,
+    ) -> std::io::Result<Self>
+    where
+        S: Service + 'static,
+        S::Response: ReadyService + Service<Request<RequestExt<RequestBody>>, Response = Response<ResB>> + 'static,
+        S::Error: fmt::Debug,
+        <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
+
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
+        BE: fmt::Debug + 'static,
+    {
+        let config = xitca_tls::rustls::ServerConfig::builder()
+            .with_no_client_auth()
+            .with_cert_resolver(std::sync::Arc::new(resolver));
+        self.bind_rustls(addr, config)
+    }
+
     #[cfg(unix)]
-    pub fn bind_unix<P: AsRef<std::path::Path>, ResB, BE>(mut self, path: P) -> std::io::Result<Self>
+    pub fn bind_unix<P: AsRef<std::path::Path>, ResB, BE, BF>(mut self, path: P) -> std::io::Result<Self>
     where
         S: Service + 'static,
         S::Response: ReadyService + Service<Request<RequestExt<RequestBody>>, Response = Response<ResB>> + 'static,
         S::Error: fmt::Debug,
         <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
 
-        ResB: Stream<Item = Result<Bytes, BE>> + 'static,
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
         BE: fmt::Debug + 'static,
     {
         let config = self.config;
@@ -311,7 +495,7 @@ where
     }
 
     #[cfg(feature = "http3")]
-    pub fn bind_h3<A: std::net::ToSocketAddrs, ResB, BE>(
+    pub fn bind_h3<A: std::net::ToSocketAddrs, ResB, BE, BF>(
         mut self,
         addr: A,
         config: xitca_io::net::QuicConfig,
@@ -322,7 +506,8 @@ where
         S::Error: fmt::Debug,
         <S::Response as Service<Request<RequestExt<RequestBody>>>>::Error: fmt::Debug,
 
-        ResB: Stream<Item = Result<Bytes, BE>> + 'static,
+        ResB: Stream<Item = Result<BF, BE>> + 'static,
+        BF: Into<Frame> + 'static,
         BE: fmt::Debug + 'static,
     {
         let service = self
@@ -7,7 +7,7 @@ use std::path::PathBuf;
 use http_file::{runtime::AsyncFs, ServeDir as _ServeDir};
 use xitca_http::util::service::router::{PathGen, RouteGen};
 
-use crate::service::Service;
+use crate::{http::Method, service::Service};
 
 /// builder type for serve dir service.
 pub struct ServeDir<F: AsyncFs = dumb::Dumb> {
@@ -73,6 +73,10 @@ where
 
         prefix
     }
+
+    fn methods_gen(&self) -> Vec<Method> {
+        vec![Method::GET, Method::HEAD]
+    }
 }
 
 impl<F> RouteGen for ServeDir<F>